@@ -1,5 +1,56 @@
 use thiserror::Error;
 
+/// Machine-readable classification of a claims validation failure.
+///
+/// Carried by [`ClaimsError::Validation`] so callers can match on the kind of
+/// failure (e.g. to pick an HTTP problem type) instead of parsing messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ValidationCode {
+    /// A required claim is absent
+    Missing,
+
+    /// A claim is present but has the wrong type or shape
+    InvalidFormat,
+
+    /// The token's `exp` is in the past
+    Expired,
+
+    /// The token's `nbf` is in the future
+    NotYetValid,
+
+    /// The `iss` claim is not one of the accepted issuers
+    IssuerMismatch,
+
+    /// The `aud` claim does not contain an accepted audience
+    AudienceMismatch,
+
+    /// The token lacks a required role
+    MissingRole,
+}
+
+impl ValidationCode {
+    /// Stable snake-case identifier, suitable for logs and problem types
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ValidationCode::Missing => "missing",
+            ValidationCode::InvalidFormat => "invalid_format",
+            ValidationCode::Expired => "expired",
+            ValidationCode::NotYetValid => "not_yet_valid",
+            ValidationCode::IssuerMismatch => "issuer_mismatch",
+            ValidationCode::AudienceMismatch => "audience_mismatch",
+            ValidationCode::MissingRole => "missing_role",
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Errors that can occur during JWT claims validation and processing
 #[derive(Debug, Error)]
 pub enum ClaimsError {
@@ -33,6 +84,12 @@ pub enum ClaimsError {
     #[error("Missing required claim: {0}")]
     MissingClaim(String),
 
+    #[error("Missing required role: expected all of {required:?}, got {present:?}")]
+    MissingRole {
+        required: Vec<String>,
+        present: Vec<String>,
+    },
+
     #[error("Invalid claim format: {field} - {reason}")]
     InvalidClaimFormat { field: String, reason: String },
 
@@ -47,13 +104,94 @@ pub enum ClaimsError {
 
     #[error("Unknown key ID: {0}")]
     UnknownKeyId(String),
+
+    /// Structured claim validation failure with a field path and a
+    /// machine-readable code.
+    #[error("Claim validation failed: {field} ({code}) - {reason}")]
+    Validation {
+        field: String,
+        code: ValidationCode,
+        reason: String,
+    },
+}
+
+impl ClaimsError {
+    /// Build a [`ClaimsError::Validation`].
+    pub fn validation(
+        field: impl Into<String>,
+        code: ValidationCode,
+        reason: impl Into<String>,
+    ) -> Self {
+        ClaimsError::Validation {
+            field: field.into(),
+            code,
+            reason: reason.into(),
+        }
+    }
+
+    /// Validation code for this error, if it is a claims validation failure.
+    ///
+    /// Works for both [`ClaimsError::Validation`] and the equivalent specific
+    /// variants (`Expired`, `MissingClaim`, `InvalidIssuer`, ...). Returns
+    /// `None` for signature, key, and provider errors.
+    #[must_use]
+    pub fn code(&self) -> Option<ValidationCode> {
+        match self {
+            ClaimsError::Validation { code, .. } => Some(*code),
+            ClaimsError::MissingClaim(_) => Some(ValidationCode::Missing),
+            ClaimsError::InvalidClaimFormat { .. } | ClaimsError::Malformed(_) => {
+                Some(ValidationCode::InvalidFormat)
+            }
+            ClaimsError::Expired => Some(ValidationCode::Expired),
+            ClaimsError::NotYetValid => Some(ValidationCode::NotYetValid),
+            ClaimsError::InvalidIssuer { .. } => Some(ValidationCode::IssuerMismatch),
+            ClaimsError::InvalidAudience { .. } => Some(ValidationCode::AudienceMismatch),
+            ClaimsError::MissingRole { .. } => Some(ValidationCode::MissingRole),
+            _ => None,
+        }
+    }
+
+    /// Convert a specific validation variant into [`ClaimsError::Validation`].
+    ///
+    /// Errors without a [`ValidationCode`] are returned unchanged. The human
+    /// reason is the original error message.
+    #[must_use]
+    pub fn into_validation(self) -> Self {
+        let Some(code) = self.code() else {
+            return self;
+        };
+        let field = match &self {
+            ClaimsError::Validation { .. } => return self,
+            ClaimsError::MissingClaim(claim) => claim.clone(),
+            ClaimsError::InvalidClaimFormat { field, .. } => field.clone(),
+            ClaimsError::Expired => "exp".to_owned(),
+            ClaimsError::NotYetValid => "nbf".to_owned(),
+            ClaimsError::InvalidIssuer { .. } => "iss".to_owned(),
+            ClaimsError::InvalidAudience { .. } => "aud".to_owned(),
+            ClaimsError::MissingRole { .. } => "roles".to_owned(),
+            _ => String::new(),
+        };
+        let reason = match &self {
+            ClaimsError::InvalidClaimFormat { reason, .. } => reason.clone(),
+            other => other.to_string(),
+        };
+        ClaimsError::Validation {
+            field,
+            code,
+            reason,
+        }
+    }
 }
 
 // Conversion from ClaimsError to AuthError for backward compatibility
 impl From<ClaimsError> for crate::errors::AuthError {
     fn from(err: ClaimsError) -> Self {
         match err {
-            ClaimsError::Expired => crate::errors::AuthError::TokenExpired,
+            ClaimsError::Expired
+            | ClaimsError::Validation {
+                code: ValidationCode::Expired,
+                ..
+            } => crate::errors::AuthError::TokenExpired,
             ClaimsError::InvalidSignature => {
                 crate::errors::AuthError::InvalidToken("Invalid signature".into())
             }
@@ -66,8 +204,112 @@ impl From<ClaimsError> for crate::errors::AuthError {
             ClaimsError::InvalidAudience { expected, actual } => {
                 crate::errors::AuthError::AudienceMismatch { expected, actual }
             }
+            // A structured failure carries no expected/actual values to fill
+            // `IssuerMismatch`/`AudienceMismatch` with, so report its reason.
+            ClaimsError::Validation {
+                code: ValidationCode::IssuerMismatch | ValidationCode::AudienceMismatch,
+                reason,
+                ..
+            } => crate::errors::AuthError::ValidationFailed(reason),
+            ClaimsError::MissingRole { .. }
+            | ClaimsError::Validation {
+                code: ValidationCode::MissingRole,
+                ..
+            } => crate::errors::AuthError::Forbidden,
             ClaimsError::JwksFetchFailed(msg) => crate::errors::AuthError::JwksFetchFailed(msg),
             other => crate::errors::AuthError::ValidationFailed(other.to_string()),
         }
     }
 }
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn code_matches_specific_variants() {
+        assert_eq!(ClaimsError::Expired.code(), Some(ValidationCode::Expired));
+        assert_eq!(
+            ClaimsError::MissingClaim("sub".into()).code(),
+            Some(ValidationCode::Missing)
+        );
+        assert_eq!(ClaimsError::InvalidSignature.code(), None);
+    }
+
+    #[test]
+    fn into_validation_keeps_field_and_reason() {
+        let err = ClaimsError::InvalidClaimFormat {
+            field: "sub".into(),
+            reason: "must be a valid UUID".into(),
+        }
+        .into_validation();
+
+        match err {
+            ClaimsError::Validation {
+                field,
+                code,
+                reason,
+            } => {
+                assert_eq!(field, "sub");
+                assert_eq!(code, ValidationCode::InvalidFormat);
+                assert_eq!(reason, "must be a valid UUID");
+            }
+            other => panic!("expected Validation, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn into_validation_uses_standard_claim_names() {
+        match ClaimsError::NotYetValid.into_validation() {
+            ClaimsError::Validation { field, code, .. } => {
+                assert_eq!(field, "nbf");
+                assert_eq!(code, ValidationCode::NotYetValid);
+            }
+            other => panic!("expected Validation, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn into_validation_leaves_non_validation_errors_unchanged() {
+        assert!(matches!(
+            ClaimsError::UnknownKeyId("k1".into()).into_validation(),
+            ClaimsError::UnknownKeyId(_)
+        ));
+    }
+
+    #[test]
+    fn expired_validation_maps_to_token_expired() {
+        let err = ClaimsError::validation("exp", ValidationCode::Expired, "token expired");
+        assert!(matches!(
+            crate::errors::AuthError::from(err),
+            crate::errors::AuthError::TokenExpired
+        ));
+    }
+
+    #[test]
+    fn specific_mismatch_variants_keep_expected_and_actual() {
+        let err = ClaimsError::InvalidIssuer {
+            expected: vec!["https://a".into()],
+            actual: "https://b".into(),
+        };
+        match crate::errors::AuthError::from(err) {
+            crate::errors::AuthError::IssuerMismatch { expected, actual } => {
+                assert_eq!(expected, "https://a");
+                assert_eq!(actual, "https://b");
+            }
+            other => panic!("expected IssuerMismatch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn structured_mismatch_validation_maps_to_validation_failed_with_reason() {
+        let err = ClaimsError::validation("aud", ValidationCode::AudienceMismatch, "wrong aud");
+        match crate::errors::AuthError::from(err) {
+            crate::errors::AuthError::ValidationFailed(reason) => {
+                assert_eq!(reason, "wrong aud");
+            }
+            other => panic!("expected ValidationFailed, got {other:?}"),
+        }
+    }
+}
@@ -53,6 +53,7 @@ impl From<&AuthConfig> for ValidationConfig {
             allowed_audiences: config.audiences.clone(),
             leeway_seconds: config.leeway_seconds,
             require_exp: config.require_exp,
+            ..Self::default()
         }
     }
 }
@@ -22,7 +22,7 @@ pub use errors::AuthError;
 pub use traits::{KeyProvider, TokenValidator};
 
 // JWT / JWKS exports
-pub use claims_error::ClaimsError;
+pub use claims_error::{ClaimsError, ValidationCode};
 pub use config::{AuthConfig, JwksConfig};
 pub use metrics::{AuthEvent, AuthMetricLabels, AuthMetrics, LoggingMetrics, NoOpMetrics};
 pub use providers::JwksKeyProvider;
@@ -18,6 +18,25 @@ pub struct ValidationConfig {
     /// Whether the `exp` claim is required (default: `true`).
     /// Set to `false` to allow tokens without an expiration claim.
     pub require_exp: bool,
+
+    /// Expected value of the token-use claim, e.g. `"access"` to reject id or
+    /// refresh tokens presented at an access-token endpoint (`None` disables
+    /// the check).
+    pub required_token_use: Option<String>,
+
+    /// Name of the claim checked against `required_token_use`
+    /// (default: `"token_use"`, as issued by Cognito).
+    pub token_use_claim: String,
+
+    /// Roles that must all be present in the token (if empty, roles are not
+    /// checked)
+    pub required_roles: Vec<String>,
+
+    /// Dot-separated path of the claim holding the token's roles
+    /// (default: `"roles"`; e.g. `"realm_access.roles"` for Keycloak realm
+    /// roles). The claim may be an array of strings or a space/comma-delimited
+    /// string.
+    pub roles_claim: String,
 }
 
 impl Default for ValidationConfig {
@@ -27,6 +46,10 @@ impl Default for ValidationConfig {
             allowed_audiences: vec![],
             leeway_seconds: 60,
             require_exp: true,
+            required_token_use: None,
+            token_use_claim: "token_use".to_owned(),
+            required_roles: vec![],
+            roles_claim: "roles".to_owned(),
         }
     }
 }
@@ -38,7 +61,11 @@ impl Default for ValidationConfig {
 /// 2. **Audience** (`aud`) — at least one must match `config.allowed_audiences` (skipped if empty)
 /// 3. **Expiration** (`exp`) — required by default; must not be in the past (with leeway).
 ///    Set `require_exp = false` to accept tokens without an `exp` claim.
-/// 4. **Not Before** (`nbf`) — must not be in the future (with leeway)
+/// 4. **Not Before** (`nbf`) — must not be in the future (with leeway), nor after `exp`
+/// 5. **Token use** (`config.token_use_claim`) — must equal `config.required_token_use`
+///    (skipped if `None`)
+/// 6. **Roles** (`config.roles_claim`) — must contain every role in
+///    `config.required_roles` (skipped if empty)
 ///
 /// # Errors
 /// Returns `ClaimsError` if any validation check fails.
@@ -96,8 +123,11 @@ pub fn validate_claims(
     let leeway = time::Duration::seconds(config.leeway_seconds);
 
     // 3. Validate expiration with leeway
-    if let Some(exp_value) = raw.get(StandardClaim::EXP) {
-        let exp = parse_timestamp(exp_value, StandardClaim::EXP)?;
+    let exp = raw
+        .get(StandardClaim::EXP)
+        .map(|exp_value| parse_timestamp(exp_value, StandardClaim::EXP))
+        .transpose()?;
+    if let Some(exp) = exp {
         let exp_with_leeway =
             exp.checked_add(leeway)
                 .ok_or_else(|| ClaimsError::InvalidClaimFormat {
@@ -114,6 +144,14 @@ pub fn validate_claims(
     // 4. Validate not-before with leeway
     if let Some(nbf_value) = raw.get(StandardClaim::NBF) {
         let nbf = parse_timestamp(nbf_value, StandardClaim::NBF)?;
+        // An inverted window is malformed regardless of the current time,
+        // and leeway could otherwise let it through.
+        if exp.is_some_and(|exp| nbf > exp) {
+            return Err(ClaimsError::InvalidClaimFormat {
+                field: StandardClaim::NBF.to_owned(),
+                reason: "nbf is after exp".to_owned(),
+            });
+        }
         let nbf_with_leeway =
             nbf.checked_sub(leeway)
                 .ok_or_else(|| ClaimsError::InvalidClaimFormat {
@@ -125,9 +163,44 @@ pub fn validate_claims(
         }
     }
 
+    // 5. Validate token use
+    if let Some(expected) = &config.required_token_use {
+        let claim = config.token_use_claim.as_str();
+        let value = raw
+            .get(claim)
+            .ok_or_else(|| ClaimsError::MissingClaim(claim.to_owned()))?;
+        let actual = extract_string(value, claim)?;
+        if actual != *expected {
+            return Err(ClaimsError::InvalidClaimFormat {
+                field: claim.to_owned(),
+                reason: format!("expected '{expected}', got '{actual}'"),
+            });
+        }
+    }
+
+    // 6. Validate required roles
+    if !config.required_roles.is_empty() {
+        let present = match claim_at_path(raw, &config.roles_claim) {
+            Some(value) => extract_roles(value, &config.roles_claim)?,
+            None => Vec::new(),
+        };
+        if !config.required_roles.iter().all(|r| present.contains(r)) {
+            return Err(ClaimsError::MissingRole {
+                required: config.required_roles.clone(),
+                present,
+            });
+        }
+    }
+
     Ok(())
 }
 
+/// Resolve a dot-separated claim path (e.g. `"realm_access.roles"`).
+fn claim_at_path<'a>(raw: &'a serde_json::Value, path: &str) -> Option<&'a serde_json::Value> {
+    path.split('.')
+        .try_fold(raw, |value, segment| value.get(segment))
+}
+
 /// Helper to parse a UUID from a JSON value.
 ///
 /// # Errors
@@ -204,6 +277,36 @@ pub fn extract_string(value: &serde_json::Value, field_name: &str) -> Result<Str
         })
 }
 
+/// Extract roles from a JSON value.
+///
+/// Accepts an array of strings or a single string delimited by spaces and/or
+/// commas. Empty entries are dropped.
+///
+/// # Errors
+/// Returns `ClaimsError::InvalidClaimFormat` if the value is neither a string
+/// nor an array of strings.
+pub fn extract_roles(
+    value: &serde_json::Value,
+    field_name: &str,
+) -> Result<Vec<String>, ClaimsError> {
+    let invalid = || ClaimsError::InvalidClaimFormat {
+        field: field_name.to_owned(),
+        reason: "must be a string or array of strings".to_owned(),
+    };
+    match value {
+        serde_json::Value::String(s) => Ok(s
+            .split([' ', ','])
+            .filter(|r| !r.is_empty())
+            .map(str::to_owned)
+            .collect()),
+        serde_json::Value::Array(arr) => arr
+            .iter()
+            .map(|v| v.as_str().map(str::to_owned).ok_or_else(invalid))
+            .collect(),
+        _ => Err(invalid()),
+    }
+}
+
 /// Extract audiences from a JSON value.
 ///
 /// Accepts a single string or an array of strings. Rejects non-string entries
@@ -349,6 +452,24 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_nbf_after_exp_fails_even_within_leeway() {
+        let now = time::OffsetDateTime::now_utc();
+        let claims = json!({
+            "exp": (now + time::Duration::seconds(10)).unix_timestamp(),
+            "nbf": (now + time::Duration::seconds(20)).unix_timestamp(),
+        });
+        let config = ValidationConfig::default();
+        let err = validate_claims(&claims, &config).unwrap_err();
+        match err {
+            ClaimsError::InvalidClaimFormat { field, reason } => {
+                assert_eq!(field, StandardClaim::NBF);
+                assert_eq!(reason, "nbf is after exp");
+            }
+            other => panic!("expected InvalidClaimFormat for nbf after exp, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_leeway_allows_slightly_expired() {
         let now = time::OffsetDateTime::now_utc();
@@ -557,4 +678,147 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_token_use_matches() {
+        let claims = json!({ "token_use": "access" });
+        let config = ValidationConfig {
+            require_exp: false,
+            required_token_use: Some("access".to_owned()),
+            ..Default::default()
+        };
+        assert!(validate_claims(&claims, &config).is_ok());
+    }
+
+    #[test]
+    fn test_token_use_mismatch_fails() {
+        let claims = json!({ "token_use": "id" });
+        let config = ValidationConfig {
+            require_exp: false,
+            required_token_use: Some("access".to_owned()),
+            ..Default::default()
+        };
+        match validate_claims(&claims, &config).unwrap_err() {
+            ClaimsError::InvalidClaimFormat { field, reason } => {
+                assert_eq!(field, "token_use");
+                assert_eq!(reason, "expected 'access', got 'id'");
+            }
+            other => panic!("expected InvalidClaimFormat, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_token_use_missing_fails() {
+        let claims = json!({ "sub": "user-1" });
+        let config = ValidationConfig {
+            require_exp: false,
+            required_token_use: Some("access".to_owned()),
+            ..Default::default()
+        };
+        match validate_claims(&claims, &config).unwrap_err() {
+            ClaimsError::MissingClaim(claim) => assert_eq!(claim, "token_use"),
+            other => panic!("expected MissingClaim(token_use), got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_token_use_non_string_fails() {
+        let claims = json!({ "token_use": 1 });
+        let config = ValidationConfig {
+            require_exp: false,
+            required_token_use: Some("access".to_owned()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            validate_claims(&claims, &config),
+            Err(ClaimsError::InvalidClaimFormat { field, .. }) if field == "token_use"
+        ));
+    }
+
+    #[test]
+    fn test_token_use_custom_claim_name() {
+        let claims = json!({ "typ": "refresh", "token_use": "access" });
+        let config = ValidationConfig {
+            require_exp: false,
+            required_token_use: Some("access".to_owned()),
+            token_use_claim: "typ".to_owned(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            validate_claims(&claims, &config),
+            Err(ClaimsError::InvalidClaimFormat { field, .. }) if field == "typ"
+        ));
+    }
+
+    #[test]
+    fn test_token_use_ignored_when_not_configured() {
+        let claims = json!({ "token_use": "refresh" });
+        let config = ValidationConfig {
+            require_exp: false,
+            ..Default::default()
+        };
+        assert!(validate_claims(&claims, &config).is_ok());
+    }
+
+    fn roles_config(roles: &[&str]) -> ValidationConfig {
+        ValidationConfig {
+            require_exp: false,
+            required_roles: roles.iter().map(|r| (*r).to_owned()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_required_roles_from_array_pass() {
+        let claims = json!({ "roles": ["reader", "writer"] });
+        assert!(validate_claims(&claims, &roles_config(&["writer"])).is_ok());
+    }
+
+    #[test]
+    fn test_required_roles_from_delimited_string_pass() {
+        let claims = json!({ "roles": "reader, writer admin" });
+        let config = roles_config(&["reader", "writer", "admin"]);
+        assert!(validate_claims(&claims, &config).is_ok());
+    }
+
+    #[test]
+    fn test_missing_required_role_fails() {
+        let claims = json!({ "roles": ["reader"] });
+        let err = validate_claims(&claims, &roles_config(&["reader", "admin"])).unwrap_err();
+        match err {
+            ClaimsError::MissingRole { required, present } => {
+                assert_eq!(required, vec!["reader", "admin"]);
+                assert_eq!(present, vec!["reader"]);
+            }
+            other => panic!("expected MissingRole, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_absent_roles_claim_fails_with_missing_role() {
+        let claims = json!({});
+        assert!(matches!(
+            validate_claims(&claims, &roles_config(&["admin"])),
+            Err(ClaimsError::MissingRole { present, .. }) if present.is_empty()
+        ));
+    }
+
+    #[test]
+    fn test_roles_claim_nested_path() {
+        let claims = json!({ "realm_access": { "roles": ["admin"] }, "roles": [] });
+        let config = ValidationConfig {
+            roles_claim: "realm_access.roles".to_owned(),
+            ..roles_config(&["admin"])
+        };
+        assert!(validate_claims(&claims, &config).is_ok());
+    }
+
+    #[test]
+    fn test_roles_claim_invalid_format_fails() {
+        let claims = json!({ "roles": ["admin", 1] });
+        assert!(matches!(
+            validate_claims(&claims, &roles_config(&["admin"])),
+            Err(ClaimsError::InvalidClaimFormat { field, .. }) if field == "roles"
+        ));
+    }
 }
@@ -135,7 +135,9 @@ pub enum ChoosePluginError {
 ///
 /// Deserializes each entry as `BaseModkitPluginV1<P>`, filters by
 /// `vendor`, and returns the `gts_id` of the instance with the
-/// **lowest** priority value.
+/// **lowest** priority value. Instances with equal priority are ordered by
+/// `gts_id` (lexically smallest wins), so the choice does not depend on the
+/// order the registry lists them in.
 ///
 /// # Type Parameters
 ///
@@ -188,8 +190,8 @@ where
 
         match &best {
             None => best = Some((gts_id, content.priority)),
-            Some((_, cur_priority)) => {
-                if content.priority < *cur_priority {
+            Some((cur_id, cur_priority)) => {
+                if (content.priority, gts_id) < (*cur_priority, *cur_id) {
                     best = Some((gts_id, content.priority));
                 }
             }
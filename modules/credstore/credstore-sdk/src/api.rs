@@ -25,4 +25,24 @@ pub trait CredStoreClientV1: Send + Sync {
         ctx: &SecurityContext,
         key: &SecretRef,
     ) -> Result<Option<GetSecretResponse>, CredStoreError>;
+
+    /// Retrieves a specific version of a secret, or the latest if `version`
+    /// is `None`.
+    ///
+    /// Useful during rotation overlap windows, when a prior credential must
+    /// still be fetched. Follows the same `Ok(None)` semantics as
+    /// [`get`](Self::get); a version the backend does not know is `Ok(None)`.
+    ///
+    /// The default implementation delegates to [`get`](Self::get) and only
+    /// returns the value when no version was requested or the value reports
+    /// the requested version.
+    async fn get_version(
+        &self,
+        ctx: &SecurityContext,
+        key: &SecretRef,
+        version: Option<u32>,
+    ) -> Result<Option<GetSecretResponse>, CredStoreError> {
+        let resp = self.get(ctx, key).await?;
+        Ok(resp.filter(|r| version.is_none() || r.version == version))
+    }
 }
@@ -5,7 +5,7 @@ use std::fmt;
 use serde::de::Deserializer;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use zeroize::Zeroize;
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::error::CredStoreError;
 
@@ -39,8 +39,10 @@ impl fmt::Display for OwnerId {
 
 /// A validated secret reference key.
 ///
-/// Format: `[a-zA-Z0-9_-]+`, max 255 characters.
-/// Colons are prohibited to prevent `ExternalID` collisions in backend storage.
+/// Format: `[a-zA-Z0-9_-]+`, max 255 characters by default (see
+/// [`SecretRef::new_with_max`]).
+/// Colons are prohibited to prevent `ExternalID` collisions in backend storage;
+/// see [`SecretRef::external_id`].
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
 pub struct SecretRef(String);
 
@@ -52,6 +54,9 @@ impl<'de> Deserialize<'de> for SecretRef {
 }
 
 impl SecretRef {
+    /// Default maximum length used by [`SecretRef::new`].
+    pub const DEFAULT_MAX_LEN: usize = 255;
+
     /// Creates a new `SecretRef` after validating the format.
     ///
     /// # Errors
@@ -60,14 +65,27 @@ impl SecretRef {
     /// exceeds 255 characters, or contains characters outside `[a-zA-Z0-9_-]`.
     #[must_use = "returns a Result that may contain a validation error"]
     pub fn new(value: impl Into<String>) -> Result<Self, CredStoreError> {
+        Self::new_with_max(value, Self::DEFAULT_MAX_LEN)
+    }
+
+    /// Creates a new `SecretRef` with a caller-chosen maximum length, for
+    /// backends whose key-length limits differ from the 255 default.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CredStoreError::InvalidSecretRef` if the input is empty,
+    /// exceeds `max_len` characters, or contains characters outside
+    /// `[a-zA-Z0-9_-]`.
+    #[must_use = "returns a Result that may contain a validation error"]
+    pub fn new_with_max(value: impl Into<String>, max_len: usize) -> Result<Self, CredStoreError> {
         let value = value.into();
         if value.is_empty() {
             return Err(CredStoreError::invalid_ref("must not be empty"));
         }
-        if value.len() > 255 {
-            return Err(CredStoreError::invalid_ref(
-                "exceeds maximum length of 255 characters",
-            ));
+        if value.len() > max_len {
+            return Err(CredStoreError::invalid_ref(format!(
+                "exceeds maximum length of {max_len} characters"
+            )));
         }
         if !value
             .bytes()
@@ -79,6 +97,17 @@ impl SecretRef {
         }
         Ok(Self(value))
     }
+
+    /// The canonical backend identifier for this key within `tenant`:
+    /// `{tenant}:{key}`, with the tenant as a hyphenated UUID.
+    ///
+    /// Plugins should derive storage keys from this rather than formatting
+    /// their own. Neither part can contain a colon, so the first colon always
+    /// separates them and distinct `(tenant, key)` pairs never share an ID.
+    #[must_use]
+    pub fn external_id(&self, tenant: TenantId) -> String {
+        format!("{tenant}:{}", self.0)
+    }
 }
 
 impl AsRef<str> for SecretRef {
@@ -87,6 +116,29 @@ impl AsRef<str> for SecretRef {
     }
 }
 
+impl TryFrom<&str> for SecretRef {
+    type Error = CredStoreError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<String> for SecretRef {
+    type Error = CredStoreError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+/// The reference is a key name, not secret material, so it is safe to print.
+impl fmt::Display for SecretRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
 impl fmt::Debug for SecretRef {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("SecretRef").field(&self.0).finish()
@@ -107,11 +159,38 @@ impl SecretValue {
         Self(value)
     }
 
+    /// Creates a new `SecretValue` that takes over the buffer of `value`
+    /// without copying it.
+    #[must_use]
+    pub fn from_zeroizing(mut value: Zeroizing<Vec<u8>>) -> Self {
+        Self(std::mem::take(&mut *value))
+    }
+
+    /// Moves the bytes into a [`Zeroizing`] buffer without copying them, so
+    /// they stay zeroized-on-drop after leaving the `SecretValue`.
+    #[must_use]
+    pub fn into_zeroizing(mut self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(std::mem::take(&mut self.0))
+    }
+
     /// Returns a reference to the raw bytes.
+    ///
+    /// Prefer [`expose_with`](Self::expose_with), which keeps the secret
+    /// readable only for the duration of a closure.
     #[must_use]
     pub fn as_bytes(&self) -> &[u8] {
         &self.0
     }
+
+    /// Runs `f` with access to the raw bytes and returns its result.
+    ///
+    /// This is the preferred access pattern: the borrow cannot outlive the
+    /// closure, which keeps the window in which the secret is readable as
+    /// small as possible. Avoid copying the bytes out of `f`; the wrapped
+    /// buffer is zeroized on drop, but copies are not.
+    pub fn expose_with<R>(&self, f: impl FnOnce(&[u8]) -> R) -> R {
+        f(&self.0)
+    }
 }
 
 impl From<Vec<u8>> for SecretValue {
@@ -177,6 +256,9 @@ pub struct GetSecretResponse {
     /// `true` if the secret was retrieved from an ancestor tenant via
     /// hierarchical resolution, `false` if owned by the requesting tenant.
     pub is_inherited: bool,
+    /// Version of the returned value, or `None` if the backend does not
+    /// version secrets.
+    pub version: Option<u32>,
 }
 
 /// Metadata returned by plugins alongside the secret value.
@@ -186,6 +268,8 @@ pub struct SecretMetadata {
     pub owner_id: OwnerId,
     pub sharing: SharingMode,
     pub owner_tenant_id: TenantId,
+    /// Version of the value, or `None` for backends without versioning.
+    pub version: Option<u32>,
 }
 
 #[cfg(test)]
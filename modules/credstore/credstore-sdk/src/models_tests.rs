@@ -15,6 +15,39 @@ fn secret_ref_invalid_chars() {
     assert!(SecretRef::new("key/path").is_err());
 }
 
+#[test]
+fn secret_ref_external_id_format() {
+    let tenant = TenantId(Uuid::from_u128(0x0123_4567_89ab_cdef_0123_4567_89ab_cdef));
+    let key = SecretRef::new("partner-openai-key").unwrap();
+    assert_eq!(
+        key.external_id(tenant),
+        "01234567-89ab-cdef-0123-456789abcdef:partner-openai-key"
+    );
+}
+
+#[test]
+fn secret_ref_external_id_never_collides() {
+    let tenants = [
+        TenantId::nil(),
+        TenantId(Uuid::from_u128(1)),
+        TenantId(Uuid::from_u128(0x10)),
+        TenantId(Uuid::max()),
+    ];
+    let keys = ["a", "a-", "-a", "1", "01", "a_b", "a-b", "ab", "A"];
+
+    let mut seen = std::collections::HashMap::new();
+    for tenant in tenants {
+        for key in keys {
+            let id = SecretRef::new(key).unwrap().external_id(tenant);
+            let (tenant_part, key_part) = id.split_once(':').unwrap();
+            assert_eq!((tenant_part, key_part), (tenant.to_string().as_str(), key));
+            if let Some(previous) = seen.insert(id.clone(), (tenant, key)) {
+                panic!("{id} produced by both {previous:?} and {:?}", (tenant, key));
+            }
+        }
+    }
+}
+
 #[test]
 fn secret_ref_empty() {
     assert!(SecretRef::new("").is_err());
@@ -32,6 +65,20 @@ fn secret_ref_max_length() {
     assert!(SecretRef::new(max).is_ok());
 }
 
+#[test]
+fn secret_ref_new_with_max_enforces_configured_bound() {
+    assert!(SecretRef::new_with_max("a".repeat(512), 512).is_ok());
+    assert!(SecretRef::new_with_max("abcd", 3).is_err());
+    assert!(SecretRef::new_with_max("my:key", 512).is_err());
+
+    match SecretRef::new_with_max("a".repeat(33), 32) {
+        Err(CredStoreError::InvalidSecretRef { reason }) => {
+            assert_eq!(reason, "exceeds maximum length of 32 characters");
+        }
+        other => panic!("expected InvalidSecretRef, got {other:?}"),
+    }
+}
+
 #[test]
 fn secret_ref_deserialize_validates() {
     let valid: Result<SecretRef, _> = serde_json::from_str("\"valid-key_1\"");
@@ -45,6 +92,26 @@ fn secret_ref_deserialize_validates() {
     assert!(empty.is_err());
 }
 
+#[test]
+fn secret_ref_try_from_validates() {
+    let from_str = SecretRef::try_from("api_key").unwrap();
+    assert_eq!(from_str.as_ref(), "api_key");
+    let from_string = SecretRef::try_from(String::from("api-key-2")).unwrap();
+    assert_eq!(from_string.as_ref(), "api-key-2");
+
+    assert!(matches!(
+        SecretRef::try_from("my:key"),
+        Err(CredStoreError::InvalidSecretRef { .. })
+    ));
+    assert!(SecretRef::try_from(String::new()).is_err());
+}
+
+#[test]
+fn secret_ref_display_prints_key() {
+    let r = SecretRef::new("partner-openai-key").unwrap();
+    assert_eq!(r.to_string(), "partner-openai-key");
+}
+
 #[test]
 fn secret_value_debug_redacted() {
     let val = SecretValue::new(b"super-secret".to_vec());
@@ -63,6 +130,28 @@ fn secret_value_as_bytes() {
     assert_eq!(val.as_bytes(), b"hello");
 }
 
+#[test]
+fn secret_value_expose_with() {
+    let val = SecretValue::from("my-secret");
+    let len = val.expose_with(<[u8]>::len);
+    assert_eq!(len, 9);
+    assert!(val.expose_with(|bytes| bytes == b"my-secret"));
+}
+
+#[test]
+fn secret_value_zeroizing_roundtrip_moves_buffer() {
+    let source = Zeroizing::new(b"token".to_vec());
+    let ptr = source.as_ptr();
+
+    let val = SecretValue::from_zeroizing(source);
+    assert_eq!(val.as_bytes(), b"token");
+    assert_eq!(val.as_bytes().as_ptr(), ptr);
+
+    let out = val.into_zeroizing();
+    assert_eq!(out.as_slice(), b"token");
+    assert_eq!(out.as_ptr(), ptr);
+}
+
 #[test]
 fn get_secret_response_debug_redacts_value() {
     let resp = GetSecretResponse {
@@ -70,11 +159,13 @@ fn get_secret_response_debug_redacts_value() {
         owner_tenant_id: TenantId::nil(),
         sharing: SharingMode::Shared,
         is_inherited: true,
+        version: Some(3),
     };
     let debug = format!("{resp:?}");
     assert!(debug.contains("[REDACTED]"));
     assert!(!debug.contains("secret"));
     assert!(debug.contains("is_inherited: true"));
+    assert!(debug.contains("version: Some(3)"));
 }
 
 #[test]
@@ -84,6 +175,7 @@ fn secret_metadata_debug_redacts_value() {
         owner_id: OwnerId::nil(),
         sharing: SharingMode::Tenant,
         owner_tenant_id: TenantId::nil(),
+        version: None,
     };
     let debug = format!("{meta:?}");
     assert!(debug.contains("[REDACTED]"));
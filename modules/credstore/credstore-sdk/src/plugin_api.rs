@@ -16,4 +16,20 @@ pub trait CredStorePluginClientV1: Send + Sync {
         ctx: &SecurityContext,
         key: &SecretRef,
     ) -> Result<Option<SecretMetadata>, CredStoreError>;
+
+    /// Retrieves a specific version of a secret, or the latest if `version`
+    /// is `None`.
+    ///
+    /// The default implementation serves plugins without versioning: it
+    /// delegates to [`get`](Self::get) and only returns the value when no
+    /// version was requested or the value reports the requested version.
+    async fn get_version(
+        &self,
+        ctx: &SecurityContext,
+        key: &SecretRef,
+        version: Option<u32>,
+    ) -> Result<Option<SecretMetadata>, CredStoreError> {
+        let meta = self.get(ctx, key).await?;
+        Ok(meta.filter(|m| version.is_none() || m.version == version))
+    }
 }
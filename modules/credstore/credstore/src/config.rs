@@ -3,6 +3,8 @@
 
 use serde::Deserialize;
 
+use crate::domain::service::DEFAULT_RESOLVE_BACKOFF_MS;
+
 /// Module configuration.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default, deny_unknown_fields)]
@@ -12,12 +14,40 @@ pub struct CredStoreConfig {
     /// The module queries types-registry for plugin instances matching
     /// this vendor and selects the one with lowest priority number.
     pub vendor: String,
+
+    /// Vendors tried, in order, after [`vendor`](Self::vendor).
+    ///
+    /// A fallback is consulted when the previous vendor has no usable plugin
+    /// or its plugin does not know the requested secret.
+    pub vendor_fallbacks: Vec<String>,
+
+    /// How long a failed plugin resolution is reused before types-registry
+    /// is queried again, in milliseconds (default: 1000; `0` disables).
+    ///
+    /// Limits load on the registry during an outage. Successful resolutions
+    /// are cached permanently.
+    pub resolve_backoff_ms: u64,
+
+    /// How long a "not found" answer is reused before the plugins are asked
+    /// again, in milliseconds (default: 0, disabled).
+    ///
+    /// Keep it short: a secret created meanwhile stays invisible to the
+    /// caller that missed it for up to this long. Found secrets are never
+    /// cached.
+    pub negative_cache_ttl_ms: u64,
+
+    /// Maximum number of cached "not found" answers (default: 1024).
+    pub negative_cache_max_entries: usize,
 }
 
 impl Default for CredStoreConfig {
     fn default() -> Self {
         Self {
             vendor: "cyberfabric".to_owned(),
+            vendor_fallbacks: Vec::new(),
+            resolve_backoff_ms: DEFAULT_RESOLVE_BACKOFF_MS,
+            negative_cache_ttl_ms: 0,
+            negative_cache_max_entries: 1024,
         }
     }
 }
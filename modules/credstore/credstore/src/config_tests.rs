@@ -17,8 +17,48 @@ fn serde_default_applies_default_vendor() {
     );
 }
 
+#[test]
+fn vendor_fallbacks_default_to_empty() {
+    let cfg: CredStoreConfig = serde_json::from_str(r#"{"vendor": "acme"}"#).unwrap();
+    assert!(cfg.vendor_fallbacks.is_empty());
+}
+
+#[test]
+fn vendor_fallbacks_preserve_order() {
+    let json = r#"{"vendor": "vault", "vendor_fallbacks": ["static", "legacy"]}"#;
+    let cfg: CredStoreConfig = serde_json::from_str(json).unwrap();
+    assert_eq!(cfg.vendor_fallbacks, ["static", "legacy"]);
+}
+
+#[test]
+fn resolve_backoff_defaults_to_one_second() {
+    let cfg: CredStoreConfig = serde_json::from_str("{}").unwrap();
+    assert_eq!(cfg.resolve_backoff_ms, 1000);
+}
+
+#[test]
+fn resolve_backoff_can_be_disabled() {
+    let cfg: CredStoreConfig = serde_json::from_str(r#"{"resolve_backoff_ms": 0}"#).unwrap();
+    assert_eq!(cfg.resolve_backoff_ms, 0);
+}
+
 #[test]
 fn rejects_unknown_fields() {
     let json = r#"{"vendor": "x", "unexpected": true}"#;
     assert!(serde_json::from_str::<CredStoreConfig>(json).is_err());
 }
+
+#[test]
+fn negative_cache_is_disabled_by_default() {
+    let cfg: CredStoreConfig = serde_json::from_str("{}").unwrap();
+    assert_eq!(cfg.negative_cache_ttl_ms, 0);
+    assert_eq!(cfg.negative_cache_max_entries, 1024);
+}
+
+#[test]
+fn negative_cache_ttl_is_independent_of_resolve_backoff() {
+    let json = r#"{"resolve_backoff_ms": 5000, "negative_cache_ttl_ms": 250}"#;
+    let cfg: CredStoreConfig = serde_json::from_str(json).unwrap();
+    assert_eq!(cfg.resolve_backoff_ms, 5000);
+    assert_eq!(cfg.negative_cache_ttl_ms, 250);
+}
@@ -6,7 +6,7 @@ use modkit_macros::domain_model;
 
 /// Internal domain errors.
 #[domain_model]
-#[derive(thiserror::Error, Debug)]
+#[derive(thiserror::Error, Debug, Clone)]
 pub enum DomainError {
     #[error("types registry is not available: {0}")]
     TypesRegistryUnavailable(String),
@@ -50,6 +50,18 @@ impl CredStoreClientV1 for CredStoreLocalClient {
             .await
             .map_err(|e| log_and_convert("get", e))
     }
+
+    async fn get_version(
+        &self,
+        ctx: &SecurityContext,
+        key: &SecretRef,
+        version: Option<u32>,
+    ) -> Result<Option<GetSecretResponse>, CredStoreError> {
+        self.svc
+            .get_version(ctx, key, version)
+            .await
+            .map_err(|e| log_and_convert("get_version", e))
+    }
 }
 
 #[cfg(test)]
@@ -75,6 +75,7 @@ async fn get_trait_impl_returns_some_on_success() {
         owner_id: OwnerId::nil(),
         sharing: SharingMode::Tenant,
         owner_tenant_id: TenantId::nil(),
+        version: None,
     };
     let client = make_wired_client(MockPlugin::returns(Some(&meta)));
     let key = SecretRef::new("key").unwrap();
@@ -91,3 +92,31 @@ async fn get_trait_impl_returns_none_when_plugin_returns_none() {
     let resp = client.get(&test_ctx(), &key).await.unwrap();
     assert!(resp.is_none());
 }
+
+// ── CredStoreClientV1::get_version ───────────────────────────────────────
+
+#[tokio::test]
+async fn get_version_trait_impl_forwards_version() {
+    let meta = SecretMetadata {
+        value: SecretValue::from("val"),
+        owner_id: OwnerId::nil(),
+        sharing: SharingMode::Tenant,
+        owner_tenant_id: TenantId::nil(),
+        version: Some(7),
+    };
+    let client = make_wired_client(MockPlugin::returns(Some(&meta)));
+    let key = SecretRef::new("key").unwrap();
+
+    let resp = client
+        .get_version(&test_ctx(), &key, Some(7))
+        .await
+        .unwrap()
+        .expect("expected Some");
+    assert_eq!(resp.version, Some(7));
+
+    let missing = client
+        .get_version(&test_ctx(), &key, Some(6))
+        .await
+        .unwrap();
+    assert!(missing.is_none());
+}
@@ -4,8 +4,9 @@
 //! Plugin discovery is lazy: resolved on first API call after
 //! types-registry is ready.
 
-use std::sync::Arc;
-use std::time::Duration;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
 
 use credstore_sdk::{CredStorePluginClientV1, CredStorePluginSpecV1, GetSecretResponse, SecretRef};
 use modkit::client_hub::{ClientHub, ClientScope};
@@ -13,45 +14,236 @@ use modkit::plugins::{GtsPluginSelector, choose_plugin_instance};
 use modkit::telemetry::ThrottledLog;
 use modkit_macros::domain_model;
 use modkit_security::SecurityContext;
-use tracing::info;
+use tracing::{debug, info};
 use types_registry_sdk::{InstanceQuery, TypesRegistryClient};
+use uuid::Uuid;
 
 use super::error::DomainError;
 
 /// Throttle interval for plugin unavailable warnings.
 const UNAVAILABLE_LOG_THROTTLE: Duration = Duration::from_secs(10);
 
+/// Default backoff after a failed plugin resolution, in milliseconds.
+pub(crate) const DEFAULT_RESOLVE_BACKOFF_MS: u64 = 1000;
+
+/// Default cap on cached "not found" answers.
+const DEFAULT_NEGATIVE_CACHE_MAX: usize = 1024;
+
+/// A vendor in the resolution chain together with its cached plugin selection.
+#[domain_model]
+struct VendorSlot {
+    vendor: String,
+    selector: GtsPluginSelector,
+    /// Last resolution failure and the instant until which it is served
+    /// without querying types-registry again.
+    last_failure: Mutex<Option<(Instant, DomainError)>>,
+}
+
+impl VendorSlot {
+    fn new(vendor: String) -> Self {
+        Self {
+            vendor,
+            selector: GtsPluginSelector::new(),
+            last_failure: Mutex::new(None),
+        }
+    }
+
+    /// The last resolution failure, if its backoff has not elapsed yet.
+    fn recent_failure(&self) -> Option<DomainError> {
+        let guard = self
+            .last_failure
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        guard
+            .as_ref()
+            .filter(|(until, _)| Instant::now() < *until)
+            .map(|(_, err)| err.clone())
+    }
+
+    fn record_failure(&self, err: &DomainError, backoff: Duration) {
+        if backoff.is_zero() {
+            return;
+        }
+        *self
+            .last_failure
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) =
+            Some((Instant::now() + backoff, err.clone()));
+    }
+}
+
+/// Identifies a lookup whose "not found" answer may be replayed.
+///
+/// Scoped to the caller: whether a secret is visible depends on who asks.
+#[domain_model]
+#[derive(Hash, PartialEq, Eq)]
+struct MissKey {
+    tenant_id: Uuid,
+    subject_id: Uuid,
+    key: String,
+    version: Option<u32>,
+}
+
+/// How the vendor chain reported a missing secret, so a cached miss is
+/// returned in the same shape as the original answer.
+#[domain_model]
+#[derive(Clone, Copy)]
+enum Miss {
+    Empty,
+    NotFound,
+}
+
+impl Miss {
+    fn outcome(self) -> Result<Option<GetSecretResponse>, DomainError> {
+        match self {
+            Self::Empty => Ok(None),
+            Self::NotFound => Err(DomainError::NotFound),
+        }
+    }
+}
+
+/// Short-lived cache of "not found" answers, bounded by `max_entries`.
+#[domain_model]
+struct NegativeCache {
+    ttl: Duration,
+    max_entries: usize,
+    entries: Mutex<HashMap<MissKey, (Instant, Miss)>>,
+}
+
+impl NegativeCache {
+    fn new() -> Self {
+        Self {
+            ttl: Duration::ZERO,
+            max_entries: DEFAULT_NEGATIVE_CACHE_MAX,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        !self.ttl.is_zero() && self.max_entries > 0
+    }
+
+    /// The cached miss for `key`, if it has not expired yet.
+    fn get(&self, key: &MissKey) -> Option<Miss> {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        match entries.get(key) {
+            Some((until, miss)) if Instant::now() < *until => Some(*miss),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, key: MissKey, miss: Miss) {
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        if entries.len() >= self.max_entries && !entries.contains_key(&key) {
+            entries.retain(|_, (until, _)| now < *until);
+            if entries.len() >= self.max_entries {
+                entries.clear();
+            }
+        }
+        entries.insert(key, (now + self.ttl, miss));
+    }
+}
+
 /// `CredStore` domain service.
 ///
 /// Discovers plugins via types-registry and delegates storage operations.
+/// The primary vendor is tried first; configured fallback vendors are tried
+/// in order when the previous one has no usable plugin or does not know the
+/// requested secret.
+///
+/// Found secrets are never cached. "Not found" answers can be cached for a
+/// short time; see [`with_negative_cache_ttl`](Self::with_negative_cache_ttl).
 #[domain_model]
 pub struct Service {
     hub: Arc<ClientHub>,
-    vendor: String,
-    selector: GtsPluginSelector,
+    primary: VendorSlot,
+    fallbacks: Vec<VendorSlot>,
+    resolve_backoff: Duration,
+    negative_cache: NegativeCache,
     unavailable_log_throttle: ThrottledLog,
 }
 
 impl Service {
     /// Creates a new service with lazy plugin resolution.
+    ///
+    /// Resolution failures are reused for the default backoff of
+    /// [`DEFAULT_RESOLVE_BACKOFF_MS`]; see
+    /// [`with_resolve_backoff`](Self::with_resolve_backoff).
     #[must_use]
     pub fn new(hub: Arc<ClientHub>, vendor: String) -> Self {
         Self {
             hub,
-            vendor,
-            selector: GtsPluginSelector::new(),
+            primary: VendorSlot::new(vendor),
+            fallbacks: Vec::new(),
+            resolve_backoff: Duration::from_millis(DEFAULT_RESOLVE_BACKOFF_MS),
+            negative_cache: NegativeCache::new(),
             unavailable_log_throttle: ThrottledLog::new(UNAVAILABLE_LOG_THROTTLE),
         }
     }
 
+    /// Serves a failed plugin resolution from cache for `backoff` before
+    /// querying types-registry again, so repeated calls during a registry
+    /// outage do not each trigger a `list`. `Duration::ZERO` disables it.
+    ///
+    /// Successful resolutions are cached permanently regardless.
+    #[must_use]
+    pub fn with_resolve_backoff(mut self, backoff: Duration) -> Self {
+        self.resolve_backoff = backoff;
+        self
+    }
+
+    /// Replays a "not found" answer for `ttl` instead of asking the plugins
+    /// again, so callers probing for optional secrets do not hit the backend
+    /// on every call. `Duration::ZERO` (the default) disables it.
+    ///
+    /// Misses are cached per caller, key and version. A secret created
+    /// meanwhile stays invisible to that caller for at most `ttl`, so keep
+    /// it short.
+    #[must_use]
+    pub fn with_negative_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.negative_cache.ttl = ttl;
+        self
+    }
+
+    /// Caps the number of cached "not found" answers (default: 1024). When
+    /// full, expired entries are evicted first, then the cache is cleared.
+    #[must_use]
+    pub fn with_negative_cache_max(mut self, max_entries: usize) -> Self {
+        self.negative_cache.max_entries = max_entries;
+        self
+    }
+
+    /// Sets the fallback vendors tried, in order, after the primary vendor.
+    #[must_use]
+    pub fn with_vendor_fallbacks(mut self, vendors: Vec<String>) -> Self {
+        self.fallbacks = vendors.into_iter().map(VendorSlot::new).collect();
+        self
+    }
+
+    /// All vendors in resolution order: primary first, then fallbacks.
+    fn vendor_chain(&self) -> impl Iterator<Item = &VendorSlot> {
+        std::iter::once(&self.primary).chain(&self.fallbacks)
+    }
+
     /// Lazily resolves and returns the plugin client.
     ///
     /// # Errors
     ///
-    /// Returns `DomainError::PluginNotFound` if no plugin is registered for the configured vendor.
+    /// Returns `DomainError::PluginNotFound` if no plugin is registered for the vendor.
     /// Returns `DomainError::PluginUnavailable` if the plugin client is not yet registered.
-    async fn get_plugin(&self) -> Result<Arc<dyn CredStorePluginClientV1>, DomainError> {
-        let instance_id = self.selector.get_or_init(|| self.resolve_plugin()).await?;
+    async fn get_plugin(
+        &self,
+        slot: &VendorSlot,
+    ) -> Result<Arc<dyn CredStorePluginClientV1>, DomainError> {
+        let instance_id = slot
+            .selector
+            .get_or_init(|| self.resolve_with_backoff(slot))
+            .await?;
         let scope = ClientScope::gts_id(instance_id.as_ref());
 
         if let Some(client) = self
@@ -63,7 +255,7 @@ impl Service {
             if self.unavailable_log_throttle.should_log() {
                 tracing::warn!(
                     plugin_gts_id = %instance_id,
-                    vendor = %self.vendor,
+                    vendor = %slot.vendor,
                     "CredStore plugin client not registered yet"
                 );
             }
@@ -74,9 +266,26 @@ impl Service {
         }
     }
 
+    /// Resolves the slot's plugin, returning a recent failure instead of
+    /// querying types-registry while its backoff lasts.
+    ///
+    /// Runs under the selector's single-flight lock, so concurrent callers
+    /// waiting on a failed resolution are throttled too.
+    async fn resolve_with_backoff(&self, slot: &VendorSlot) -> Result<String, DomainError> {
+        if let Some(err) = slot.recent_failure() {
+            debug!(vendor = %slot.vendor, error = %err, "Plugin resolution in backoff");
+            return Err(err);
+        }
+        let result = self.resolve_plugin(&slot.vendor).await;
+        if let Err(err) = &result {
+            slot.record_failure(err, self.resolve_backoff);
+        }
+        result
+    }
+
     /// Resolves the plugin instance from types-registry.
-    #[tracing::instrument(skip_all, fields(vendor = %self.vendor))]
-    async fn resolve_plugin(&self) -> Result<String, DomainError> {
+    #[tracing::instrument(skip_all, fields(vendor = %vendor))]
+    async fn resolve_plugin(&self, vendor: &str) -> Result<String, DomainError> {
         info!("Resolving credstore plugin");
 
         let registry = self
@@ -91,7 +300,7 @@ impl Service {
             .await?;
 
         let gts_id = choose_plugin_instance::<CredStorePluginSpecV1>(
-            &self.vendor,
+            vendor,
             instances.iter().map(|e| (e.id.as_ref(), &e.object)),
         )?;
         info!(plugin_gts_id = %gts_id, "Selected credstore plugin instance");
@@ -99,28 +308,109 @@ impl Service {
         Ok(gts_id)
     }
 
-    /// Retrieves a secret from the plugin.
+    /// Retrieves the latest version of a secret.
     ///
     /// Returns `Ok(None)` if the secret is not found (anti-enumeration).
     ///
     /// # Errors
     ///
-    /// Returns a `DomainError` for plugin resolution or backend failures.
-    #[tracing::instrument(skip_all, fields(key = ?key))]
+    /// See [`get_version`](Self::get_version).
     pub async fn get(
         &self,
         ctx: &SecurityContext,
         key: &SecretRef,
     ) -> Result<Option<GetSecretResponse>, DomainError> {
-        let plugin = self.get_plugin().await?;
-
-        let result = plugin.get(ctx, key).await?;
-        Ok(result.map(|meta| GetSecretResponse {
-            value: meta.value,
-            owner_tenant_id: meta.owner_tenant_id,
-            sharing: meta.sharing,
-            is_inherited: false,
-        }))
+        self.get_version(ctx, key, None).await
+    }
+
+    /// Retrieves a secret at `version` (latest if `None`), walking the vendor chain.
+    ///
+    /// Each vendor is tried in order. A vendor is skipped when its plugin
+    /// cannot be found or is not registered yet, or when the plugin does not
+    /// know the secret. Other errors are returned immediately.
+    ///
+    /// Returns `Ok(None)` if the secret is not found (anti-enumeration). With
+    /// a negative cache configured, that answer is reused for later identical
+    /// calls until it expires.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError` for plugin resolution or backend failures. When
+    /// no vendor in the chain has a usable plugin, the last resolution error
+    /// is returned.
+    #[tracing::instrument(skip_all, fields(key = ?key, version = ?version))]
+    pub async fn get_version(
+        &self,
+        ctx: &SecurityContext,
+        key: &SecretRef,
+        version: Option<u32>,
+    ) -> Result<Option<GetSecretResponse>, DomainError> {
+        let miss_key = self.negative_cache.is_enabled().then(|| MissKey {
+            tenant_id: ctx.subject_tenant_id(),
+            subject_id: ctx.subject_id(),
+            key: key.as_ref().to_owned(),
+            version,
+        });
+        if let Some(miss) = miss_key.as_ref().and_then(|k| self.negative_cache.get(k)) {
+            debug!("Secret not found (cached)");
+            return miss.outcome();
+        }
+
+        // A plugin's "not found" answer takes precedence over resolution
+        // errors from other vendors: the lookup itself did happen.
+        let mut not_found = None;
+        let mut last_err = None;
+
+        for slot in self.vendor_chain() {
+            let plugin = match self.get_plugin(slot).await {
+                Ok(plugin) => plugin,
+                Err(
+                    e
+                    @ (DomainError::PluginNotFound { .. } | DomainError::PluginUnavailable { .. }),
+                ) => {
+                    debug!(vendor = %slot.vendor, error = %e, "Skipping credstore vendor");
+                    last_err = Some(e);
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+
+            match plugin
+                .get_version(ctx, key, version)
+                .await
+                .map_err(DomainError::from)
+            {
+                Ok(Some(meta)) => {
+                    return Ok(Some(GetSecretResponse {
+                        value: meta.value,
+                        owner_tenant_id: meta.owner_tenant_id,
+                        sharing: meta.sharing,
+                        is_inherited: false,
+                        version: meta.version,
+                    }));
+                }
+                Ok(None) => {
+                    debug!(vendor = %slot.vendor, "Secret not found, trying next vendor");
+                    not_found = Some(Miss::Empty);
+                }
+                Err(DomainError::NotFound) => {
+                    debug!(vendor = %slot.vendor, "Secret not found, trying next vendor");
+                    not_found = Some(Miss::NotFound);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        match (not_found, last_err) {
+            (Some(miss), _) => {
+                if let Some(miss_key) = miss_key {
+                    self.negative_cache.insert(miss_key, miss);
+                }
+                miss.outcome()
+            }
+            (None, Some(e)) => Err(e),
+            (None, None) => Ok(None),
+        }
     }
 }
 
@@ -76,18 +76,67 @@ async fn get_returns_registry_unavailable_when_hub_empty() {
 
 #[tokio::test]
 async fn get_retries_resolution_on_each_call_when_registry_absent() {
-    // GtsPluginSelector does not cache errors, so each call re-attempts resolution.
-    // Use a failing registry (not an empty hub) so list() is actually invoked and
+    // GtsPluginSelector does not cache errors, so with the backoff disabled each
+    // call re-attempts resolution. Use a failing registry (not an empty hub) so list() is actually invoked and
     // we can assert the call count proves no caching.
     let hub = Arc::new(ClientHub::default());
     let registry = Arc::new(
         MockTypesRegistryClient::new().with_list_error(TypesRegistryError::internal("unavailable")),
     );
     hub.register::<dyn TypesRegistryClient>(registry.clone() as Arc<dyn TypesRegistryClient>);
+    let svc = Service::new(hub, "cyberfabric".into()).with_resolve_backoff(Duration::ZERO);
+    let key = SecretRef::new("my-key").unwrap();
+    assert!(svc.get(&test_ctx(), &key).await.is_err());
+    assert!(svc.get(&test_ctx(), &key).await.is_err());
+    assert_eq!(registry.list_instance_calls(), 2);
+}
+
+fn hub_with_failing_registry() -> (Arc<ClientHub>, Arc<MockTypesRegistryClient>) {
+    let hub = Arc::new(ClientHub::default());
+    let registry = Arc::new(
+        MockTypesRegistryClient::new().with_list_error(TypesRegistryError::internal("unavailable")),
+    );
+    hub.register::<dyn TypesRegistryClient>(registry.clone() as Arc<dyn TypesRegistryClient>);
+    (hub, registry)
+}
+
+#[tokio::test]
+async fn get_throttles_resolution_during_backoff() {
+    let (hub, registry) = hub_with_failing_registry();
+    let svc = Service::new(hub, "cyberfabric".into()).with_resolve_backoff(Duration::from_mins(1));
+    let key = SecretRef::new("my-key").unwrap();
+    for _ in 0..5 {
+        let err = svc.get(&test_ctx(), &key).await.unwrap_err();
+        assert!(
+            matches!(err, DomainError::Internal(ref msg) if msg.contains("unavailable")),
+            "expected cached Internal error, got: {err:?}"
+        );
+    }
+    assert_eq!(registry.list_instance_calls(), 1);
+}
+
+#[tokio::test]
+async fn new_applies_default_resolve_backoff() {
+    let (hub, registry) = hub_with_failing_registry();
     let svc = Service::new(hub, "cyberfabric".into());
     let key = SecretRef::new("my-key").unwrap();
     assert!(svc.get(&test_ctx(), &key).await.is_err());
     assert!(svc.get(&test_ctx(), &key).await.is_err());
+    assert_eq!(registry.list_instance_calls(), 1);
+}
+
+#[tokio::test]
+async fn get_retries_resolution_after_backoff_elapses() {
+    let (hub, registry) = hub_with_failing_registry();
+    let svc =
+        Service::new(hub, "cyberfabric".into()).with_resolve_backoff(Duration::from_millis(10));
+    let key = SecretRef::new("my-key").unwrap();
+    assert!(svc.get(&test_ctx(), &key).await.is_err());
+    assert!(svc.get(&test_ctx(), &key).await.is_err());
+    assert_eq!(registry.list_instance_calls(), 1);
+
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(svc.get(&test_ctx(), &key).await.is_err());
     assert_eq!(registry.list_instance_calls(), 2);
 }
 
@@ -100,7 +149,7 @@ async fn resolve_plugin_returns_plugin_not_found_when_no_instances() {
     hub.register::<dyn TypesRegistryClient>(registry);
 
     let svc = Service::new(hub, "cyberfabric".into());
-    let err = svc.resolve_plugin().await.unwrap_err();
+    let err = svc.resolve_plugin("cyberfabric").await.unwrap_err();
     assert!(
         matches!(err, DomainError::PluginNotFound { .. }),
         "expected PluginNotFound, got: {err:?}"
@@ -117,7 +166,7 @@ async fn resolve_plugin_returns_plugin_not_found_when_vendor_mismatch() {
     hub.register::<dyn TypesRegistryClient>(registry);
 
     let svc = Service::new(hub, "cyberfabric".into());
-    let err = svc.resolve_plugin().await.unwrap_err();
+    let err = svc.resolve_plugin("cyberfabric").await.unwrap_err();
     assert!(
         matches!(err, DomainError::PluginNotFound { .. }),
         "expected PluginNotFound, got: {err:?}"
@@ -137,7 +186,7 @@ async fn resolve_plugin_returns_invalid_when_content_malformed() {
     hub.register::<dyn TypesRegistryClient>(registry);
 
     let svc = Service::new(hub, "cyberfabric".into());
-    let err = svc.resolve_plugin().await.unwrap_err();
+    let err = svc.resolve_plugin("cyberfabric").await.unwrap_err();
     assert!(
         matches!(err, DomainError::InvalidPluginInstance { .. }),
         "expected InvalidPluginInstance, got: {err:?}"
@@ -153,7 +202,7 @@ async fn resolve_plugin_returns_internal_when_registry_list_fails() {
     hub.register::<dyn TypesRegistryClient>(registry);
 
     let svc = Service::new(hub, "cyberfabric".into());
-    let err = svc.resolve_plugin().await.unwrap_err();
+    let err = svc.resolve_plugin("cyberfabric").await.unwrap_err();
     assert!(
         matches!(err, DomainError::Internal(ref msg) if msg.contains("db down")),
         "expected Internal containing 'db down', got: {err:?}"
@@ -166,10 +215,29 @@ async fn resolve_plugin_succeeds_with_matching_vendor() {
     let hub = hub_with_registry_and_plugin(&instance_id, "cyberfabric", MockPlugin::returns(None));
 
     let svc = Service::new(hub, "cyberfabric".into());
-    let resolved = svc.resolve_plugin().await.unwrap();
+    let resolved = svc.resolve_plugin("cyberfabric").await.unwrap();
     assert_eq!(resolved, instance_id);
 }
 
+#[tokio::test]
+async fn resolve_plugin_breaks_priority_ties_by_gts_id() {
+    let first = instance_id_for("alpha");
+    let second = instance_id_for("beta");
+
+    // Registry listing order must not affect the choice.
+    for order in [[&first, &second], [&second, &first]] {
+        let instances = order.map(|id| make_test_instance(id, plugin_content(id, "cyberfabric")));
+        let hub = Arc::new(ClientHub::default());
+        let registry: Arc<dyn TypesRegistryClient> =
+            Arc::new(MockTypesRegistryClient::new().with_instances(instances));
+        hub.register::<dyn TypesRegistryClient>(registry);
+
+        let svc = Service::new(hub, "cyberfabric".into());
+        let resolved = svc.resolve_plugin("cyberfabric").await.unwrap();
+        assert_eq!(resolved, first);
+    }
+}
+
 // ── get_plugin ───────────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -183,7 +251,11 @@ async fn get_plugin_returns_unavailable_when_not_in_hub() {
     hub.register::<dyn TypesRegistryClient>(registry);
 
     let svc = Service::new(hub, "cyberfabric".into());
-    let err = svc.get_plugin().await.err().expect("expected Err");
+    let err = svc
+        .get_plugin(&svc.primary)
+        .await
+        .err()
+        .expect("expected Err");
     assert!(
         matches!(err, DomainError::PluginUnavailable { .. }),
         "expected PluginUnavailable, got: {err:?}"
@@ -200,8 +272,8 @@ async fn get_plugin_caches_resolved_instance() {
     );
 
     let svc = Service::new(hub, "cyberfabric".into());
-    let p1 = svc.get_plugin().await.unwrap();
-    let p2 = svc.get_plugin().await.unwrap();
+    let p1 = svc.get_plugin(&svc.primary).await.unwrap();
+    let p2 = svc.get_plugin(&svc.primary).await.unwrap();
 
     assert_eq!(
         registry.list_instance_calls(),
@@ -224,6 +296,7 @@ async fn get_returns_some_response_on_success() {
         owner_id: OwnerId::nil(),
         sharing: SharingMode::Tenant,
         owner_tenant_id: TenantId::nil(),
+        version: None,
     };
     let hub = hub_with_registry_and_plugin(
         &instance_id,
@@ -270,3 +343,370 @@ async fn get_propagates_plugin_error() {
         "expected Internal, got: {err:?}"
     );
 }
+
+// ── vendor fallbacks ─────────────────────────────────────────────────────
+
+fn instance_id_for(name: &str) -> String {
+    format!(
+        "{}test.credstore.{name}.instance.v1",
+        CredStorePluginSpecV1::gts_schema_id()
+    )
+}
+
+/// `(instance name, vendor, plugin)`; `None` registers no plugin client.
+type VendorSpec<'a> = (&'a str, &'a str, Option<Arc<MockPlugin>>);
+
+/// Wires one registry instance (and optionally a scoped plugin) per vendor.
+fn hub_with_vendors(vendors: Vec<VendorSpec<'_>>) -> Arc<ClientHub> {
+    let hub = Arc::new(ClientHub::default());
+    let mut instances = Vec::new();
+    for (name, vendor, plugin) in vendors {
+        let instance_id = instance_id_for(name);
+        instances.push(make_test_instance(
+            &instance_id,
+            plugin_content(&instance_id, vendor),
+        ));
+        if let Some(plugin) = plugin {
+            hub.register_scoped::<dyn CredStorePluginClientV1>(
+                ClientScope::gts_id(&instance_id),
+                plugin as Arc<dyn CredStorePluginClientV1>,
+            );
+        }
+    }
+    let registry: Arc<dyn TypesRegistryClient> =
+        Arc::new(MockTypesRegistryClient::new().with_instances(instances));
+    hub.register::<dyn TypesRegistryClient>(registry);
+    hub
+}
+
+fn tenant_secret(value: &str) -> SecretMetadata {
+    SecretMetadata {
+        value: SecretValue::from(value),
+        owner_id: OwnerId::nil(),
+        sharing: SharingMode::Tenant,
+        owner_tenant_id: TenantId::nil(),
+        version: None,
+    }
+}
+
+#[tokio::test]
+async fn get_falls_back_when_primary_vendor_has_no_plugin() {
+    let hub = hub_with_vendors(vec![(
+        "fallback",
+        "static",
+        Some(MockPlugin::returns(Some(&tenant_secret("from-static")))),
+    )]);
+
+    let svc = Service::new(hub, "vault".into()).with_vendor_fallbacks(vec!["static".into()]);
+    let key = SecretRef::new("my-key").unwrap();
+    let resp = svc
+        .get(&test_ctx(), &key)
+        .await
+        .unwrap()
+        .expect("expected Some");
+    assert_eq!(resp.value.as_bytes(), b"from-static");
+}
+
+#[tokio::test]
+async fn get_falls_back_when_primary_plugin_unavailable() {
+    let hub = hub_with_vendors(vec![
+        ("primary", "vault", None),
+        (
+            "fallback",
+            "static",
+            Some(MockPlugin::returns(Some(&tenant_secret("from-static")))),
+        ),
+    ]);
+
+    let svc = Service::new(hub, "vault".into()).with_vendor_fallbacks(vec!["static".into()]);
+    let key = SecretRef::new("my-key").unwrap();
+    let resp = svc
+        .get(&test_ctx(), &key)
+        .await
+        .unwrap()
+        .expect("expected Some");
+    assert_eq!(resp.value.as_bytes(), b"from-static");
+}
+
+#[tokio::test]
+async fn get_falls_back_when_primary_does_not_know_secret() {
+    let hub = hub_with_vendors(vec![
+        ("primary", "vault", Some(MockPlugin::returns(None))),
+        (
+            "fallback",
+            "static",
+            Some(MockPlugin::returns(Some(&tenant_secret("from-static")))),
+        ),
+    ]);
+
+    let svc = Service::new(hub, "vault".into()).with_vendor_fallbacks(vec!["static".into()]);
+    let key = SecretRef::new("my-key").unwrap();
+    let resp = svc
+        .get(&test_ctx(), &key)
+        .await
+        .unwrap()
+        .expect("expected Some");
+    assert_eq!(resp.value.as_bytes(), b"from-static");
+}
+
+#[tokio::test]
+async fn get_prefers_primary_vendor_when_it_has_the_secret() {
+    let hub = hub_with_vendors(vec![
+        (
+            "primary",
+            "vault",
+            Some(MockPlugin::returns(Some(&tenant_secret("from-vault")))),
+        ),
+        (
+            "fallback",
+            "static",
+            Some(MockPlugin::returns(Some(&tenant_secret("from-static")))),
+        ),
+    ]);
+
+    let svc = Service::new(hub, "vault".into()).with_vendor_fallbacks(vec!["static".into()]);
+    let key = SecretRef::new("my-key").unwrap();
+    let resp = svc
+        .get(&test_ctx(), &key)
+        .await
+        .unwrap()
+        .expect("expected Some");
+    assert_eq!(resp.value.as_bytes(), b"from-vault");
+}
+
+#[tokio::test]
+async fn get_does_not_fall_back_on_backend_error() {
+    let hub = hub_with_vendors(vec![
+        (
+            "primary",
+            "vault",
+            Some(MockPlugin::errors_internal("backend failure")),
+        ),
+        (
+            "fallback",
+            "static",
+            Some(MockPlugin::returns(Some(&tenant_secret("from-static")))),
+        ),
+    ]);
+
+    let svc = Service::new(hub, "vault".into()).with_vendor_fallbacks(vec!["static".into()]);
+    let key = SecretRef::new("my-key").unwrap();
+    let err = svc.get(&test_ctx(), &key).await.unwrap_err();
+    assert!(
+        matches!(err, DomainError::Internal(_)),
+        "expected Internal, got: {err:?}"
+    );
+}
+
+#[tokio::test]
+async fn get_returns_none_when_no_vendor_knows_secret() {
+    let hub = hub_with_vendors(vec![
+        ("primary", "vault", None),
+        ("fallback", "static", Some(MockPlugin::returns(None))),
+    ]);
+
+    let svc = Service::new(hub, "vault".into()).with_vendor_fallbacks(vec!["static".into()]);
+    let key = SecretRef::new("missing-key").unwrap();
+    let result = svc.get(&test_ctx(), &key).await.unwrap();
+    assert!(result.is_none(), "expected None for missing secret");
+}
+
+#[tokio::test]
+async fn get_returns_plugin_not_found_when_chain_exhausted() {
+    let hub = hub_with_vendors(vec![]);
+
+    let svc = Service::new(hub, "vault".into()).with_vendor_fallbacks(vec!["static".into()]);
+    let key = SecretRef::new("my-key").unwrap();
+    let err = svc.get(&test_ctx(), &key).await.unwrap_err();
+    assert!(
+        matches!(err, DomainError::PluginNotFound { ref vendor } if vendor == "static"),
+        "expected PluginNotFound for the last vendor, got: {err:?}"
+    );
+}
+
+// ── get_version ──────────────────────────────────────────────────────────
+
+fn versioned_secret(value: &str, version: u32) -> SecretMetadata {
+    SecretMetadata {
+        version: Some(version),
+        ..tenant_secret(value)
+    }
+}
+
+#[tokio::test]
+async fn get_reports_plugin_version() {
+    let instance_id = test_instance_id();
+    let hub = hub_with_registry_and_plugin(
+        &instance_id,
+        "cyberfabric",
+        MockPlugin::returns(Some(&versioned_secret("v2-value", 2))),
+    );
+
+    let svc = Service::new(hub, "cyberfabric".into());
+    let key = SecretRef::new("my-key").unwrap();
+    let resp = svc
+        .get(&test_ctx(), &key)
+        .await
+        .unwrap()
+        .expect("expected Some");
+    assert_eq!(resp.version, Some(2));
+}
+
+#[tokio::test]
+async fn get_version_returns_matching_version() {
+    let instance_id = test_instance_id();
+    let hub = hub_with_registry_and_plugin(
+        &instance_id,
+        "cyberfabric",
+        MockPlugin::returns(Some(&versioned_secret("v2-value", 2))),
+    );
+
+    let svc = Service::new(hub, "cyberfabric".into());
+    let key = SecretRef::new("my-key").unwrap();
+    let resp = svc
+        .get_version(&test_ctx(), &key, Some(2))
+        .await
+        .unwrap()
+        .expect("expected Some");
+    assert_eq!(resp.value.as_bytes(), b"v2-value");
+    assert_eq!(resp.version, Some(2));
+}
+
+#[tokio::test]
+async fn get_version_returns_none_for_unknown_version() {
+    // MockPlugin relies on the default `get_version`, which only serves the
+    // version reported by `get`.
+    let instance_id = test_instance_id();
+    let hub = hub_with_registry_and_plugin(
+        &instance_id,
+        "cyberfabric",
+        MockPlugin::returns(Some(&versioned_secret("v2-value", 2))),
+    );
+
+    let svc = Service::new(hub, "cyberfabric".into());
+    let key = SecretRef::new("my-key").unwrap();
+    let result = svc.get_version(&test_ctx(), &key, Some(1)).await.unwrap();
+    assert!(result.is_none(), "expected None for unknown version");
+}
+
+// ── negative cache ───────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn get_without_negative_cache_asks_plugin_every_time() {
+    let instance_id = test_instance_id();
+    let plugin = MockPlugin::returns(None);
+    let hub = hub_with_registry_and_plugin(&instance_id, "cyberfabric", plugin.clone());
+
+    let svc = Service::new(hub, "cyberfabric".into());
+    let key = SecretRef::new("optional-key").unwrap();
+    for _ in 0..3 {
+        assert!(svc.get(&test_ctx(), &key).await.unwrap().is_none());
+    }
+    assert_eq!(plugin.calls(), 3);
+}
+
+#[tokio::test]
+async fn get_serves_repeated_misses_from_negative_cache() {
+    let instance_id = test_instance_id();
+    let plugin = MockPlugin::returns(None);
+    let hub = hub_with_registry_and_plugin(&instance_id, "cyberfabric", plugin.clone());
+
+    let svc =
+        Service::new(hub, "cyberfabric".into()).with_negative_cache_ttl(Duration::from_mins(1));
+    let key = SecretRef::new("optional-key").unwrap();
+    for _ in 0..10 {
+        assert!(svc.get(&test_ctx(), &key).await.unwrap().is_none());
+    }
+    assert_eq!(
+        plugin.calls(),
+        1,
+        "repeated misses must not reach the plugin"
+    );
+}
+
+#[tokio::test]
+async fn negative_cache_replays_not_found_error() {
+    let instance_id = test_instance_id();
+    let plugin = MockPlugin::errors_not_found();
+    let hub = hub_with_registry_and_plugin(&instance_id, "cyberfabric", plugin.clone());
+
+    let svc =
+        Service::new(hub, "cyberfabric".into()).with_negative_cache_ttl(Duration::from_mins(1));
+    let key = SecretRef::new("optional-key").unwrap();
+    for _ in 0..2 {
+        let err = svc.get(&test_ctx(), &key).await.unwrap_err();
+        assert!(matches!(err, DomainError::NotFound), "got: {err:?}");
+    }
+    assert_eq!(plugin.calls(), 1);
+}
+
+#[tokio::test]
+async fn negative_cache_entries_expire() {
+    let instance_id = test_instance_id();
+    let plugin = MockPlugin::returns(None);
+    let hub = hub_with_registry_and_plugin(&instance_id, "cyberfabric", plugin.clone());
+
+    let svc =
+        Service::new(hub, "cyberfabric".into()).with_negative_cache_ttl(Duration::from_millis(20));
+    let key = SecretRef::new("optional-key").unwrap();
+    svc.get(&test_ctx(), &key).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(40)).await;
+    svc.get(&test_ctx(), &key).await.unwrap();
+
+    assert_eq!(plugin.calls(), 2);
+}
+
+#[tokio::test]
+async fn negative_cache_is_scoped_to_caller_and_version() {
+    let instance_id = test_instance_id();
+    let plugin = MockPlugin::returns(None);
+    let hub = hub_with_registry_and_plugin(&instance_id, "cyberfabric", plugin.clone());
+
+    let svc =
+        Service::new(hub, "cyberfabric".into()).with_negative_cache_ttl(Duration::from_mins(1));
+    let key = SecretRef::new("optional-key").unwrap();
+    let other_ctx = SecurityContext::builder()
+        .subject_id(Uuid::from_u128(1))
+        .subject_tenant_id(Uuid::nil())
+        .build()
+        .unwrap();
+
+    svc.get(&test_ctx(), &key).await.unwrap();
+    svc.get(&other_ctx, &key).await.unwrap();
+    svc.get_version(&test_ctx(), &key, Some(1)).await.unwrap();
+    svc.get(&test_ctx(), &key).await.unwrap();
+
+    assert_eq!(plugin.calls(), 3);
+}
+
+#[tokio::test]
+async fn negative_cache_is_bounded() {
+    let instance_id = test_instance_id();
+    let plugin = MockPlugin::returns(None);
+    let hub = hub_with_registry_and_plugin(&instance_id, "cyberfabric", plugin.clone());
+
+    let svc = Service::new(hub, "cyberfabric".into())
+        .with_negative_cache_ttl(Duration::from_mins(1))
+        .with_negative_cache_max(2);
+    for name in ["a", "b", "c"] {
+        svc.get(&test_ctx(), &SecretRef::new(name).unwrap())
+            .await
+            .unwrap();
+    }
+    assert!(svc.negative_cache.entries.lock().unwrap().len() <= 2);
+}
+
+#[tokio::test]
+async fn negative_cache_does_not_hold_found_secrets() {
+    let instance_id = test_instance_id();
+    let plugin = MockPlugin::returns(Some(&tenant_secret("value")));
+    let hub = hub_with_registry_and_plugin(&instance_id, "cyberfabric", plugin.clone());
+
+    let svc =
+        Service::new(hub, "cyberfabric".into()).with_negative_cache_ttl(Duration::from_mins(1));
+    let key = SecretRef::new("my-key").unwrap();
+    for _ in 0..2 {
+        assert!(svc.get(&test_ctx(), &key).await.unwrap().is_some());
+    }
+    assert_eq!(plugin.calls(), 2);
+}
@@ -4,6 +4,7 @@
 //! `make_test_instance` from `types_registry_sdk::testing` directly.
 
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use async_trait::async_trait;
 use credstore_sdk::{
@@ -37,39 +38,49 @@ type PluginFn = Arc<dyn Fn() -> Result<Option<SecretMetadata>, CredStoreError> +
 
 pub struct MockPlugin {
     handler: PluginFn,
+    calls: AtomicUsize,
 }
 
 impl MockPlugin {
+    fn with_handler(handler: PluginFn) -> Arc<Self> {
+        Arc::new(Self {
+            handler,
+            calls: AtomicUsize::new(0),
+        })
+    }
+
+    /// Number of `get` calls received so far.
+    #[must_use]
+    pub fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+
     #[must_use]
     pub fn returns(meta: Option<&SecretMetadata>) -> Arc<Self> {
         let bytes = meta.map(|m| m.value.as_bytes().to_vec());
         let owner_id = meta.map_or(OwnerId::nil(), |m| m.owner_id);
         let sharing = meta.map_or(SharingMode::Tenant, |m| m.sharing);
         let owner_tenant_id = meta.map_or(TenantId::nil(), |m| m.owner_tenant_id);
-        Arc::new(Self {
-            handler: Arc::new(move || {
-                Ok(bytes.as_ref().map(|b| SecretMetadata {
-                    value: SecretValue::new(b.clone()),
-                    owner_id,
-                    sharing,
-                    owner_tenant_id,
-                }))
-            }),
-        })
+        let version = meta.and_then(|m| m.version);
+        Self::with_handler(Arc::new(move || {
+            Ok(bytes.as_ref().map(|b| SecretMetadata {
+                value: SecretValue::new(b.clone()),
+                owner_id,
+                sharing,
+                owner_tenant_id,
+                version,
+            }))
+        }))
     }
 
     #[must_use]
     pub fn errors_not_found() -> Arc<Self> {
-        Arc::new(Self {
-            handler: Arc::new(|| Err(CredStoreError::NotFound)),
-        })
+        Self::with_handler(Arc::new(|| Err(CredStoreError::NotFound)))
     }
 
     #[must_use]
     pub fn errors_internal(msg: &'static str) -> Arc<Self> {
-        Arc::new(Self {
-            handler: Arc::new(move || Err(CredStoreError::Internal(msg.into()))),
-        })
+        Self::with_handler(Arc::new(move || Err(CredStoreError::Internal(msg.into()))))
     }
 }
 
@@ -80,6 +91,7 @@ impl CredStorePluginClientV1 for MockPlugin {
         _ctx: &SecurityContext,
         _key: &SecretRef,
     ) -> Result<Option<SecretMetadata>, CredStoreError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
         (self.handler)()
     }
 }
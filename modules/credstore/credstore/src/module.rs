@@ -1,6 +1,7 @@
 //! `CredStore` module.
 
 use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 
 use async_trait::async_trait;
 use credstore_sdk::{CredStoreClientV1, CredStorePluginSpecV1};
@@ -42,7 +43,7 @@ impl Module for CredStoreModule {
     async fn init(&self, ctx: &ModuleCtx) -> anyhow::Result<()> {
         let cfg: CredStoreConfig = ctx.config_or_default()?;
         tracing::Span::current().record("vendor", cfg.vendor.as_str());
-        info!(vendor = %cfg.vendor);
+        info!(vendor = %cfg.vendor, vendor_fallbacks = ?cfg.vendor_fallbacks);
 
         // Register plugin schema in types-registry
         let registry = ctx.client_hub().get::<dyn TypesRegistryClient>()?;
@@ -57,7 +58,13 @@ impl Module for CredStoreModule {
 
         // Create domain service
         let hub = ctx.client_hub();
-        let svc = Arc::new(Service::new(hub, cfg.vendor));
+        let svc = Arc::new(
+            Service::new(hub, cfg.vendor)
+                .with_vendor_fallbacks(cfg.vendor_fallbacks)
+                .with_resolve_backoff(Duration::from_millis(cfg.resolve_backoff_ms))
+                .with_negative_cache_ttl(Duration::from_millis(cfg.negative_cache_ttl_ms))
+                .with_negative_cache_max(cfg.negative_cache_max_entries),
+        );
         self.service
             .set(svc.clone())
             .map_err(|_| anyhow::anyhow!("{} module already initialized", Self::MODULE_NAME))?;
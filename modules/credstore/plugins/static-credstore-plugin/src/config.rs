@@ -73,6 +73,15 @@ pub struct SecretConfig {
     /// - `tenant_id=Some`, `owner_id=None` → `Tenant`
     /// - `tenant_id=Some`, `owner_id=Some` → `Private`
     pub sharing: Option<SharingMode>,
+
+    /// Whether the secret may be read by tenants other than its owner
+    /// (descendants resolving it through the hierarchy).
+    ///
+    /// When `None`, derived from the sharing mode: only `Shared` secrets are
+    /// inheritable. Set `false` on a tenant-scoped `Shared` secret to keep it
+    /// from leaking to other tenants. `true` is rejected for non-`Shared`
+    /// secrets and `false` for global secrets.
+    pub inheritable: Option<bool>,
 }
 
 impl SecretConfig {
@@ -87,6 +96,14 @@ impl SecretConfig {
                 (Some(_), Some(_)) => SharingMode::Private,
             })
     }
+
+    /// Resolve the effective inheritability from the explicit value or the
+    /// resolved sharing mode.
+    #[must_use]
+    pub fn resolve_inheritable(&self) -> bool {
+        self.inheritable
+            .unwrap_or(self.resolve_sharing() == SharingMode::Shared)
+    }
 }
 
 impl core::fmt::Debug for SecretConfig {
@@ -97,6 +114,7 @@ impl core::fmt::Debug for SecretConfig {
             .field("key", &self.key)
             .field("value", &"<redacted>")
             .field("sharing", &self.resolve_sharing())
+            .field("inheritable", &self.resolve_inheritable())
             .finish()
     }
 }
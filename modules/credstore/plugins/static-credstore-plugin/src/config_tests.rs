@@ -68,6 +68,22 @@ secrets:
     assert_eq!(cfg.secrets[0].resolve_sharing(), SharingMode::Shared);
 }
 
+#[test]
+fn config_parses_inheritable_flag() {
+    let yaml = r#"
+secrets:
+  - tenant_id: "00000000-0000-0000-0000-000000000001"
+    key: "key"
+    value: "val"
+    sharing: "shared"
+    inheritable: false
+"#;
+
+    let cfg: StaticCredStorePluginConfig = serde_saphyr::from_str(yaml).unwrap();
+    assert_eq!(cfg.secrets[0].inheritable, Some(false));
+    assert!(!cfg.secrets[0].resolve_inheritable());
+}
+
 #[test]
 fn config_rejects_unknown_fields() {
     let yaml = r#"
@@ -37,6 +37,8 @@ impl CredStorePluginClientV1 for Service {
             owner_id,
             sharing: entry.sharing,
             owner_tenant_id,
+            // Static secrets are not versioned.
+            version: None,
         }))
     }
 }
@@ -36,6 +36,7 @@ fn service_with_single_secret() -> Service {
             key: "openai_api_key".to_owned(),
             value: "sk-test-123".to_owned(),
             sharing: None,
+            inheritable: None,
         }],
         ..StaticCredStorePluginConfig::default()
     };
@@ -59,6 +60,22 @@ async fn get_returns_metadata_for_matching_tenant_and_owner() {
     assert_eq!(metadata.owner_tenant_id, TenantId(tenant_a()));
 }
 
+#[tokio::test]
+async fn get_version_serves_latest_only() {
+    let service = service_with_single_secret();
+    let plugin: &dyn CredStorePluginClientV1 = &service;
+    let key = SecretRef::new("openai_api_key").unwrap();
+    let ctx = ctx(tenant_a(), owner_a());
+
+    let latest = plugin.get_version(&ctx, &key, None).await.unwrap().unwrap();
+    assert_eq!(latest.value.as_bytes(), b"sk-test-123");
+    assert_eq!(latest.version, None);
+
+    // Static secrets are unversioned, so no specific version can be served.
+    let pinned = plugin.get_version(&ctx, &key, Some(1)).await.unwrap();
+    assert!(pinned.is_none());
+}
+
 #[tokio::test]
 async fn get_returns_none_for_other_tenant() {
     let service = service_with_single_secret();
@@ -110,6 +127,7 @@ async fn shared_secret_resolves_owner_from_context() {
             key: "global_key".to_owned(),
             value: "global-val".to_owned(),
             sharing: None,
+            inheritable: None,
         }],
         ..StaticCredStorePluginConfig::default()
     };
@@ -139,6 +157,7 @@ async fn tenant_secret_resolves_owner_from_context() {
             key: "scoped_key".to_owned(),
             value: "scoped-val".to_owned(),
             sharing: None,
+            inheritable: None,
         }],
         ..StaticCredStorePluginConfig::default()
     };
@@ -168,6 +187,7 @@ async fn private_takes_precedence_over_tenant_and_shared_via_plugin() {
                 key: "k".to_owned(),
                 value: "shared-val".to_owned(),
                 sharing: None,
+                inheritable: None,
             },
             SecretConfig {
                 tenant_id: Some(tenant_a()),
@@ -175,6 +195,7 @@ async fn private_takes_precedence_over_tenant_and_shared_via_plugin() {
                 key: "k".to_owned(),
                 value: "tenant-val".to_owned(),
                 sharing: None,
+                inheritable: None,
             },
             SecretConfig {
                 tenant_id: Some(tenant_a()),
@@ -182,6 +203,7 @@ async fn private_takes_precedence_over_tenant_and_shared_via_plugin() {
                 key: "k".to_owned(),
                 value: "private-val".to_owned(),
                 sharing: None,
+                inheritable: None,
             },
         ],
         ..StaticCredStorePluginConfig::default()
@@ -15,6 +15,29 @@ pub struct SecretEntry {
     pub sharing: SharingMode,
     pub owner_id: OwnerId,
     pub owner_tenant_id: TenantId,
+    /// Whether tenants other than `owner_tenant_id` may read this entry.
+    pub inheritable: bool,
+}
+
+impl SecretEntry {
+    /// Whether a subject of `tenant_id` may read this entry under its
+    /// `SharingMode`:
+    ///
+    /// - `Private` — only the owner, within the owning tenant.
+    /// - `Tenant` — any subject of the owning tenant.
+    /// - `Shared` — any tenant if the entry is `inheritable` (descendant
+    ///   visibility is resolved by the gateway; global entries have no owning
+    ///   tenant at all), otherwise only the owning tenant.
+    #[must_use]
+    pub fn is_accessible_by(&self, tenant_id: TenantId, subject_id: OwnerId) -> bool {
+        match self.sharing {
+            SharingMode::Private => {
+                self.owner_tenant_id == tenant_id && self.owner_id == subject_id
+            }
+            SharingMode::Tenant => self.owner_tenant_id == tenant_id,
+            SharingMode::Shared => self.inheritable || self.owner_tenant_id == tenant_id,
+        }
+    }
 }
 
 /// Static credstore service.
@@ -57,6 +80,8 @@ impl Service {
     /// - a secret without `owner_id` has an explicit `SharingMode::Private`
     /// - `tenant_id` or `owner_id` is an explicit nil UUID
     /// - `owner_id` is set without `tenant_id`
+    /// - `inheritable: true` on a non-`Shared` secret, or `inheritable: false`
+    ///   on a global secret
     pub fn from_config(cfg: &StaticCredStorePluginConfig) -> anyhow::Result<Self> {
         let mut private_secrets: HashMap<(TenantId, OwnerId, SecretRef), SecretEntry> =
             HashMap::new();
@@ -96,6 +121,21 @@ impl Service {
                 );
             }
 
+            let inheritable = entry.resolve_inheritable();
+            if inheritable && sharing != SharingMode::Shared {
+                anyhow::bail!(
+                    "secret '{}': inheritable is only valid for shared sharing mode, \
+                     but resolved sharing is {sharing:?}",
+                    entry.key
+                );
+            }
+            if entry.inheritable == Some(false) && entry.tenant_id.is_none() {
+                anyhow::bail!(
+                    "secret '{}': global secrets are always inheritable",
+                    entry.key
+                );
+            }
+
             let key = SecretRef::new(&entry.key)?;
 
             match (sharing, entry.tenant_id) {
@@ -106,6 +146,7 @@ impl Service {
                         sharing,
                         owner_id: OwnerId::nil(),
                         owner_tenant_id: TenantId::nil(),
+                        inheritable,
                     };
                     if global_secrets.contains_key(&key) {
                         anyhow::bail!("duplicate global secret key '{}'", entry.key);
@@ -121,6 +162,7 @@ impl Service {
                         sharing,
                         owner_id: OwnerId::nil(),
                         owner_tenant_id: tenant_id,
+                        inheritable,
                     };
                     let map_key = (tenant_id, key);
                     if shared_secrets.contains_key(&map_key) {
@@ -144,6 +186,7 @@ impl Service {
                         sharing,
                         owner_id: OwnerId::nil(),
                         owner_tenant_id: tenant_id,
+                        inheritable,
                     };
                     let map_key = (tenant_id, key);
                     if tenant_secrets.contains_key(&map_key) {
@@ -174,6 +217,7 @@ impl Service {
                         sharing,
                         owner_id,
                         owner_tenant_id: tenant_id,
+                        inheritable,
                     };
                     let map_key = (tenant_id, owner_id, key);
                     if private_secrets.contains_key(&map_key) {
@@ -200,16 +244,31 @@ impl Service {
     /// Look up a secret using the caller's security context.
     ///
     /// Lookup order: **Private → Tenant → Shared → Global** (most specific first).
+    ///
+    /// Every candidate is also checked against its `SharingMode` via
+    /// [`SecretEntry::is_accessible_by`], so access control does not rely on
+    /// the map keys alone. An entry that fails the check is skipped and the
+    /// next scope is tried.
     #[must_use]
     pub fn get(&self, ctx: &SecurityContext, key: &SecretRef) -> Option<&SecretEntry> {
         let tenant_id = TenantId(ctx.subject_tenant_id());
         let subject_id = OwnerId(ctx.subject_id());
+        let accessible = |entry: &&SecretEntry| entry.is_accessible_by(tenant_id, subject_id);
 
         self.private_secrets
             .get(&(tenant_id, subject_id, key.clone()))
-            .or_else(|| self.tenant_secrets.get(&(tenant_id, key.clone())))
-            .or_else(|| self.shared_secrets.get(&(tenant_id, key.clone())))
-            .or_else(|| self.global_secrets.get(key))
+            .filter(accessible)
+            .or_else(|| {
+                self.tenant_secrets
+                    .get(&(tenant_id, key.clone()))
+                    .filter(accessible)
+            })
+            .or_else(|| {
+                self.shared_secrets
+                    .get(&(tenant_id, key.clone()))
+                    .filter(accessible)
+            })
+            .or_else(|| self.global_secrets.get(key).filter(accessible))
     }
 }
 
@@ -36,6 +36,7 @@ fn cfg_with_single_secret() -> StaticCredStorePluginConfig {
             key: "openai_api_key".to_owned(),
             value: "sk-test-123".to_owned(),
             sharing: None,
+            inheritable: None,
         }],
         ..StaticCredStorePluginConfig::default()
     }
@@ -50,6 +51,7 @@ fn from_config_rejects_invalid_secret_ref() {
             key: "invalid:key".to_owned(),
             value: "value".to_owned(),
             sharing: None,
+            inheritable: None,
         }],
         ..StaticCredStorePluginConfig::default()
     };
@@ -115,6 +117,7 @@ fn tenant_secret_returned_for_any_subject_in_same_tenant() {
             key: "team_key".to_owned(),
             value: "team-val".to_owned(),
             sharing: None,
+            inheritable: None,
         }],
         ..StaticCredStorePluginConfig::default()
     };
@@ -142,6 +145,7 @@ fn global_secret_returned_for_any_tenant_and_subject() {
             key: "global_key".to_owned(),
             value: "global-val".to_owned(),
             sharing: None,
+            inheritable: None,
         }],
         ..StaticCredStorePluginConfig::default()
     };
@@ -167,6 +171,7 @@ fn shared_secret_returned_only_for_owning_tenant() {
             key: "shared_key".to_owned(),
             value: "shared-val".to_owned(),
             sharing: Some(SharingMode::Shared),
+            inheritable: None,
         }],
         ..StaticCredStorePluginConfig::default()
     };
@@ -196,6 +201,7 @@ fn private_takes_precedence_over_tenant_shared_and_global() {
                 key: "k".to_owned(),
                 value: "global-val".to_owned(),
                 sharing: None,
+                inheritable: None,
             },
             SecretConfig {
                 tenant_id: Some(tenant_a()),
@@ -203,6 +209,7 @@ fn private_takes_precedence_over_tenant_shared_and_global() {
                 key: "k".to_owned(),
                 value: "shared-val".to_owned(),
                 sharing: Some(SharingMode::Shared),
+                inheritable: None,
             },
             SecretConfig {
                 tenant_id: Some(tenant_a()),
@@ -210,6 +217,7 @@ fn private_takes_precedence_over_tenant_shared_and_global() {
                 key: "k".to_owned(),
                 value: "tenant-val".to_owned(),
                 sharing: None,
+                inheritable: None,
             },
             SecretConfig {
                 tenant_id: Some(tenant_a()),
@@ -217,6 +225,7 @@ fn private_takes_precedence_over_tenant_shared_and_global() {
                 key: "k".to_owned(),
                 value: "private-val".to_owned(),
                 sharing: None,
+                inheritable: None,
             },
         ],
         ..StaticCredStorePluginConfig::default()
@@ -250,6 +259,7 @@ fn tenant_takes_precedence_over_shared_and_global() {
                 key: "k".to_owned(),
                 value: "global-val".to_owned(),
                 sharing: None,
+                inheritable: None,
             },
             SecretConfig {
                 tenant_id: Some(tenant_a()),
@@ -257,6 +267,7 @@ fn tenant_takes_precedence_over_shared_and_global() {
                 key: "k".to_owned(),
                 value: "shared-val".to_owned(),
                 sharing: Some(SharingMode::Shared),
+                inheritable: None,
             },
             SecretConfig {
                 tenant_id: Some(tenant_a()),
@@ -264,6 +275,7 @@ fn tenant_takes_precedence_over_shared_and_global() {
                 key: "k".to_owned(),
                 value: "tenant-val".to_owned(),
                 sharing: None,
+                inheritable: None,
             },
         ],
         ..StaticCredStorePluginConfig::default()
@@ -288,6 +300,7 @@ fn shared_takes_precedence_over_global() {
                 key: "k".to_owned(),
                 value: "global-val".to_owned(),
                 sharing: None,
+                inheritable: None,
             },
             SecretConfig {
                 tenant_id: Some(tenant_a()),
@@ -295,6 +308,7 @@ fn shared_takes_precedence_over_global() {
                 key: "k".to_owned(),
                 value: "shared-val".to_owned(),
                 sharing: Some(SharingMode::Shared),
+                inheritable: None,
             },
         ],
         ..StaticCredStorePluginConfig::default()
@@ -322,6 +336,7 @@ fn from_config_rejects_duplicate_private_key() {
         key: "dup".to_owned(),
         value: "v1".to_owned(),
         sharing: None,
+        inheritable: None,
     };
     let cfg = StaticCredStorePluginConfig {
         secrets: vec![
@@ -354,6 +369,7 @@ fn from_config_rejects_duplicate_tenant_key() {
                 key: "dup".to_owned(),
                 value: "v1".to_owned(),
                 sharing: None,
+                inheritable: None,
             },
             SecretConfig {
                 tenant_id: Some(tenant_a()),
@@ -361,6 +377,7 @@ fn from_config_rejects_duplicate_tenant_key() {
                 key: "dup".to_owned(),
                 value: "v2".to_owned(),
                 sharing: None,
+                inheritable: None,
             },
         ],
         ..StaticCredStorePluginConfig::default()
@@ -385,6 +402,7 @@ fn from_config_rejects_duplicate_global_key() {
                 key: "dup".to_owned(),
                 value: "v1".to_owned(),
                 sharing: None,
+                inheritable: None,
             },
             SecretConfig {
                 tenant_id: None,
@@ -392,6 +410,7 @@ fn from_config_rejects_duplicate_global_key() {
                 key: "dup".to_owned(),
                 value: "v2".to_owned(),
                 sharing: None,
+                inheritable: None,
             },
         ],
         ..StaticCredStorePluginConfig::default()
@@ -416,6 +435,7 @@ fn from_config_rejects_duplicate_shared_key() {
                 key: "dup".to_owned(),
                 value: "v1".to_owned(),
                 sharing: Some(SharingMode::Shared),
+                inheritable: None,
             },
             SecretConfig {
                 tenant_id: Some(tenant_a()),
@@ -423,6 +443,7 @@ fn from_config_rejects_duplicate_shared_key() {
                 key: "dup".to_owned(),
                 value: "v2".to_owned(),
                 sharing: Some(SharingMode::Shared),
+                inheritable: None,
             },
         ],
         ..StaticCredStorePluginConfig::default()
@@ -449,6 +470,7 @@ fn from_config_rejects_non_shared_global_secret() {
                 key: "global_key".to_owned(),
                 value: "val".to_owned(),
                 sharing: Some(mode),
+                inheritable: None,
             }],
             ..StaticCredStorePluginConfig::default()
         };
@@ -469,6 +491,7 @@ fn from_config_rejects_private_without_owner_id() {
             key: "private_key".to_owned(),
             value: "val".to_owned(),
             sharing: Some(SharingMode::Private),
+            inheritable: None,
         }],
         ..StaticCredStorePluginConfig::default()
     };
@@ -491,6 +514,7 @@ fn from_config_rejects_owner_id_without_tenant_id() {
             key: "bad_key".to_owned(),
             value: "val".to_owned(),
             sharing: None,
+            inheritable: None,
         }],
         ..StaticCredStorePluginConfig::default()
     };
@@ -517,6 +541,7 @@ fn from_config_rejects_owner_id_for_non_private() {
                 key: "bad_key".to_owned(),
                 value: "val".to_owned(),
                 sharing: Some(mode),
+                inheritable: None,
             }],
             ..StaticCredStorePluginConfig::default()
         };
@@ -543,6 +568,7 @@ fn from_config_accepts_shared_with_tenant_id() {
             key: "k".to_owned(),
             value: "v".to_owned(),
             sharing: Some(SharingMode::Shared),
+            inheritable: None,
         }],
         ..StaticCredStorePluginConfig::default()
     };
@@ -563,6 +589,7 @@ fn from_config_rejects_nil_tenant_id() {
             key: "k".to_owned(),
             value: "v".to_owned(),
             sharing: None,
+            inheritable: None,
         }],
         ..StaticCredStorePluginConfig::default()
     };
@@ -585,6 +612,7 @@ fn from_config_rejects_nil_owner_id() {
             key: "k".to_owned(),
             value: "v".to_owned(),
             sharing: None,
+            inheritable: None,
         }],
         ..StaticCredStorePluginConfig::default()
     };
@@ -609,6 +637,7 @@ fn default_sharing_is_shared_for_global() {
             key: "g".to_owned(),
             value: "v".to_owned(),
             sharing: None,
+            inheritable: None,
         }],
         ..StaticCredStorePluginConfig::default()
     };
@@ -632,6 +661,7 @@ fn default_sharing_is_tenant_for_scoped_without_owner() {
             key: "t".to_owned(),
             value: "v".to_owned(),
             sharing: None,
+            inheritable: None,
         }],
         ..StaticCredStorePluginConfig::default()
     };
@@ -655,6 +685,7 @@ fn default_sharing_is_private_for_scoped_with_owner() {
             key: "p".to_owned(),
             value: "v".to_owned(),
             sharing: None,
+            inheritable: None,
         }],
         ..StaticCredStorePluginConfig::default()
     };
@@ -679,6 +710,7 @@ fn explicit_sharing_overrides_default() {
             key: "k".to_owned(),
             value: "v".to_owned(),
             sharing: Some(SharingMode::Shared),
+            inheritable: None,
         }],
         ..StaticCredStorePluginConfig::default()
     };
@@ -705,6 +737,7 @@ fn allows_same_key_in_different_tenants() {
                 key: "api_key".to_owned(),
                 value: "val-a".to_owned(),
                 sharing: None,
+                inheritable: None,
             },
             SecretConfig {
                 tenant_id: Some(tenant_b()),
@@ -712,6 +745,7 @@ fn allows_same_key_in_different_tenants() {
                 key: "api_key".to_owned(),
                 value: "val-b".to_owned(),
                 sharing: None,
+                inheritable: None,
             },
         ],
         ..StaticCredStorePluginConfig::default()
@@ -747,6 +781,7 @@ fn same_key_across_all_four_scopes() {
                 key: "k".to_owned(),
                 value: "global".to_owned(),
                 sharing: None,
+                inheritable: None,
             },
             SecretConfig {
                 tenant_id: Some(tenant_a()),
@@ -754,6 +789,7 @@ fn same_key_across_all_four_scopes() {
                 key: "k".to_owned(),
                 value: "shared".to_owned(),
                 sharing: Some(SharingMode::Shared),
+                inheritable: None,
             },
             SecretConfig {
                 tenant_id: Some(tenant_a()),
@@ -761,6 +797,7 @@ fn same_key_across_all_four_scopes() {
                 key: "k".to_owned(),
                 value: "tenant".to_owned(),
                 sharing: None,
+                inheritable: None,
             },
             SecretConfig {
                 tenant_id: Some(tenant_a()),
@@ -768,6 +805,7 @@ fn same_key_across_all_four_scopes() {
                 key: "k".to_owned(),
                 value: "private".to_owned(),
                 sharing: None,
+                inheritable: None,
             },
         ],
         ..StaticCredStorePluginConfig::default()
@@ -775,3 +813,143 @@ fn same_key_across_all_four_scopes() {
 
     assert!(Service::from_config(&cfg).is_ok());
 }
+
+fn entry(sharing: SharingMode, owner: Uuid, tenant: Uuid) -> SecretEntry {
+    SecretEntry {
+        value: SecretValue::from("v"),
+        sharing,
+        owner_id: OwnerId(owner),
+        owner_tenant_id: TenantId(tenant),
+        inheritable: sharing == SharingMode::Shared,
+    }
+}
+
+#[test]
+fn private_entry_accessible_only_by_owner_in_owning_tenant() {
+    let e = entry(SharingMode::Private, owner_a(), tenant_a());
+    assert!(e.is_accessible_by(TenantId(tenant_a()), OwnerId(owner_a())));
+    assert!(!e.is_accessible_by(TenantId(tenant_a()), OwnerId(owner_b())));
+    assert!(!e.is_accessible_by(TenantId(tenant_b()), OwnerId(owner_a())));
+}
+
+#[test]
+fn tenant_entry_accessible_by_any_subject_of_owning_tenant() {
+    let e = entry(SharingMode::Tenant, Uuid::nil(), tenant_a());
+    assert!(e.is_accessible_by(TenantId(tenant_a()), OwnerId(owner_a())));
+    assert!(e.is_accessible_by(TenantId(tenant_a()), OwnerId(owner_b())));
+    assert!(!e.is_accessible_by(TenantId(tenant_b()), OwnerId(owner_a())));
+}
+
+#[test]
+fn shared_entry_accessible_across_tenants() {
+    let e = entry(SharingMode::Shared, Uuid::nil(), tenant_a());
+    assert!(e.is_accessible_by(TenantId(tenant_b()), OwnerId(owner_b())));
+}
+
+#[test]
+fn non_inheritable_shared_entry_accessible_only_by_owning_tenant() {
+    let e = SecretEntry {
+        inheritable: false,
+        ..entry(SharingMode::Shared, Uuid::nil(), tenant_a())
+    };
+    assert!(e.is_accessible_by(TenantId(tenant_a()), OwnerId(owner_a())));
+    assert!(!e.is_accessible_by(TenantId(tenant_b()), OwnerId(owner_b())));
+}
+
+fn shared_secret(inheritable: Option<bool>) -> SecretConfig {
+    SecretConfig {
+        tenant_id: Some(tenant_a()),
+        owner_id: None,
+        key: "k".to_owned(),
+        value: "v".to_owned(),
+        sharing: Some(SharingMode::Shared),
+        inheritable,
+    }
+}
+
+#[test]
+fn inheritable_defaults_from_sharing_mode() {
+    assert!(shared_secret(None).resolve_inheritable());
+    assert!(
+        !SecretConfig {
+            sharing: None,
+            ..shared_secret(None)
+        }
+        .resolve_inheritable()
+    );
+}
+
+#[test]
+fn from_config_stores_explicit_non_inheritable_flag() {
+    let cfg = StaticCredStorePluginConfig {
+        secrets: vec![shared_secret(Some(false))],
+        ..StaticCredStorePluginConfig::default()
+    };
+    let service = Service::from_config(&cfg).unwrap();
+    let key = SecretRef::new("k").unwrap();
+
+    let e = service.get(&ctx(tenant_a(), owner_a()), &key).unwrap();
+    assert!(!e.inheritable);
+    assert!(!e.is_accessible_by(TenantId(tenant_b()), OwnerId(owner_b())));
+}
+
+#[test]
+fn from_config_rejects_inheritable_tenant_secret() {
+    let cfg = StaticCredStorePluginConfig {
+        secrets: vec![SecretConfig {
+            sharing: Some(SharingMode::Tenant),
+            ..shared_secret(Some(true))
+        }],
+        ..StaticCredStorePluginConfig::default()
+    };
+    match Service::from_config(&cfg) {
+        Ok(_) => panic!("expected error for inheritable tenant secret"),
+        Err(e) => assert!(e.to_string().contains("inheritable"), "got: {e}"),
+    }
+}
+
+#[test]
+fn from_config_rejects_non_inheritable_global_secret() {
+    let cfg = StaticCredStorePluginConfig {
+        secrets: vec![SecretConfig {
+            tenant_id: None,
+            ..shared_secret(Some(false))
+        }],
+        ..StaticCredStorePluginConfig::default()
+    };
+    match Service::from_config(&cfg) {
+        Ok(_) => panic!("expected error for non-inheritable global secret"),
+        Err(e) => assert!(e.to_string().contains("always inheritable"), "got: {e}"),
+    }
+}
+
+#[test]
+fn get_rejects_private_entry_filed_under_another_subject() {
+    // Defense in depth: even if an entry ends up under the wrong map key,
+    // its own owner fields decide access.
+    let key = SecretRef::new("openai_api_key").unwrap();
+    let mut svc = Service::from_config(&StaticCredStorePluginConfig::default()).unwrap();
+    svc.private_secrets.insert(
+        (TenantId(tenant_a()), OwnerId(owner_a()), key.clone()),
+        entry(SharingMode::Private, owner_b(), tenant_a()),
+    );
+
+    assert!(svc.get(&ctx(tenant_a(), owner_a()), &key).is_none());
+}
+
+#[test]
+fn get_falls_through_to_next_scope_when_entry_is_inaccessible() {
+    let key = SecretRef::new("openai_api_key").unwrap();
+    let mut svc = Service::from_config(&StaticCredStorePluginConfig::default()).unwrap();
+    svc.tenant_secrets.insert(
+        (TenantId(tenant_a()), key.clone()),
+        entry(SharingMode::Tenant, Uuid::nil(), tenant_b()),
+    );
+    svc.global_secrets.insert(
+        key.clone(),
+        entry(SharingMode::Shared, Uuid::nil(), Uuid::nil()),
+    );
+
+    let found = svc.get(&ctx(tenant_a(), owner_a()), &key).unwrap();
+    assert_eq!(found.sharing, SharingMode::Shared);
+}
@@ -57,6 +57,7 @@ fn gateway_rate_limit_maps_to_rate_limited() {
         detail: "too many requests".into(),
         instance: "/test".into(),
         retry_after_secs: Some(60),
+        rate_limit: None,
     };
     let mapped: LlmProviderError = err.into();
     assert!(matches!(
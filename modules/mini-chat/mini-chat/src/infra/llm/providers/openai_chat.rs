@@ -807,6 +807,7 @@ mod tests {
             data: r#"{"choices":[{"delta":{"content":"Hello"}}]}"#.into(),
             id: None,
             retry: None,
+            ..Default::default()
         };
         let result = ChatCompletionEvent::from_server_event(event).unwrap();
         assert!(matches!(result, ChatCompletionEvent::Delta { content, .. } if content == "Hello"));
@@ -819,6 +820,7 @@ mod tests {
             data: r#"{"usage":{"prompt_tokens":500,"completion_tokens":120},"choices":[{"finish_reason":"stop"}]}"#.into(),
             id: None,
             retry: None,
+            ..Default::default()
         };
         let result = ChatCompletionEvent::from_server_event(event).unwrap();
         match result {
@@ -841,6 +843,7 @@ mod tests {
             data: r#"{"usage":{"prompt_tokens":500,"completion_tokens":120,"prompt_tokens_details":{"cached_tokens":200},"completion_tokens_details":{"reasoning_tokens":40}},"choices":[{"finish_reason":"stop"}]}"#.into(),
             id: None,
             retry: None,
+            ..Default::default()
         };
         let result = ChatCompletionEvent::from_server_event(event).unwrap();
         match result {
@@ -869,6 +872,7 @@ mod tests {
             data: r#"{"choices":[{"delta":{},"finish_reason":"stop"}]}"#.into(),
             id: None,
             retry: None,
+            ..Default::default()
         };
         let result = ChatCompletionEvent::from_server_event(event).unwrap();
         assert!(matches!(
@@ -884,6 +888,7 @@ mod tests {
             data: r#"{"choices":[],"usage":{"prompt_tokens":100,"completion_tokens":50}}"#.into(),
             id: None,
             retry: None,
+            ..Default::default()
         };
         let result = ChatCompletionEvent::from_server_event(event).unwrap();
         match result {
@@ -902,6 +907,7 @@ mod tests {
             data: "[DONE]".into(),
             id: None,
             retry: None,
+            ..Default::default()
         };
         let result = ChatCompletionEvent::from_server_event(event).unwrap();
         assert!(matches!(result, ChatCompletionEvent::StreamEnd));
@@ -914,6 +920,7 @@ mod tests {
             data: "not json at all".into(),
             id: None,
             retry: None,
+            ..Default::default()
         };
         let result = ChatCompletionEvent::from_server_event(event);
         assert!(matches!(
@@ -929,6 +936,7 @@ mod tests {
             data: r#"{"choices":[{"delta":{}}]}"#.into(),
             id: None,
             retry: None,
+            ..Default::default()
         };
         let result = ChatCompletionEvent::from_server_event(event).unwrap();
         assert!(matches!(result, ChatCompletionEvent::Unknown));
@@ -1165,6 +1173,7 @@ mod tests {
             data: r#"{"choices":[{"delta":{"tool_calls":[{"index":0,"id":"call_abc","function":{"name":"get_weather","arguments":""}}]}}]}"#.into(),
             id: None,
             retry: None,
+            ..Default::default()
         };
         let result = ChatCompletionEvent::from_server_event(event).unwrap();
         match result {
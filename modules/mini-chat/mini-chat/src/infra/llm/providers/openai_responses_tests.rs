@@ -555,6 +555,7 @@ fn parse_text_delta_event() {
         data: r#"{"delta":"Hello"}"#.to_string(),
         id: None,
         retry: None,
+        ..Default::default()
     };
     let result = ProviderEvent::from_server_event(event).unwrap();
     assert!(matches!(result, ProviderEvent::ResponseOutputTextDelta { delta } if delta == "Hello"));
@@ -567,6 +568,7 @@ fn parse_text_done_event() {
         data: r#"{"text":"Hello world"}"#.to_string(),
         id: None,
         retry: None,
+        ..Default::default()
     };
     let result = ProviderEvent::from_server_event(event).unwrap();
     assert!(
@@ -581,6 +583,7 @@ fn parse_file_search_searching_event() {
         data: "{}".to_string(),
         id: None,
         retry: None,
+        ..Default::default()
     };
     let result = ProviderEvent::from_server_event(event).unwrap();
     assert!(matches!(
@@ -598,6 +601,7 @@ fn parse_file_search_completed_event() {
                 .to_string(),
         id: None,
         retry: None,
+        ..Default::default()
     };
     let result = ProviderEvent::from_server_event(event).unwrap();
     match result {
@@ -616,6 +620,7 @@ fn parse_web_search_searching_event() {
         data: "{}".to_string(),
         id: None,
         retry: None,
+        ..Default::default()
     };
     let result = ProviderEvent::from_server_event(event).unwrap();
     assert!(matches!(
@@ -631,6 +636,7 @@ fn parse_web_search_completed_event() {
         data: "{}".to_string(),
         id: None,
         retry: None,
+        ..Default::default()
     };
     let result = ProviderEvent::from_server_event(event).unwrap();
     assert!(matches!(
@@ -646,6 +652,7 @@ fn parse_code_interpreter_in_progress_event() {
         data: "{}".to_string(),
         id: None,
         retry: None,
+        ..Default::default()
     };
     let result = ProviderEvent::from_server_event(event).unwrap();
     assert!(matches!(
@@ -661,6 +668,7 @@ fn parse_code_interpreter_interpreting_event_is_ignored() {
         data: "{}".to_string(),
         id: None,
         retry: None,
+        ..Default::default()
     };
     let result = ProviderEvent::from_server_event(event).unwrap();
     assert!(matches!(result, ProviderEvent::Unknown { .. }));
@@ -673,6 +681,7 @@ fn parse_code_interpreter_completed_event_extracts_logs() {
         data: r#"{"outputs":[{"type":"logs","logs":"result text"}]}"#.to_string(),
         id: None,
         retry: None,
+        ..Default::default()
     };
     let result = ProviderEvent::from_server_event(event).unwrap();
     match result {
@@ -697,6 +706,7 @@ fn parse_code_interpreter_completed_event_ignores_file_outputs() {
         .to_string(),
         id: None,
         retry: None,
+        ..Default::default()
     };
     let result = ProviderEvent::from_server_event(event).unwrap();
     match result {
@@ -714,6 +724,7 @@ fn parse_response_completed_event() {
             data: r#"{"response":{"id":"resp-abc","output":[{"type":"message","content":[{"type":"output_text","text":"Hello","annotations":[]}]}],"usage":{"input_tokens":100,"output_tokens":50}}}"#.to_string(),
             id: None,
             retry: None,
+            ..Default::default()
         };
     let result = ProviderEvent::from_server_event(event).unwrap();
     match result {
@@ -733,6 +744,7 @@ fn parse_response_completed_with_token_details() {
             data: r#"{"response":{"id":"resp-abc","output":[],"usage":{"input_tokens":800,"output_tokens":200,"input_tokens_details":{"cached_tokens":300},"output_tokens_details":{"reasoning_tokens":60}}}}"#.to_string(),
             id: None,
             retry: None,
+            ..Default::default()
         };
     let result = ProviderEvent::from_server_event(event).unwrap();
     match result {
@@ -767,6 +779,7 @@ fn parse_response_failed_event() {
         data: r#"{"error":{"code":"server_error","message":"internal failure"}}"#.to_string(),
         id: None,
         retry: None,
+        ..Default::default()
     };
     let result = ProviderEvent::from_server_event(event).unwrap();
     match result {
@@ -785,6 +798,7 @@ fn parse_response_incomplete_event() {
             data: r#"{"response":{"id":"resp-inc","output":[],"usage":{"input_tokens":200,"output_tokens":4096},"incomplete_details":{"reason":"max_output_tokens"}}}"#.to_string(),
             id: None,
             retry: None,
+            ..Default::default()
         };
     let result = ProviderEvent::from_server_event(event).unwrap();
     match result {
@@ -808,6 +822,7 @@ fn parse_unknown_event_returns_unknown() {
         data: r#"{"something":"new"}"#.to_string(),
         id: None,
         retry: None,
+        ..Default::default()
     };
     let result = ProviderEvent::from_server_event(event).unwrap();
     assert!(
@@ -822,6 +837,7 @@ fn parse_malformed_json_in_known_event_returns_error() {
         data: "not valid json".to_string(),
         id: None,
         retry: None,
+        ..Default::default()
     };
     let result = ProviderEvent::from_server_event(event);
     assert!(result.is_err());
@@ -1399,6 +1415,7 @@ async fn oagw_rate_limit_error() {
         detail: "too many requests".into(),
         instance: "/test".into(),
         retry_after_secs: Some(30),
+        rate_limit: None,
     });
     let provider = OpenAiResponsesProvider::new(gw);
 
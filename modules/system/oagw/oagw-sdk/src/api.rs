@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use modkit_security::SecurityContext;
 use uuid::Uuid;
@@ -145,3 +147,126 @@ pub trait ServiceGatewayClientV1: Send + Sync {
         req: http::Request<Body>,
     ) -> Result<http::Response<Body>, ServiceGatewayError>;
 }
+
+/// Forwards every call to the shared client, so an `Arc` (including
+/// `Arc<dyn ServiceGatewayClientV1>` from `ClientHub`) can be used wherever
+/// the trait is expected, e.g. as the inner client of a decorator.
+#[async_trait]
+impl<T: ServiceGatewayClientV1 + ?Sized> ServiceGatewayClientV1 for Arc<T> {
+    async fn create_upstream(
+        &self,
+        ctx: SecurityContext,
+        req: CreateUpstreamRequest,
+    ) -> Result<Upstream, ServiceGatewayError> {
+        (**self).create_upstream(ctx, req).await
+    }
+
+    async fn get_upstream(
+        &self,
+        ctx: SecurityContext,
+        id: Uuid,
+    ) -> Result<Upstream, ServiceGatewayError> {
+        (**self).get_upstream(ctx, id).await
+    }
+
+    async fn list_upstreams(
+        &self,
+        ctx: SecurityContext,
+        query: &ListQuery,
+    ) -> Result<Vec<Upstream>, ServiceGatewayError> {
+        (**self).list_upstreams(ctx, query).await
+    }
+
+    async fn update_upstream(
+        &self,
+        ctx: SecurityContext,
+        id: Uuid,
+        req: UpdateUpstreamRequest,
+    ) -> Result<Upstream, ServiceGatewayError> {
+        (**self).update_upstream(ctx, id, req).await
+    }
+
+    async fn delete_upstream(
+        &self,
+        ctx: SecurityContext,
+        id: Uuid,
+    ) -> Result<(), ServiceGatewayError> {
+        (**self).delete_upstream(ctx, id).await
+    }
+
+    async fn create_route(
+        &self,
+        ctx: SecurityContext,
+        req: CreateRouteRequest,
+    ) -> Result<Route, ServiceGatewayError> {
+        (**self).create_route(ctx, req).await
+    }
+
+    async fn get_route(
+        &self,
+        ctx: SecurityContext,
+        id: Uuid,
+    ) -> Result<Route, ServiceGatewayError> {
+        (**self).get_route(ctx, id).await
+    }
+
+    async fn list_routes(
+        &self,
+        ctx: SecurityContext,
+        upstream_id: Option<Uuid>,
+        query: &ListQuery,
+    ) -> Result<Vec<Route>, ServiceGatewayError> {
+        (**self).list_routes(ctx, upstream_id, query).await
+    }
+
+    async fn update_route(
+        &self,
+        ctx: SecurityContext,
+        id: Uuid,
+        req: UpdateRouteRequest,
+    ) -> Result<Route, ServiceGatewayError> {
+        (**self).update_route(ctx, id, req).await
+    }
+
+    async fn delete_route(
+        &self,
+        ctx: SecurityContext,
+        id: Uuid,
+    ) -> Result<(), ServiceGatewayError> {
+        (**self).delete_route(ctx, id).await
+    }
+
+    async fn resolve_proxy_target(
+        &self,
+        ctx: SecurityContext,
+        alias: &str,
+        method: &str,
+        path: &str,
+    ) -> Result<(Upstream, Route), ServiceGatewayError> {
+        (**self)
+            .resolve_proxy_target(ctx, alias, method, path)
+            .await
+    }
+
+    async fn proxy_request(
+        &self,
+        ctx: SecurityContext,
+        req: http::Request<Body>,
+    ) -> Result<http::Response<Body>, ServiceGatewayError> {
+        (**self).proxy_request(ctx, req).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_client<C: ServiceGatewayClientV1 + ?Sized>() {}
+
+    #[test]
+    fn trait_stays_object_safe_and_arcs_forward() {
+        assert_client::<dyn ServiceGatewayClientV1>();
+        assert_client::<Arc<dyn ServiceGatewayClientV1>>();
+        assert_client::<Arc<Arc<dyn ServiceGatewayClientV1>>>();
+    }
+}
@@ -1,7 +1,15 @@
+#[cfg(feature = "fs")]
+use std::path::PathBuf;
 use std::pin::Pin;
 
-use bytes::Bytes;
+use bytes::{Bytes, BytesMut};
 use futures_core::Stream;
+use futures_util::FutureExt;
+use futures_util::future::{BoxFuture, Shared};
+use http::HeaderMap;
+use tokio::io::{AsyncRead, ReadBuf};
+
+use crate::error::StreamingError;
 
 /// Boxed error type for body stream errors.
 pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
@@ -9,6 +17,69 @@ pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
 /// A streaming body.
 pub type BodyStream = Pin<Box<dyn Stream<Item = Result<Bytes, BoxError>> + Send>>;
 
+/// HTTP trailers delivered after a streaming body ends.
+///
+/// A cheap, clonable handle: take one with [`Body::trailers`] before
+/// consuming the body, then await [`get`](Self::get) once the body has been
+/// read. Resolves to `None` if the producer finished without sending trailers.
+#[derive(Clone)]
+pub struct Trailers {
+    inner: Shared<BoxFuture<'static, Option<HeaderMap>>>,
+}
+
+/// Producer side of [`Trailers::channel`].
+#[derive(Debug)]
+pub struct TrailersSender {
+    tx: tokio::sync::oneshot::Sender<HeaderMap>,
+}
+
+impl Trailers {
+    /// Create a trailers handle fed by the returned sender.
+    #[must_use]
+    pub fn channel() -> (TrailersSender, Trailers) {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        let inner = async move { rx.await.ok() }.boxed().shared();
+        (TrailersSender { tx }, Trailers { inner })
+    }
+
+    /// Wait for the trailers. Completes once the producer sends them or
+    /// drops its [`TrailersSender`].
+    pub async fn get(self) -> Option<HeaderMap> {
+        self.inner.await
+    }
+
+    /// The trailers, if they have already been received and awaited through
+    /// some handle.
+    #[must_use]
+    pub fn peek(&self) -> Option<HeaderMap> {
+        self.inner.peek().cloned().flatten()
+    }
+}
+
+impl std::fmt::Debug for Trailers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Trailers")
+            .field("received", &self.peek())
+            .finish()
+    }
+}
+
+impl TrailersSender {
+    /// Deliver the trailers to every [`Trailers`] handle.
+    pub fn send(self, trailers: HeaderMap) {
+        // Every handle being gone just means nobody is interested.
+        let _ = self.tx.send(trailers);
+    }
+}
+
+/// Number of chunks the [`Body::tee`] observer may lag behind before the
+/// forwarded body waits for it.
+const TEE_BUFFER_CHUNKS: usize = 16;
+
+/// Read size for streaming a [`Body::File`].
+#[cfg(feature = "fs")]
+const FILE_CHUNK_SIZE: usize = 64 * 1024;
+
 /// Unified body type for gateway proxy requests and responses.
 ///
 /// Covers every protocol through a single `proxy_request` call:
@@ -16,6 +87,10 @@ pub type BodyStream = Pin<Box<dyn Stream<Item = Result<Bytes, BoxError>> + Send>
 /// - `Bytes` — buffered body (small JSON payloads, typical API calls)
 /// - `Stream` — streaming body (SSE, chunked transfer, large payloads,
 ///   **and WebSocket messages** serialized as byte chunks)
+/// - `StreamWithTrailers` — streaming body followed by HTTP trailers
+///   (e.g. gRPC `grpc-status`)
+/// - `File` — file on disk, streamed in chunks without loading it into memory
+///   (requires the `fs` feature)
 ///
 /// # Protocol mapping
 ///
@@ -31,6 +106,12 @@ pub enum Body {
     Bytes(Bytes),
     /// Streaming body (SSE responses, WebSocket messages, chunked transfers).
     Stream(BodyStream),
+    /// Streaming body whose trailers arrive through a [`Trailers`] handle.
+    StreamWithTrailers(BodyStream, Trailers),
+    /// File on disk. Opened only when the body is read; a missing or
+    /// unreadable file surfaces as a read error then.
+    #[cfg(feature = "fs")]
+    File(PathBuf),
 }
 
 impl std::fmt::Debug for Body {
@@ -39,30 +120,162 @@ impl std::fmt::Debug for Body {
             Body::Empty => write!(f, "Body::Empty"),
             Body::Bytes(b) => write!(f, "Body::Bytes({} bytes)", b.len()),
             Body::Stream(_) => write!(f, "Body::Stream(...)"),
+            Body::StreamWithTrailers(..) => write!(f, "Body::StreamWithTrailers(...)"),
+            #[cfg(feature = "fs")]
+            Body::File(path) => write!(f, "Body::File({})", path.display()),
         }
     }
 }
 
 impl Body {
-    /// Returns `true` if this is an empty body.
+    /// A `Body::Stream` that reads `reader` in chunks of up to `chunk_size`
+    /// bytes as the body is polled, ending at EOF. I/O errors are yielded
+    /// as stream errors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn from_async_read<R>(reader: R, chunk_size: usize) -> Body
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        assert!(chunk_size > 0, "chunk_size must be non-zero");
+        Body::Stream(read_stream(Box::pin(reader), chunk_size))
+    }
+
+    /// Returns whether this body is known to be empty, without consuming it.
+    ///
+    /// - `Empty` → `Some(true)`
+    /// - `Bytes` → `Some(len == 0)`
+    /// - `Stream`/`StreamWithTrailers`/`File` → `None` (unknown until the
+    ///   body is read)
+    #[must_use]
+    pub fn is_empty(&self) -> Option<bool> {
+        match self {
+            Body::Empty => Some(true),
+            Body::Bytes(b) => Some(b.is_empty()),
+            Body::Stream(_) | Body::StreamWithTrailers(..) => None,
+            #[cfg(feature = "fs")]
+            Body::File(_) => None,
+        }
+    }
+
+    /// Clone a buffered body, e.g. to re-send it when retrying a request.
+    ///
+    /// - `Empty` → `Some(Body::Empty)`
+    /// - `Bytes` → `Some` sharing the same buffer (no copy)
+    /// - `Stream`/`StreamWithTrailers`/`File` → `None`; the body cannot be
+    ///   replayed, so a retry is not safe
+    #[must_use]
+    pub fn try_clone(&self) -> Option<Body> {
+        match self {
+            Body::Empty => Some(Body::Empty),
+            Body::Bytes(b) => Some(Body::Bytes(b.clone())),
+            Body::Stream(_) | Body::StreamWithTrailers(..) => None,
+            #[cfg(feature = "fs")]
+            Body::File(_) => None,
+        }
+    }
+
+    /// Returns the body's length in bytes when it is known without reading
+    /// it, e.g. for a `Content-Length` header.
+    ///
+    /// - `Empty` → `Some(0)`
+    /// - `Bytes` → `Some(len)`
+    /// - `File` → the file's length from its metadata, or `None` if it
+    ///   cannot be read (a blocking `stat` call)
+    /// - `Stream`/`StreamWithTrailers` → `None`
+    #[must_use]
+    pub fn size_hint(&self) -> Option<u64> {
+        match self {
+            Body::Empty => Some(0),
+            Body::Bytes(b) => Some(b.len() as u64),
+            #[cfg(feature = "fs")]
+            Body::File(path) => std::fs::metadata(path).ok().map(|m| m.len()),
+            Body::Stream(_) | Body::StreamWithTrailers(..) => None,
+        }
+    }
+
+    /// Returns `true` if this is a streaming body; `File` bodies are
+    /// streamed from disk.
+    #[must_use]
+    pub fn is_streaming(&self) -> bool {
+        match self {
+            Body::Empty | Body::Bytes(_) => false,
+            Body::Stream(_) | Body::StreamWithTrailers(..) => true,
+            #[cfg(feature = "fs")]
+            Body::File(_) => true,
+        }
+    }
+
+    /// A handle to this body's trailers, if it carries any.
+    ///
+    /// Take it before consuming the body with [`into_bytes`](Self::into_bytes)
+    /// or [`into_stream`](Self::into_stream), then await
+    /// [`Trailers::get`] once the stream has been read to the end.
+    #[must_use]
+    pub fn trailers(&self) -> Option<Trailers> {
+        match self {
+            Body::StreamWithTrailers(_, trailers) => Some(trailers.clone()),
+            _ => None,
+        }
+    }
+
+    /// Clone the buffered contents of this body without consuming it.
+    ///
+    /// - `Empty` → `Some(Bytes::new())`
+    /// - `Bytes` → `Some(..)` (a cheap reference-counted clone)
+    /// - `Stream`/`StreamWithTrailers`/`File` → `None`
     #[must_use]
-    pub fn is_empty(&self) -> bool {
-        matches!(self, Body::Empty)
+    pub fn clone_bytes(&self) -> Option<Bytes> {
+        match self {
+            Body::Empty => Some(Bytes::new()),
+            Body::Bytes(b) => Some(b.clone()),
+            Body::Stream(_) | Body::StreamWithTrailers(..) => None,
+            #[cfg(feature = "fs")]
+            Body::File(_) => None,
+        }
+    }
+
+    /// Buffer this body and assert that it equals `expected`.
+    ///
+    /// Test helper, available with the `test-util` feature. On mismatch the
+    /// panic message shows both sides as lossy UTF-8.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the contents differ or a stream chunk fails.
+    #[cfg(feature = "test-util")]
+    pub async fn assert_bytes_eq(self, expected: impl AsRef<[u8]>) {
+        let actual = match self.into_bytes().await {
+            Ok(bytes) => bytes,
+            Err(e) => panic!("body stream failed: {e}"),
+        };
+        let expected = expected.as_ref();
+        assert!(
+            actual.as_ref() == expected,
+            "body mismatch:\n  actual:   {:?}\n  expected: {:?}",
+            String::from_utf8_lossy(&actual),
+            String::from_utf8_lossy(expected),
+        );
     }
 
     /// Consume this body into `Bytes`, buffering a stream if necessary.
     ///
     /// For `Body::Stream`, reads the entire stream into memory. Use with
-    /// caution on unbounded streams (SSE, etc.).
+    /// caution on unbounded streams (SSE, etc.). For `Body::File`, reads the
+    /// whole file.
     ///
     /// # Errors
     ///
-    /// Returns an error if a stream chunk fails.
+    /// Returns an error if a stream chunk fails or the file cannot be read.
     pub async fn into_bytes(self) -> Result<Bytes, BoxError> {
         match self {
             Body::Empty => Ok(Bytes::new()),
             Body::Bytes(b) => Ok(b),
-            Body::Stream(mut s) => {
+            #[cfg(feature = "fs")]
+            Body::File(path) => Ok(Bytes::from(tokio::fs::read(&path).await?)),
+            Body::Stream(mut s) | Body::StreamWithTrailers(mut s, _) => {
                 use futures_util::StreamExt;
                 let mut buf = Vec::new();
                 while let Some(chunk) = s.next().await {
@@ -73,17 +286,199 @@ impl Body {
         }
     }
 
+    /// Like [`into_bytes`](Self::into_bytes), but fails once more than `max`
+    /// bytes have been read, so untrusted bodies cannot exhaust memory.
+    ///
+    /// Streams are read only until the limit is crossed; the rest is dropped
+    /// unread.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::BodyTooLarge`] if the body is longer than
+    /// `max`, or the error of a failing stream chunk or file read.
+    pub async fn into_bytes_limited(self, max: usize) -> Result<Bytes, BoxError> {
+        use futures_util::StreamExt;
+
+        let too_large = || BoxError::from(StreamingError::BodyTooLarge { limit: max });
+        let mut stream = match self {
+            Body::Empty => return Ok(Bytes::new()),
+            Body::Bytes(b) if b.len() > max => return Err(too_large()),
+            Body::Bytes(b) => return Ok(b),
+            other => other.into_stream(),
+        };
+        let mut buf = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            if buf.len() + chunk.len() > max {
+                return Err(too_large());
+            }
+            buf.extend_from_slice(&chunk);
+        }
+        Ok(Bytes::from(buf))
+    }
+
     /// Extract the inner `BodyStream`, converting other variants as needed.
     ///
     /// - `Empty` → empty stream
     /// - `Bytes` → single-item stream
-    /// - `Stream` → pass-through
+    /// - `Stream`/`StreamWithTrailers` → pass-through (trailers stay
+    ///   reachable through a handle taken with [`trailers`](Self::trailers))
+    /// - `File` → the file's contents in 64 KiB chunks; the file is opened on
+    ///   the first poll
     pub fn into_stream(self) -> BodyStream {
         match self {
             Body::Empty => Box::pin(futures_util::stream::empty()),
             Body::Bytes(b) => Box::pin(futures_util::stream::once(async { Ok(b) })),
-            Body::Stream(s) => s,
+            Body::Stream(s) | Body::StreamWithTrailers(s, _) => s,
+            #[cfg(feature = "fs")]
+            Body::File(path) => file_stream(path),
+        }
+    }
+
+    /// Transform each chunk with `f` without buffering the body.
+    ///
+    /// - `Empty` → unchanged (there are no chunks, so `f` is not called)
+    /// - `Bytes` → `f` applied once to the whole content
+    /// - `Stream`/`StreamWithTrailers`/`File` → `f` applied to each chunk as
+    ///   it is polled; errors pass through unchanged and trailers are kept
+    ///
+    /// Chunk boundaries are whatever the source yields, so `f` must not
+    /// assume a chunk holds a complete line or record.
+    #[must_use]
+    pub fn map_stream<F>(self, mut f: F) -> Body
+    where
+        F: FnMut(Bytes) -> Bytes + Send + 'static,
+    {
+        use futures_util::StreamExt;
+
+        match self {
+            Body::Empty => Body::Empty,
+            Body::Bytes(b) => Body::Bytes(f(b)),
+            body => {
+                let trailers = body.trailers();
+                let mapped = body.into_stream().map(move |chunk| chunk.map(&mut f));
+                Body::with_stream(trailers, Box::pin(mapped))
+            }
+        }
+    }
+
+    /// Rewrap a transformed stream, keeping this body's trailers (if any).
+    fn with_stream(trailers: Option<Trailers>, stream: BodyStream) -> Body {
+        match trailers {
+            Some(trailers) => Body::StreamWithTrailers(stream, trailers),
+            None => Body::Stream(stream),
+        }
+    }
+
+    /// Read at least `len` leading bytes without losing them.
+    ///
+    /// Returns the bytes read so far together with an equivalent body that
+    /// still yields every byte, starting with the peeked prefix. The prefix
+    /// may be longer than `len` (chunks are not split) or shorter (the body
+    /// ended or a chunk failed first). A chunk error is not surfaced here —
+    /// it is replayed by the returned body right after the prefix.
+    pub async fn peek(self, len: usize) -> (Bytes, Body) {
+        use futures_util::StreamExt;
+
+        let trailers = self.trailers();
+        let mut stream = match self {
+            Body::Empty => return (Bytes::new(), Body::Empty),
+            Body::Bytes(b) => return (b.clone(), Body::Bytes(b)),
+            Body::Stream(s) | Body::StreamWithTrailers(s, _) => s,
+            #[cfg(feature = "fs")]
+            Body::File(path) => file_stream(path),
+        };
+
+        let mut buf = Vec::new();
+        let mut head: Vec<Result<Bytes, BoxError>> = Vec::new();
+        let mut ended = false;
+        while buf.len() < len {
+            match stream.next().await {
+                Some(Ok(chunk)) => buf.extend_from_slice(&chunk),
+                Some(Err(e)) => {
+                    head.push(Err(e));
+                    break;
+                }
+                None => {
+                    ended = true;
+                    break;
+                }
+            }
+        }
+
+        let prefix = Bytes::from(buf);
+        if !prefix.is_empty() {
+            head.insert(0, Ok(prefix.clone()));
         }
+        let head = futures_util::stream::iter(head);
+        let body: BodyStream = if ended {
+            Box::pin(head)
+        } else {
+            Box::pin(head.chain(stream))
+        };
+        (prefix, Body::with_stream(trailers, body))
+    }
+
+    /// Split this body into a forwardable body and an observer stream that
+    /// yields the same chunks as they pass through, without buffering the
+    /// whole body.
+    ///
+    /// `Empty` and `Bytes` bodies are returned unchanged and the observer
+    /// yields their content (if any) as a single chunk. For `Stream` bodies,
+    /// each chunk is handed to the observer as the forwarded body is polled:
+    ///
+    /// - **Backpressure:** the observer may lag up to 16 chunks behind;
+    ///   beyond that, the forwarded body waits until the observer catches up.
+    ///   Chunks are never dropped silently, so a slow observer slows down
+    ///   forwarding rather than missing data.
+    /// - **Detach:** if the observer stream is dropped, forwarding continues
+    ///   unobserved at full speed.
+    /// - **Errors:** a stream error is forwarded as-is; the observer receives
+    ///   a copy carrying the same message.
+    /// - **End:** the observer ends when the forwarded body ends or is dropped.
+    ///
+    /// Trailers, if any, stay with the forwarded body. A `File` body is
+    /// forwarded as a `Stream` of its contents.
+    pub fn tee(self) -> (Body, BodyStream) {
+        use futures_util::StreamExt;
+
+        let trailers = self.trailers();
+        let stream = match self {
+            Body::Empty => return (Body::Empty, Box::pin(futures_util::stream::empty())),
+            Body::Bytes(b) => {
+                let copy = b.clone();
+                return (
+                    Body::Bytes(b),
+                    Box::pin(futures_util::stream::once(async { Ok(copy) })),
+                );
+            }
+            Body::Stream(s) | Body::StreamWithTrailers(s, _) => s,
+            #[cfg(feature = "fs")]
+            Body::File(path) => file_stream(path),
+        };
+        let (tx, rx) = tokio::sync::mpsc::channel(TEE_BUFFER_CHUNKS);
+        let forward =
+            futures_util::stream::unfold((stream, Some(tx)), |(mut stream, mut tx)| async move {
+                let item = stream.next().await?;
+                if let Some(sender) = &tx {
+                    let copy = match &item {
+                        Ok(chunk) => Ok(chunk.clone()),
+                        Err(e) => Err(BoxError::from(e.to_string())),
+                    };
+                    if sender.send(copy).await.is_err() {
+                        // Observer dropped — keep forwarding unobserved.
+                        tx = None;
+                    }
+                }
+                Some((item, (stream, tx)))
+            });
+        let observer = futures_util::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|item| (item, rx))
+        });
+        (
+            Body::with_stream(trailers, Box::pin(forward)),
+            Box::pin(observer),
+        )
     }
 
     /// Try to extract the inner `Bytes`.
@@ -98,15 +493,49 @@ impl Body {
 
     /// Try to extract the inner `BodyStream`.
     ///
-    /// Returns `Err(self)` if this is not `Body::Stream`.
+    /// Returns `Err(self)` if this is neither `Body::Stream` nor
+    /// `Body::StreamWithTrailers`.
     pub fn try_into_stream(self) -> Result<BodyStream, Self> {
         match self {
-            Body::Stream(s) => Ok(s),
+            Body::Stream(s) | Body::StreamWithTrailers(s, _) => Ok(s),
             other => Err(other),
         }
     }
 }
 
+/// Read `reader` in chunks of up to `chunk_size` bytes until EOF.
+fn read_stream<R>(reader: Pin<Box<R>>, chunk_size: usize) -> BodyStream
+where
+    R: AsyncRead + Send + ?Sized + 'static,
+{
+    Box::pin(futures_util::stream::try_unfold(
+        reader,
+        move |mut reader| async move {
+            let mut chunk = BytesMut::zeroed(chunk_size);
+            let mut buf = ReadBuf::new(&mut chunk);
+            futures_util::future::poll_fn(|cx| reader.as_mut().poll_read(cx, &mut buf)).await?;
+            let filled = buf.filled().len();
+            if filled == 0 {
+                return Ok(None);
+            }
+            chunk.truncate(filled);
+            Ok::<_, BoxError>(Some((chunk.freeze(), reader)))
+        },
+    ))
+}
+
+/// Stream the file at `path`, opening it on the first poll.
+#[cfg(feature = "fs")]
+fn file_stream(path: PathBuf) -> BodyStream {
+    use futures_util::TryStreamExt;
+
+    let opened = futures_util::stream::once(async move {
+        let file = tokio::fs::File::open(&path).await?;
+        Ok::<_, BoxError>(read_stream(Box::pin(file), FILE_CHUNK_SIZE))
+    });
+    Box::pin(opened.try_flatten())
+}
+
 impl From<()> for Body {
     fn from((): ()) -> Self {
         Body::Empty
@@ -147,31 +576,85 @@ impl From<BodyStream> for Body {
     }
 }
 
+#[cfg(feature = "reqwest")]
+impl Body {
+    /// Convert a `reqwest` response into an SDK response, so it can be
+    /// handed to [`ServerEventsStream::from_response`] and friends.
+    ///
+    /// Status, version, headers and extensions carry over; the body becomes a
+    /// [`Body::Stream`] read lazily from the connection, with `reqwest`
+    /// errors surfaced as [`BoxError`].
+    ///
+    /// [`ServerEventsStream::from_response`]: crate::sse::ServerEventsStream::from_response
+    #[must_use]
+    pub fn from_reqwest(mut resp: reqwest::Response) -> http::Response<Body> {
+        let mut out = http::Response::new(Body::Empty);
+        *out.status_mut() = resp.status();
+        *out.version_mut() = resp.version();
+        *out.headers_mut() = std::mem::take(resp.headers_mut());
+        *out.extensions_mut() = std::mem::take(resp.extensions_mut());
+        *out.body_mut() = Body::from(resp);
+        out
+    }
+}
+
+/// The response body as a stream; status and headers are dropped. Use
+/// [`Body::from_reqwest`] to keep them.
+#[cfg(feature = "reqwest")]
+impl From<reqwest::Response> for Body {
+    fn from(resp: reqwest::Response) -> Self {
+        use futures_util::TryStreamExt;
+
+        Body::Stream(Box::pin(resp.bytes_stream().map_err(BoxError::from)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn body_empty_is_empty() {
-        assert!(Body::Empty.is_empty());
+        assert_eq!(Body::Empty.is_empty(), Some(true));
     }
 
     #[test]
     fn body_bytes_not_empty() {
         let body = Body::from(Bytes::from("hello"));
-        assert!(!body.is_empty());
+        assert_eq!(body.is_empty(), Some(false));
+    }
+
+    #[test]
+    fn zero_length_bytes_variant_is_empty() {
+        // Constructed directly, bypassing the `From<Bytes>` normalization.
+        assert_eq!(Body::Bytes(Bytes::new()).is_empty(), Some(true));
+    }
+
+    #[test]
+    fn stream_emptiness_is_unknown() {
+        let stream: BodyStream = Box::pin(futures_util::stream::empty());
+        let body = Body::Stream(stream);
+        assert_eq!(body.is_empty(), None);
+        assert!(body.is_streaming());
+    }
+
+    #[test]
+    fn buffered_bodies_are_not_streaming() {
+        assert!(!Body::Empty.is_streaming());
+        assert!(!Body::from("hello").is_streaming());
     }
 
     #[test]
     fn empty_bytes_becomes_empty_body() {
         let body = Body::from(Bytes::new());
-        assert!(body.is_empty());
+        assert_eq!(body.is_empty(), Some(true));
+        assert!(matches!(body, Body::Empty));
     }
 
     #[test]
     fn from_string() {
         let body = Body::from("hello".to_string());
-        assert!(!body.is_empty());
+        assert_eq!(body.is_empty(), Some(false));
         assert!(matches!(body, Body::Bytes(_)));
     }
 
@@ -190,7 +673,7 @@ mod tests {
     #[test]
     fn from_unit() {
         let body = Body::from(());
-        assert!(body.is_empty());
+        assert_eq!(body.is_empty(), Some(true));
     }
 
     #[test]
@@ -251,4 +734,397 @@ mod tests {
         let body = Body::Bytes(Bytes::from("data"));
         assert!(body.try_into_stream().is_err());
     }
+
+    fn stream_of(chunks: Vec<&'static str>) -> Body {
+        let items: Vec<Result<Bytes, BoxError>> =
+            chunks.into_iter().map(|c| Ok(Bytes::from(c))).collect();
+        Body::Stream(Box::pin(futures_util::stream::iter(items)))
+    }
+
+    #[tokio::test]
+    async fn tee_observer_sees_forwarded_chunks() {
+        use futures_util::StreamExt;
+
+        let (forward, observer) = stream_of(vec!["a", "b", "c"]).tee();
+        let forwarded = forward.into_bytes().await.unwrap();
+        let observed: Vec<Bytes> = observer.map(Result::unwrap).collect().await;
+
+        assert_eq!(forwarded, Bytes::from("abc"));
+        assert_eq!(observed, vec!["a", "b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn tee_buffered_body_is_unchanged() {
+        use futures_util::StreamExt;
+
+        let (forward, observer) = Body::from("hello").tee();
+        assert!(matches!(forward, Body::Bytes(ref b) if b == "hello"));
+        let observed: Vec<Bytes> = observer.map(Result::unwrap).collect().await;
+        assert_eq!(observed, vec!["hello"]);
+
+        let (forward, observer) = Body::Empty.tee();
+        assert!(matches!(forward, Body::Empty));
+        assert_eq!(observer.count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn tee_continues_when_observer_dropped() {
+        let chunks = vec!["x"; TEE_BUFFER_CHUNKS * 2];
+        let (forward, observer) = stream_of(chunks).tee();
+        drop(observer);
+
+        let forwarded = forward.into_bytes().await.unwrap();
+        assert_eq!(forwarded.len(), TEE_BUFFER_CHUNKS * 2);
+    }
+
+    #[tokio::test]
+    async fn tee_applies_backpressure_when_observer_lags() {
+        use futures_util::StreamExt;
+
+        let chunks = vec!["x"; TEE_BUFFER_CHUNKS + 2];
+        let (forward, mut observer) = stream_of(chunks).tee();
+        let mut forward = forward.into_stream();
+
+        // The observer buffer absorbs the first chunks...
+        for _ in 0..TEE_BUFFER_CHUNKS {
+            forward.next().await.unwrap().unwrap();
+        }
+        // ...then forwarding stalls until the observer reads.
+        let stalled =
+            tokio::time::timeout(std::time::Duration::from_millis(20), forward.next()).await;
+        assert!(stalled.is_err(), "forwarding should wait for the observer");
+
+        observer.next().await.unwrap().unwrap();
+        assert!(forward.next().await.is_some());
+    }
+
+    #[tokio::test]
+    async fn tee_copies_stream_errors_to_observer() {
+        use futures_util::StreamExt;
+
+        let items: Vec<Result<Bytes, BoxError>> =
+            vec![Ok(Bytes::from("ok")), Err("upstream reset".into())];
+        let body = Body::Stream(Box::pin(futures_util::stream::iter(items)));
+        let (forward, observer) = body.tee();
+
+        let err = forward.into_bytes().await.unwrap_err();
+        assert_eq!(err.to_string(), "upstream reset");
+
+        let observed: Vec<_> = observer.collect().await;
+        assert_eq!(observed.len(), 2);
+        assert_eq!(
+            observed[1].as_ref().unwrap_err().to_string(),
+            "upstream reset"
+        );
+    }
+
+    #[tokio::test]
+    async fn peek_stream_preserves_all_bytes() {
+        let (prefix, body) = stream_of(vec!["da", "ta: x\n", "\n", "rest"]).peek(4).await;
+        assert_eq!(prefix, Bytes::from("data: x\n"));
+        assert_eq!(
+            body.into_bytes().await.unwrap(),
+            Bytes::from("data: x\n\nrest")
+        );
+    }
+
+    #[tokio::test]
+    async fn peek_short_stream_returns_everything() {
+        let (prefix, body) = stream_of(vec!["ab"]).peek(16).await;
+        assert_eq!(prefix, Bytes::from("ab"));
+        assert_eq!(body.into_bytes().await.unwrap(), Bytes::from("ab"));
+    }
+
+    #[tokio::test]
+    async fn peek_replays_stream_error_after_prefix() {
+        use futures_util::StreamExt;
+
+        let items: Vec<Result<Bytes, BoxError>> = vec![
+            Ok(Bytes::from("ab")),
+            Err("boom".into()),
+            Ok(Bytes::from("cd")),
+        ];
+        let body = Body::Stream(Box::pin(futures_util::stream::iter(items)));
+
+        let (prefix, body) = body.peek(16).await;
+        assert_eq!(prefix, Bytes::from("ab"));
+
+        let items: Vec<_> = body.into_stream().collect().await;
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].as_ref().unwrap(), "ab");
+        assert_eq!(items[1].as_ref().unwrap_err().to_string(), "boom");
+        assert_eq!(items[2].as_ref().unwrap(), "cd");
+    }
+
+    #[tokio::test]
+    async fn peek_buffered_body_is_unchanged() {
+        let (prefix, body) = Body::from("hello").peek(2).await;
+        assert_eq!(prefix, Bytes::from("hello"));
+        assert!(matches!(body, Body::Bytes(ref b) if b == "hello"));
+
+        let (prefix, body) = Body::Empty.peek(2).await;
+        assert!(prefix.is_empty());
+        assert!(matches!(body, Body::Empty));
+    }
+
+    /// A two-chunk stream that sends `grpc-status: 0` trailers once drained.
+    fn stream_with_trailers() -> Body {
+        use futures_util::StreamExt;
+
+        let (tx, trailers) = Trailers::channel();
+        let mut tx = Some(tx);
+        let chunks = futures_util::stream::iter(["ab", "cd"]).map(|s| Ok(Bytes::from(s)));
+        let done = futures_util::stream::poll_fn(move |_| {
+            if let Some(tx) = tx.take() {
+                let mut map = HeaderMap::new();
+                map.insert("grpc-status", http::HeaderValue::from_static("0"));
+                tx.send(map);
+            }
+            std::task::Poll::Ready(None)
+        });
+        Body::StreamWithTrailers(Box::pin(chunks.chain(done)), trailers)
+    }
+
+    #[tokio::test]
+    async fn trailers_available_after_into_bytes() {
+        let body = stream_with_trailers();
+        assert!(body.is_streaming());
+        let trailers = body.trailers().unwrap();
+        assert!(trailers.peek().is_none());
+
+        assert_eq!(body.into_bytes().await.unwrap(), Bytes::from("abcd"));
+        let map = trailers.clone().get().await.unwrap();
+        assert_eq!(map["grpc-status"], "0");
+        assert_eq!(trailers.peek().unwrap()["grpc-status"], "0");
+    }
+
+    #[tokio::test]
+    async fn trailers_survive_peek_and_tee() {
+        let (prefix, body) = stream_with_trailers().peek(1).await;
+        assert_eq!(prefix, Bytes::from("ab"));
+        let (body, _observer) = body.tee();
+        let trailers = body.trailers().expect("trailers kept");
+
+        use futures_util::StreamExt;
+        let chunks: Vec<_> = body.into_stream().collect().await;
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(trailers.get().await.unwrap()["grpc-status"], "0");
+    }
+
+    #[tokio::test]
+    async fn trailers_resolve_to_none_when_sender_dropped() {
+        let (tx, trailers) = Trailers::channel();
+        drop(tx);
+        assert!(trailers.get().await.is_none());
+        assert!(Body::from("x").trailers().is_none());
+    }
+
+    /// A temp file holding `contents`, removed when dropped.
+    #[cfg(feature = "fs")]
+    struct TempFile(PathBuf);
+
+    #[cfg(feature = "fs")]
+    impl TempFile {
+        fn new(contents: &[u8]) -> Self {
+            let path = std::env::temp_dir().join(format!("oagw-body-{}", uuid::Uuid::new_v4()));
+            std::fs::write(&path, contents).unwrap();
+            Self(path)
+        }
+    }
+
+    #[cfg(feature = "fs")]
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    #[cfg(feature = "fs")]
+    #[tokio::test]
+    async fn file_body_into_bytes_reads_whole_file() {
+        let file = TempFile::new(b"hello from disk");
+        let body = Body::File(file.0.clone());
+        assert!(body.is_streaming());
+        assert_eq!(body.is_empty(), None);
+        assert_eq!(
+            body.into_bytes().await.unwrap(),
+            Bytes::from("hello from disk")
+        );
+    }
+
+    #[cfg(feature = "fs")]
+    #[tokio::test]
+    async fn file_body_into_stream_yields_chunks_in_order() {
+        use futures_util::TryStreamExt;
+
+        let contents: Vec<u8> = (0..FILE_CHUNK_SIZE * 2 + 10)
+            .map(|i| (i % 251) as u8)
+            .collect();
+        let file = TempFile::new(&contents);
+        let chunks: Vec<Bytes> = Body::File(file.0.clone())
+            .into_stream()
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(chunks.len() >= 3, "got {} chunks", chunks.len());
+        assert!(chunks.iter().all(|c| c.len() <= FILE_CHUNK_SIZE));
+        assert_eq!(chunks.concat(), contents);
+    }
+
+    #[tokio::test]
+    async fn into_bytes_limited_rejects_oversized_stream() {
+        use futures_util::StreamExt;
+
+        let body = || {
+            let chunks = futures_util::stream::iter(["aaaa", "bbbb", "cccc"])
+                .map(|s| Ok::<_, BoxError>(Bytes::from(s)));
+            Body::Stream(Box::pin(chunks))
+        };
+        assert_eq!(
+            body().into_bytes_limited(12).await.unwrap(),
+            Bytes::from("aaaabbbbcccc")
+        );
+
+        let err = body().into_bytes_limited(10).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<StreamingError>(),
+            Some(StreamingError::BodyTooLarge { limit: 10 })
+        ));
+
+        assert!(Body::from("hello").into_bytes_limited(4).await.is_err());
+        assert_eq!(
+            Body::Empty.into_bytes_limited(0).await.unwrap(),
+            Bytes::new()
+        );
+    }
+
+    #[tokio::test]
+    async fn map_stream_transforms_each_chunk() {
+        use futures_util::StreamExt;
+
+        let upper = |chunk: Bytes| Bytes::from(chunk.to_ascii_uppercase());
+        let chunks = futures_util::stream::iter(["ab", "cd", "ef"])
+            .map(|s| Ok::<_, BoxError>(Bytes::from(s)));
+        let body = Body::Stream(Box::pin(chunks)).map_stream(upper);
+        assert!(body.is_streaming());
+        assert_eq!(body.into_bytes().await.unwrap(), Bytes::from("ABCDEF"));
+
+        assert_eq!(
+            Body::from("xyz")
+                .map_stream(upper)
+                .try_into_bytes()
+                .unwrap(),
+            Bytes::from("XYZ")
+        );
+        assert!(matches!(Body::Empty.map_stream(upper), Body::Empty));
+    }
+
+    #[tokio::test]
+    async fn map_stream_passes_errors_and_keeps_trailers() {
+        use futures_util::StreamExt;
+
+        let chunks =
+            futures_util::stream::iter([Ok(Bytes::from("ok")), Err(BoxError::from("boom"))]);
+        let items: Vec<_> = Body::Stream(Box::pin(chunks))
+            .map_stream(|c| c)
+            .into_stream()
+            .collect()
+            .await;
+        assert_eq!(items[0].as_ref().unwrap(), &Bytes::from("ok"));
+        assert_eq!(items[1].as_ref().unwrap_err().to_string(), "boom");
+
+        let body = stream_with_trailers().map_stream(|c| c);
+        let trailers = body.trailers().expect("trailers kept");
+        assert_eq!(body.into_bytes().await.unwrap(), Bytes::from("abcd"));
+        assert_eq!(trailers.get().await.unwrap()["grpc-status"], "0");
+    }
+
+    #[test]
+    fn try_clone_shares_bytes_and_refuses_streams() {
+        let body = Body::from("retry me");
+        let clone = body.try_clone().expect("bytes are cloneable");
+        let (Body::Bytes(a), Body::Bytes(b)) = (&body, &clone) else {
+            panic!("expected Bytes bodies");
+        };
+        assert_eq!(a, b);
+        assert_eq!(a.as_ptr(), b.as_ptr(), "clone should share the buffer");
+
+        assert!(matches!(Body::Empty.try_clone(), Some(Body::Empty)));
+        let stream: BodyStream = Box::pin(futures_util::stream::empty());
+        assert!(Body::Stream(stream).try_clone().is_none());
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn try_clone_rejects_file_body() {
+        assert!(Body::File(PathBuf::from("/dev/null")).try_clone().is_none());
+    }
+
+    #[test]
+    fn size_hint_per_variant() {
+        assert_eq!(Body::Empty.size_hint(), Some(0));
+        assert_eq!(Body::from("hello").size_hint(), Some(5));
+        let stream: BodyStream = Box::pin(futures_util::stream::empty());
+        assert_eq!(Body::Stream(stream).size_hint(), None);
+        assert_eq!(stream_with_trailers().size_hint(), None);
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn size_hint_reads_file_metadata() {
+        let file = TempFile::new(b"twelve bytes");
+        assert_eq!(Body::File(file.0.clone()).size_hint(), Some(12));
+        let missing = std::env::temp_dir().join(format!("oagw-body-{}", uuid::Uuid::new_v4()));
+        assert_eq!(Body::File(missing).size_hint(), None);
+    }
+
+    #[tokio::test]
+    async fn from_async_read_round_trips_in_chunks() {
+        use futures_util::TryStreamExt;
+
+        let contents: Vec<u8> = (0..=255).cycle().take(1000).collect();
+        let body = Body::from_async_read(std::io::Cursor::new(contents.clone()), 300);
+        assert!(matches!(body, Body::Stream(_)));
+        let chunks: Vec<Bytes> = body.into_stream().try_collect().await.unwrap();
+        assert_eq!(
+            chunks.iter().map(Bytes::len).collect::<Vec<_>>(),
+            [300, 300, 300, 100]
+        );
+
+        let body = Body::from_async_read(std::io::Cursor::new(contents.clone()), 300);
+        assert_eq!(body.into_bytes().await.unwrap(), contents);
+    }
+
+    #[tokio::test]
+    async fn from_async_read_surfaces_io_errors() {
+        struct Failing;
+
+        impl AsyncRead for Failing {
+            fn poll_read(
+                self: Pin<&mut Self>,
+                _cx: &mut std::task::Context<'_>,
+                _buf: &mut tokio::io::ReadBuf<'_>,
+            ) -> std::task::Poll<std::io::Result<()>> {
+                std::task::Poll::Ready(Err(std::io::Error::other("boom")))
+            }
+        }
+
+        let err = Body::from_async_read(Failing, 16)
+            .into_bytes()
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("boom"), "got: {err}");
+    }
+
+    #[cfg(feature = "fs")]
+    #[tokio::test]
+    async fn file_body_missing_file_fails_on_read() {
+        let path = std::env::temp_dir().join(format!("oagw-body-{}", uuid::Uuid::new_v4()));
+        assert!(Body::File(path.clone()).into_bytes().await.is_err());
+
+        use futures_util::StreamExt;
+        let items: Vec<_> = Body::File(path).into_stream().collect().await;
+        assert_eq!(items.len(), 1);
+        assert!(items[0].is_err());
+    }
 }
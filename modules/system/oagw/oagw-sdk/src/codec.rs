@@ -1,7 +1,8 @@
 /// Codec adapter that provides automatic JSON serialization and deserialization
 /// for both SSE and WebSocket streaming protocols.
 ///
-/// `Json<T>` implements [`FromServerEvent`](crate::sse::FromServerEvent) and
+/// `Json<T>` implements [`FromServerEvent`](crate::sse::FromServerEvent),
+/// [`ToServerEvent`](crate::sse::ToServerEvent) and
 /// [`FromWebSocketMessage`](crate::ws::FromWebSocketMessage), so any type that
 /// derives `Serialize`/`Deserialize` can be used directly as the type parameter
 /// of [`ServerEventsStream`](crate::sse::ServerEventsStream) or
@@ -60,3 +61,151 @@ impl<T> Json<T> {
         self.0
     }
 }
+
+/// Codec adapter for newline-delimited JSON (`application/x-ndjson`).
+///
+/// Each value is one JSON document on its own line. Incoming NDJSON bodies
+/// are parsed with [`parse_ndjson_stream`](crate::ndjson::parse_ndjson_stream);
+/// [`to_line`](Self::to_line) writes the matching wire format.
+///
+/// ```ignore
+/// let mut rows = parse_ndjson_stream::<NdJson<Row>>(body);
+/// while let Some(row) = rows.next().await {
+///     println!("{}", row?.id);
+/// }
+///
+/// let line = NdJson(Row { id: 1 }).to_line()?; // b"{\"id\":1}\n"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NdJson<T>(pub T);
+
+impl<T> std::ops::Deref for NdJson<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> std::ops::DerefMut for NdJson<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> NdJson<T> {
+    /// Unwrap into the inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: serde::Serialize> NdJson<T> {
+    /// Serialize as one NDJSON line, including the trailing `\n`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::NdJsonSerialize`](crate::StreamingError::NdJsonSerialize)
+    /// if `T` cannot be serialized as JSON.
+    pub fn to_line(&self) -> Result<bytes::Bytes, crate::StreamingError> {
+        let mut line =
+            serde_json::to_vec(&self.0).map_err(|e| crate::StreamingError::NdJsonSerialize {
+                detail: e.to_string(),
+            })?;
+        line.push(b'\n');
+        Ok(line.into())
+    }
+}
+
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for NdJson<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(NdJson)
+    }
+}
+
+/// Codec adapter for `application/x-www-form-urlencoded` payloads.
+///
+/// `Form<T>` implements [`FromWebSocketMessage`](crate::ws::FromWebSocketMessage)
+/// over Text frames, for legacy bridges that exchange form-encoded messages
+/// instead of JSON. Field types must be flat (strings, numbers, booleans,
+/// options and sequences of those) — nested structs cannot be form-encoded.
+///
+/// Requires the `form` feature.
+///
+/// ```ignore
+/// let mut ws: WebSocketStream<Form<Login>> = ...;
+/// ws.send(&Form(Login { user: "alice".into(), remember: true })).await?;
+/// // wire: "user=alice&remember=true"
+/// ```
+#[cfg(feature = "form")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Form<T>(pub T);
+
+#[cfg(feature = "form")]
+impl<T> std::ops::Deref for Form<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "form")]
+impl<T> std::ops::DerefMut for Form<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "form")]
+impl<T> Form<T> {
+    /// Unwrap into the inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// Codec adapter for MessagePack payloads.
+///
+/// `MsgPack<T>` implements [`FromWebSocketMessage`](crate::ws::FromWebSocketMessage)
+/// over Binary frames, and [`FromServerEvent`](crate::sse::FromServerEvent) /
+/// [`ToServerEvent`](crate::sse::ToServerEvent) over base64-encoded event
+/// data (see [`ServerEvent::with_binary_data`](crate::sse::ServerEvent::with_binary_data)).
+/// Structs are encoded as maps with field names, so either side may add
+/// fields.
+///
+/// SSE receivers must parse with
+/// [`SseDecoder::with_binary_data`](crate::sse::SseDecoder::with_binary_data)
+/// to keep the `data-encoding` marker; without it `data` is decoded as raw
+/// bytes.
+///
+/// Requires the `msgpack` feature.
+///
+/// ```ignore
+/// let mut ws: WebSocketStream<MsgPack<Tick>> = ...;
+/// ws.send(&MsgPack(Tick { seq: 1 })).await?; // sent as a Binary frame
+/// ```
+#[cfg(feature = "msgpack")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MsgPack<T>(pub T);
+
+#[cfg(feature = "msgpack")]
+impl<T> std::ops::Deref for MsgPack<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "msgpack")]
+impl<T> std::ops::DerefMut for MsgPack<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "msgpack")]
+impl<T> MsgPack<T> {
+    /// Unwrap into the inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
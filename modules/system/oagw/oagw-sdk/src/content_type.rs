@@ -0,0 +1,231 @@
+//! Content-type rules that route streaming responses to a parser.
+
+use std::borrow::Cow;
+use std::sync::LazyLock;
+
+use http::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+/// Rules used by [`is_server_events_response`](crate::sse::is_server_events_response)
+/// and [`ServerEventsStream::from_response`](crate::sse::ServerEventsStream::from_response).
+static DEFAULT: LazyLock<StreamingContentTypes> = LazyLock::new(StreamingContentTypes::default);
+
+/// One rule for recognising a `Content-Type` value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentTypeMatcher {
+    /// The media type (the value before any `;` parameters, trimmed) equals
+    /// this string, ignoring ASCII case.
+    Exact(Cow<'static, str>),
+    /// The header value, ignoring leading whitespace and ASCII case, starts
+    /// with this string.
+    Prefix(Cow<'static, str>),
+}
+
+impl ContentTypeMatcher {
+    #[must_use]
+    pub fn exact(media_type: impl Into<Cow<'static, str>>) -> Self {
+        Self::Exact(media_type.into())
+    }
+
+    #[must_use]
+    pub fn prefix(prefix: impl Into<Cow<'static, str>>) -> Self {
+        Self::Prefix(prefix.into())
+    }
+
+    /// Whether `content_type`, a raw `Content-Type` value, matches this rule.
+    #[must_use]
+    pub fn matches(&self, content_type: &str) -> bool {
+        match self {
+            Self::Exact(media_type) => content_type
+                .split(';')
+                .next()
+                .is_some_and(|mime| mime.trim().eq_ignore_ascii_case(media_type)),
+            Self::Prefix(prefix) => content_type
+                .trim_start()
+                .get(..prefix.len())
+                .is_some_and(|head| head.eq_ignore_ascii_case(prefix)),
+        }
+    }
+}
+
+/// The parser a streaming response is routed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamFormat {
+    /// Server-sent events (`text/event-stream`).
+    ServerEvents,
+    /// Newline-delimited JSON.
+    NdJson,
+}
+
+/// Which `Content-Type` values are treated as SSE and which as NDJSON.
+///
+/// The default recognises `text/event-stream` (with any parameters) as SSE
+/// and `application/x-ndjson`, `application/jsonl` and
+/// `application/stream+json` as NDJSON. Deserializable so a deployment can
+/// replace either list in configuration; an omitted list keeps its default:
+///
+/// ```yaml
+/// server_events:
+///   - prefix: "text/event-stream"
+/// ndjson:
+///   - exact: "application/x-ndjson"
+///   - exact: "application/vnd.acme.stream+json"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StreamingContentTypes {
+    /// Rules that select the SSE parser. Checked first.
+    pub server_events: Vec<ContentTypeMatcher>,
+    /// Rules that select the NDJSON parser.
+    pub ndjson: Vec<ContentTypeMatcher>,
+}
+
+impl Default for StreamingContentTypes {
+    fn default() -> Self {
+        Self {
+            server_events: vec![ContentTypeMatcher::prefix("text/event-stream")],
+            ndjson: vec![
+                ContentTypeMatcher::exact("application/x-ndjson"),
+                ContentTypeMatcher::exact("application/jsonl"),
+                ContentTypeMatcher::exact("application/stream+json"),
+            ],
+        }
+    }
+}
+
+impl StreamingContentTypes {
+    /// Rules that match nothing; add to them with the `with_*` methods.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self {
+            server_events: Vec::new(),
+            ndjson: Vec::new(),
+        }
+    }
+
+    /// Add a rule selecting the SSE parser.
+    #[must_use]
+    pub fn with_server_events(mut self, matcher: ContentTypeMatcher) -> Self {
+        self.server_events.push(matcher);
+        self
+    }
+
+    /// Add a rule selecting the NDJSON parser.
+    #[must_use]
+    pub fn with_ndjson(mut self, matcher: ContentTypeMatcher) -> Self {
+        self.ndjson.push(matcher);
+        self
+    }
+
+    /// The parser for a response with these headers, or `None` if the
+    /// content type is absent or matches no rule. SSE rules win when both
+    /// lists match.
+    #[must_use]
+    pub fn format_of(&self, headers: &HeaderMap) -> Option<StreamFormat> {
+        let content_type = headers
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())?;
+        if self.server_events.iter().any(|m| m.matches(content_type)) {
+            Some(StreamFormat::ServerEvents)
+        } else if self.ndjson.iter().any(|m| m.matches(content_type)) {
+            Some(StreamFormat::NdJson)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the response should be parsed as SSE.
+    #[must_use]
+    pub fn is_server_events(&self, headers: &HeaderMap) -> bool {
+        self.format_of(headers) == Some(StreamFormat::ServerEvents)
+    }
+
+    /// Whether the response should be parsed as NDJSON.
+    #[must_use]
+    pub fn is_ndjson(&self, headers: &HeaderMap) -> bool {
+        self.format_of(headers) == Some(StreamFormat::NdJson)
+    }
+
+    /// The shared default rules.
+    pub(crate) fn default_ref() -> &'static Self {
+        &DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::HeaderValue;
+
+    fn with_content_type(value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(http::header::CONTENT_TYPE, HeaderValue::from_static(value));
+        headers
+    }
+
+    #[test]
+    fn default_routes_sse_and_ndjson() {
+        let types = StreamingContentTypes::default();
+
+        assert_eq!(
+            types.format_of(&with_content_type("text/event-stream; charset=utf-8")),
+            Some(StreamFormat::ServerEvents)
+        );
+        assert_eq!(
+            types.format_of(&with_content_type("Application/Stream+JSON")),
+            Some(StreamFormat::NdJson)
+        );
+        assert_eq!(
+            types.format_of(&with_content_type("application/x-ndjson; charset=utf-8")),
+            Some(StreamFormat::NdJson)
+        );
+        assert_eq!(
+            types.format_of(&with_content_type("application/json")),
+            None
+        );
+        assert_eq!(types.format_of(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn empty_rules_match_only_added_matchers() {
+        let types = StreamingContentTypes::empty()
+            .with_server_events(ContentTypeMatcher::exact("application/x-sse"))
+            .with_ndjson(ContentTypeMatcher::prefix("application/vnd.acme"));
+
+        assert!(types.is_server_events(&with_content_type("application/x-sse")));
+        assert!(!types.is_server_events(&with_content_type("text/event-stream")));
+        assert!(types.is_ndjson(&with_content_type("application/vnd.acme.v2+json")));
+    }
+
+    #[test]
+    fn prefix_ignores_case_and_leading_whitespace() {
+        let matcher = ContentTypeMatcher::prefix("text/event-stream");
+
+        assert!(matcher.matches("Text/Event-Stream"));
+        assert!(matcher.matches("  text/event-stream;charset=utf-8"));
+        assert!(!matcher.matches("text/event"));
+        assert!(!matcher.matches("application/json"));
+        // A non-ASCII byte at the cut point must not panic.
+        assert!(!matcher.matches("text/event-strea\u{e9}"));
+    }
+
+    #[test]
+    fn deserializes_from_config() {
+        let types: StreamingContentTypes = serde_json::from_value(serde_json::json!({
+            "ndjson": [{ "exact": "application/vnd.acme.stream+json" }]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            types.server_events,
+            StreamingContentTypes::default().server_events
+        );
+        assert_eq!(
+            types.ndjson,
+            vec![ContentTypeMatcher::exact(
+                "application/vnd.acme.stream+json"
+            )]
+        );
+    }
+}
@@ -1,3 +1,134 @@
+use http::{HeaderValue, StatusCode};
+
+use crate::api::ErrorSource;
+use crate::body::Body;
+use crate::rate_limit::RateLimitInfo;
+
+/// A problem type the gateway reports: the GTS identifier used as the
+/// problem-details `type` and the `title` that goes with it.
+///
+/// This is the single table of problem types for both
+/// [`ServiceGatewayError::to_problem_response`] and the gateway's REST error
+/// mapping, so the two cannot drift apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProblemKind {
+    /// GTS problem type identifier.
+    pub type_id: &'static str,
+    /// Short human-readable summary of the error kind.
+    pub title: &'static str,
+}
+
+impl ProblemKind {
+    pub const VALIDATION: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.validation.error.v1",
+        "Validation Error",
+    );
+    pub const CONFLICT: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.resource.conflict.v1",
+        "Conflict",
+    );
+    pub const MISSING_TARGET_HOST: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.routing.missing_target_host.v1",
+        "Missing Target Host",
+    );
+    pub const INVALID_TARGET_HOST: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.routing.invalid_target_host.v1",
+        "Invalid Target Host",
+    );
+    pub const UNKNOWN_TARGET_HOST: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.routing.unknown_target_host.v1",
+        "Unknown Target Host",
+    );
+    pub const AUTH_FAILED: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.auth.failed.v1",
+        "Authentication Failed",
+    );
+    pub const NOT_FOUND: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.resource.not_found.v1",
+        "Not Found",
+    );
+    pub const ROUTE_NOT_FOUND: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.route.not_found.v1",
+        "Route Not Found",
+    );
+    pub const PAYLOAD_TOO_LARGE: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.payload.too_large.v1",
+        "Payload Too Large",
+    );
+    pub const RATE_LIMIT_EXCEEDED: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.rate_limit.exceeded.v1",
+        "Rate Limit Exceeded",
+    );
+    pub const SECRET_NOT_FOUND: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.secret.not_found.v1",
+        "Secret Not Found",
+    );
+    pub const DOWNSTREAM: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.downstream.error.v1",
+        "Downstream Error",
+    );
+    pub const PROTOCOL: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.protocol.error.v1",
+        "Protocol Error",
+    );
+    pub const UPSTREAM_DISABLED: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.routing.upstream_disabled.v1",
+        "Upstream Disabled",
+    );
+    pub const CONNECTION_TIMEOUT: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.timeout.connection.v1",
+        "Connection Timeout",
+    );
+    pub const REQUEST_TIMEOUT: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.timeout.request.v1",
+        "Request Timeout",
+    );
+    pub const GUARD_REJECTED: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.guard.rejected.v1",
+        "Guard Rejected",
+    );
+    pub const CORS_ORIGIN_NOT_ALLOWED: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.cors.origin_not_allowed.v1",
+        "CORS Origin Not Allowed",
+    );
+    pub const CORS_METHOD_NOT_ALLOWED: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.cors.method_not_allowed.v1",
+        "CORS Method Not Allowed",
+    );
+    pub const STREAM_ABORTED: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.stream.aborted.v1",
+        "Stream Aborted",
+    );
+    pub const LINK_UNAVAILABLE: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.link.unavailable.v1",
+        "Link Unavailable",
+    );
+    pub const CIRCUIT_BREAKER_OPEN: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.circuit_breaker.open.v1",
+        "Circuit Breaker Open",
+    );
+    pub const IDLE_TIMEOUT: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.timeout.idle.v1",
+        "Idle Timeout",
+    );
+    pub const PLUGIN_NOT_FOUND: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.plugin.not_found.v1",
+        "Plugin Not Found",
+    );
+    pub const PLUGIN_IN_USE: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.plugin.in_use.v1",
+        "Plugin In Use",
+    );
+    pub const FORBIDDEN: Self = Self::new(
+        "gts.cf.core.errors.err.v1~cf.oagw.authz.forbidden.v1",
+        "Forbidden",
+    );
+
+    const fn new(type_id: &'static str, title: &'static str) -> Self {
+        Self { type_id, title }
+    }
+}
+
 /// Gateway-originated error with all information needed to produce a Problem Details response.
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum ServiceGatewayError {
@@ -30,6 +161,8 @@ pub enum ServiceGatewayError {
         detail: String,
         instance: String,
         retry_after_secs: Option<u64>,
+        /// Quota of the exhausted limit, including when it resets.
+        rate_limit: Option<RateLimitInfo>,
     },
 
     #[error("{detail}")]
@@ -82,13 +215,222 @@ pub enum ServiceGatewayError {
     Forbidden { detail: String },
 }
 
+impl ServiceGatewayError {
+    /// HTTP status code for this error, matching the gateway's REST mapping.
+    #[must_use]
+    pub fn status(&self) -> StatusCode {
+        match self {
+            Self::ValidationError { .. }
+            | Self::MissingTargetHost { .. }
+            | Self::InvalidTargetHost { .. }
+            | Self::UnknownTargetHost { .. } => StatusCode::BAD_REQUEST,
+            Self::AuthenticationFailed { .. } => StatusCode::UNAUTHORIZED,
+            Self::Forbidden { .. } => StatusCode::FORBIDDEN,
+            Self::NotFound { .. } | Self::RouteNotFound { .. } | Self::PluginNotFound { .. } => {
+                StatusCode::NOT_FOUND
+            }
+            Self::PluginInUse { .. } => StatusCode::CONFLICT,
+            Self::PayloadTooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::RateLimitExceeded { .. } => StatusCode::TOO_MANY_REQUESTS,
+            Self::SecretNotFound { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::DownstreamError { .. }
+            | Self::ProtocolError { .. }
+            | Self::StreamAborted { .. } => StatusCode::BAD_GATEWAY,
+            Self::UpstreamDisabled { .. }
+            | Self::LinkUnavailable { .. }
+            | Self::CircuitBreakerOpen { .. } => StatusCode::SERVICE_UNAVAILABLE,
+            Self::ConnectionTimeout { .. }
+            | Self::RequestTimeout { .. }
+            | Self::IdleTimeout { .. } => StatusCode::GATEWAY_TIMEOUT,
+            Self::GuardRejected { status, .. } => StatusCode::from_u16(*status)
+                .ok()
+                .filter(|code| code.is_client_error() || code.is_server_error())
+                .unwrap_or(StatusCode::BAD_REQUEST),
+        }
+    }
+
+    /// The problem type of this error, as used in the gateway's
+    /// problem-details responses.
+    #[must_use]
+    pub fn problem_kind(&self) -> ProblemKind {
+        match self {
+            Self::ValidationError { .. } => ProblemKind::VALIDATION,
+            Self::MissingTargetHost { .. } => ProblemKind::MISSING_TARGET_HOST,
+            Self::InvalidTargetHost { .. } => ProblemKind::INVALID_TARGET_HOST,
+            Self::UnknownTargetHost { .. } => ProblemKind::UNKNOWN_TARGET_HOST,
+            Self::AuthenticationFailed { .. } => ProblemKind::AUTH_FAILED,
+            Self::NotFound { .. } => ProblemKind::NOT_FOUND,
+            Self::RouteNotFound { .. } => ProblemKind::ROUTE_NOT_FOUND,
+            Self::PayloadTooLarge { .. } => ProblemKind::PAYLOAD_TOO_LARGE,
+            Self::RateLimitExceeded { .. } => ProblemKind::RATE_LIMIT_EXCEEDED,
+            Self::SecretNotFound { .. } => ProblemKind::SECRET_NOT_FOUND,
+            Self::DownstreamError { .. } => ProblemKind::DOWNSTREAM,
+            Self::ProtocolError { .. } => ProblemKind::PROTOCOL,
+            Self::UpstreamDisabled { .. } => ProblemKind::UPSTREAM_DISABLED,
+            Self::ConnectionTimeout { .. } => ProblemKind::CONNECTION_TIMEOUT,
+            Self::RequestTimeout { .. } => ProblemKind::REQUEST_TIMEOUT,
+            Self::GuardRejected { .. } => ProblemKind::GUARD_REJECTED,
+            Self::StreamAborted { .. } => ProblemKind::STREAM_ABORTED,
+            Self::LinkUnavailable { .. } => ProblemKind::LINK_UNAVAILABLE,
+            Self::CircuitBreakerOpen { .. } => ProblemKind::CIRCUIT_BREAKER_OPEN,
+            Self::IdleTimeout { .. } => ProblemKind::IDLE_TIMEOUT,
+            Self::PluginNotFound { .. } => ProblemKind::PLUGIN_NOT_FOUND,
+            Self::PluginInUse { .. } => ProblemKind::PLUGIN_IN_USE,
+            Self::Forbidden { .. } => ProblemKind::FORBIDDEN,
+        }
+    }
+
+    /// GTS problem type identifier, as used in the `type` member of the
+    /// gateway's problem-details responses.
+    #[must_use]
+    pub fn problem_type(&self) -> &'static str {
+        self.problem_kind().type_id
+    }
+
+    /// Short human-readable summary of the error kind (the problem `title`).
+    #[must_use]
+    pub fn title(&self) -> &'static str {
+        self.problem_kind().title
+    }
+
+    /// The `instance` this error refers to, or `""` for variants without one.
+    #[must_use]
+    pub fn instance(&self) -> &str {
+        match self {
+            Self::ValidationError { instance, .. }
+            | Self::MissingTargetHost { instance }
+            | Self::InvalidTargetHost { instance }
+            | Self::UnknownTargetHost { instance, .. }
+            | Self::AuthenticationFailed { instance, .. }
+            | Self::NotFound { instance, .. }
+            | Self::RouteNotFound { instance }
+            | Self::PayloadTooLarge { instance, .. }
+            | Self::RateLimitExceeded { instance, .. }
+            | Self::SecretNotFound { instance, .. }
+            | Self::DownstreamError { instance, .. }
+            | Self::ProtocolError { instance, .. }
+            | Self::UpstreamDisabled { instance, .. }
+            | Self::ConnectionTimeout { instance, .. }
+            | Self::RequestTimeout { instance, .. }
+            | Self::GuardRejected { instance, .. }
+            | Self::StreamAborted { instance, .. }
+            | Self::LinkUnavailable { instance, .. }
+            | Self::CircuitBreakerOpen { instance, .. }
+            | Self::IdleTimeout { instance, .. } => instance,
+            Self::PluginNotFound { .. } | Self::PluginInUse { .. } | Self::Forbidden { .. } => "",
+        }
+    }
+
+    /// Build an RFC 9457 problem-details response for this error.
+    ///
+    /// The response has the status from [`status`](Self::status),
+    /// `Content-Type: application/problem+json`, and a JSON body with
+    /// `type`, `title`, `status`, `detail` and (when non-empty) `instance`.
+    /// `RateLimitExceeded` also sets `Retry-After` and the `x-ratelimit-*`
+    /// headers. Like gateway-originated
+    /// proxy errors, the response is tagged with `x-oagw-error-source:
+    /// gateway` and an [`ErrorSource::Gateway`] extension.
+    #[must_use]
+    pub fn to_problem_response(&self) -> http::Response<Body> {
+        let status = self.status();
+        let kind = self.problem_kind();
+        let mut problem = serde_json::json!({
+            "type": kind.type_id,
+            "title": kind.title,
+            "status": status.as_u16(),
+            "detail": self.to_string(),
+        });
+        if !self.instance().is_empty() {
+            problem["instance"] = self.instance().into();
+        }
+
+        let mut resp = http::Response::new(Body::from(problem.to_string()));
+        *resp.status_mut() = status;
+        let headers = resp.headers_mut();
+        headers.insert(
+            http::header::CONTENT_TYPE,
+            HeaderValue::from_static("application/problem+json"),
+        );
+        headers.insert(
+            "x-oagw-error-source",
+            HeaderValue::from_static(ErrorSource::Gateway.as_str()),
+        );
+        if let Self::RateLimitExceeded {
+            retry_after_secs,
+            rate_limit,
+            ..
+        } = self
+        {
+            if let Some(secs) = *retry_after_secs {
+                headers.insert(http::header::RETRY_AFTER, HeaderValue::from(secs));
+            }
+            if let Some(info) = rate_limit {
+                info.insert_headers(headers);
+            }
+        }
+        resp.extensions_mut().insert(ErrorSource::Gateway);
+        resp
+    }
+}
+
 /// Errors produced by the streaming helpers.
 #[derive(Debug, thiserror::Error)]
 pub enum StreamingError {
     /// SSE parse error — a chunk could not be decoded as UTF-8.
+    ///
+    /// When raised by the SSE parser, `detail` includes the byte offset in the
+    /// body stream and the index of the event being parsed, e.g.
+    /// `invalid UTF-8 at byte 10423 (event 57): ...`.
     #[error("SSE parse error: {detail}")]
     ServerEventsParse { detail: String },
 
+    /// An SSE event grew past the decoder's buffer limit without being
+    /// terminated by a blank line. The stream terminates after yielding this
+    /// error; see [`SseDecoder::with_max_buffer_size`].
+    ///
+    /// [`SseDecoder::with_max_buffer_size`]: crate::sse::SseDecoder::with_max_buffer_size
+    #[error("SSE event exceeds the {limit}-byte buffer limit")]
+    ServerEventsBufferExceeded { limit: usize },
+
+    /// A [`ServerEvent`](crate::sse::ServerEvent) cannot be written in SSE
+    /// wire format, e.g. its `id` or `event` contains a line break.
+    #[error("SSE serialize error: {detail}")]
+    ServerEventsSerialize { detail: String },
+
+    /// A reconnecting SSE client got a response that is not an event stream.
+    /// The client ends after yielding this error; see
+    /// [`ReconnectingServerEvents`](crate::sse::ReconnectingServerEvents).
+    #[error("expected an SSE response, got status {status}")]
+    ServerEventsUnexpectedResponse { status: http::StatusCode },
+
+    /// No activity was observed on an SSE stream within the configured idle
+    /// timeout. The stream terminates after yielding this error.
+    #[error("SSE stream idle for longer than {timeout:?}")]
+    IdleTimeout { timeout: std::time::Duration },
+
+    /// An NDJSON line is not valid JSON for the expected type. `detail`
+    /// includes the 1-based line number, e.g. `line 3: expected value`.
+    #[error("NDJSON parse error: {detail}")]
+    NdJsonParse { detail: String },
+
+    /// An NDJSON line grew past the decoder's length limit. The stream
+    /// terminates after yielding this error; see
+    /// [`NdJsonDecoder::with_max_line_length`].
+    ///
+    /// [`NdJsonDecoder::with_max_line_length`]: crate::ndjson::NdJsonDecoder::with_max_line_length
+    #[error("NDJSON line exceeds the {limit}-byte limit")]
+    NdJsonLineTooLong { limit: usize },
+
+    /// A value could not be serialized as an NDJSON line.
+    #[error("NDJSON serialize error: {detail}")]
+    NdJsonSerialize { detail: String },
+
+    /// A body grew past the limit passed to
+    /// [`Body::into_bytes_limited`](crate::body::Body::into_bytes_limited).
+    /// Maps naturally to [`ServiceGatewayError::PayloadTooLarge`].
+    #[error("body exceeds the {limit}-byte limit")]
+    BodyTooLarge { limit: usize },
+
     /// Underlying byte stream produced an error.
     #[error("stream error: {0}")]
     Stream(#[from] Box<dyn std::error::Error + Send + Sync>),
@@ -100,4 +442,124 @@ pub enum StreamingError {
     /// WebSocket bridge error during forwarding.
     #[error("WebSocket bridge error: {detail}")]
     WebSocketBridge { detail: String },
+
+    /// No WebSocket message arrived within the timeout passed to
+    /// [`WebSocketStream::recv_timeout`](crate::ws::WebSocketStream::recv_timeout).
+    /// The connection stays usable.
+    #[error("no WebSocket message received within {timeout:?}")]
+    WebSocketRecvTimeout { timeout: std::time::Duration },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn problem_json(resp: http::Response<Body>) -> serde_json::Value {
+        let bytes = resp.into_body().into_bytes().await.unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn validation_error_becomes_400_problem() {
+        let err = ServiceGatewayError::ValidationError {
+            detail: "bad alias".into(),
+            instance: "/api/oagw/v1/upstreams".into(),
+        };
+        let resp = err.to_problem_response();
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            resp.headers()[http::header::CONTENT_TYPE],
+            "application/problem+json"
+        );
+        assert_eq!(resp.headers()["x-oagw-error-source"], "gateway");
+        assert_eq!(
+            resp.extensions().get::<ErrorSource>(),
+            Some(&ErrorSource::Gateway)
+        );
+        let json = problem_json(resp).await;
+        assert_eq!(
+            json["type"],
+            "gts.cf.core.errors.err.v1~cf.oagw.validation.error.v1"
+        );
+        assert_eq!(json["title"], "Validation Error");
+        assert_eq!(json["status"], 400);
+        assert_eq!(json["detail"], "bad alias");
+        assert_eq!(json["instance"], "/api/oagw/v1/upstreams");
+        // Only borrowed: the error is still there to return or log.
+        assert_eq!(err.problem_kind(), ProblemKind::VALIDATION);
+    }
+
+    #[tokio::test]
+    async fn rate_limit_sets_retry_after() {
+        let err = ServiceGatewayError::RateLimitExceeded {
+            detail: "slow down".into(),
+            instance: "/proxy/openai".into(),
+            retry_after_secs: Some(30),
+            rate_limit: Some(RateLimitInfo {
+                limit: Some(100),
+                remaining: Some(0),
+                reset_epoch: Some(1_700_000_030),
+            }),
+        };
+        let resp = err.to_problem_response();
+
+        assert_eq!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(resp.headers()[http::header::RETRY_AFTER], "30");
+        assert_eq!(resp.headers()["x-ratelimit-limit"], "100");
+        assert_eq!(resp.headers()["x-ratelimit-remaining"], "0");
+        assert_eq!(resp.headers()["x-ratelimit-reset"], "1700000030");
+    }
+
+    #[tokio::test]
+    async fn variants_without_instance_omit_it() {
+        let resp = ServiceGatewayError::Forbidden {
+            detail: "no".into(),
+        }
+        .to_problem_response();
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let json = problem_json(resp).await;
+        assert!(json.get("instance").is_none());
+        assert_eq!(json["detail"], "access forbidden: no");
+    }
+
+    #[test]
+    fn status_mapping_covers_gateway_and_timeout_errors() {
+        let instance = String::new();
+        let cases = [
+            (
+                ServiceGatewayError::RouteNotFound {
+                    instance: instance.clone(),
+                },
+                StatusCode::NOT_FOUND,
+            ),
+            (
+                ServiceGatewayError::DownstreamError {
+                    detail: String::new(),
+                    instance: instance.clone(),
+                },
+                StatusCode::BAD_GATEWAY,
+            ),
+            (
+                ServiceGatewayError::RequestTimeout {
+                    detail: String::new(),
+                    instance: instance.clone(),
+                },
+                StatusCode::GATEWAY_TIMEOUT,
+            ),
+            (
+                ServiceGatewayError::GuardRejected {
+                    status: 200,
+                    error_code: String::new(),
+                    detail: String::new(),
+                    instance,
+                },
+                StatusCode::BAD_REQUEST,
+            ),
+        ];
+        for (err, expected) in cases {
+            assert_eq!(err.status(), expected, "{err:?}");
+        }
+    }
 }
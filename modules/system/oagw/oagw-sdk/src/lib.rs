@@ -1,8 +1,12 @@
 pub mod api;
 pub mod body;
 pub mod codec;
+pub mod content_type;
 pub mod error;
 pub mod multipart;
+pub mod ndjson;
+pub mod rate_limit;
+pub mod resolver;
 pub mod sse;
 pub mod ws;
 
@@ -11,23 +15,41 @@ pub mod models;
 pub use models::{
     AuthConfig, BudgetConfig, BudgetMode, BurstConfig, CorsConfig, CorsHttpMethod,
     CreateRouteRequest, CreateRouteRequestBuilder, CreateUpstreamRequest,
-    CreateUpstreamRequestBuilder, Endpoint, GrpcMatch, HeadersConfig, HttpMatch, HttpMethod,
-    ListQuery, MatchRules, PassthroughMode, PathSuffixMode, PluginBinding, PluginsConfig,
-    RateLimitAlgorithm, RateLimitConfig, RateLimitScope, RateLimitStrategy, RequestHeaderRules,
-    ResponseHeaderRules, Route, Scheme, Server, SharingMode, SustainedRate, UpdateRouteRequest,
-    UpdateRouteRequestBuilder, UpdateUpstreamRequest, UpdateUpstreamRequestBuilder, Upstream,
-    Window,
+    CreateUpstreamRequestBuilder, Endpoint, FieldError, GrpcMatch, HeadersConfig, HttpMatch,
+    HttpMethod, ListQuery, MatchRules, PassthroughMode, PathSuffixMode, PluginBinding,
+    PluginsConfig, RateLimitAlgorithm, RateLimitConfig, RateLimitScope, RateLimitStrategy,
+    RequestHeaderRules, ResponseHeaderRules, Route, Scheme, Server, SharingMode, SustainedRate,
+    UpdateRouteRequest, UpdateRouteRequestBuilder, UpdateUpstreamRequest,
+    UpdateUpstreamRequestBuilder, Upstream, Window,
 };
 
 pub use api::ServiceGatewayClientV1;
-pub use body::Body;
+pub use body::{Body, Trailers, TrailersSender};
+#[cfg(feature = "form")]
+pub use codec::Form;
 pub use codec::Json;
+#[cfg(feature = "msgpack")]
+pub use codec::MsgPack;
+pub use codec::NdJson;
+pub use content_type::{ContentTypeMatcher, StreamFormat, StreamingContentTypes};
 pub use error::StreamingError;
 pub use multipart::{MultipartBody, MultipartError, Part};
-pub use sse::{FromServerEvent, ServerEvent, ServerEventsResponse, ServerEventsStream};
+pub use ndjson::{NdJsonDecoder, parse_ndjson_stream};
+pub use rate_limit::RateLimitInfo;
+pub use resolver::CachingResolver;
+pub use sse::{
+    FromServerEvent, IdleActivity, MergePolicy, ReconnectPolicy, ReconnectingServerEvents,
+    ResponseKind, ServerEvent, ServerEventBuilder, ServerEventRouter, ServerEventsResponse,
+    ServerEventsStream, SseDecoder, SseStats, ToServerEvent, Utf8Mode,
+};
+#[cfg(feature = "axum")]
+pub use sse::{SseWriter, sse_channel};
 #[cfg(feature = "axum")]
 pub use ws::axum_adapter;
+#[cfg(feature = "tungstenite")]
+pub use ws::tungstenite_adapter;
 pub use ws::{
-    FromWebSocketMessage, WebSocketCloseFrame, WebSocketMessage, WebSocketReceiver,
-    WebSocketSender, WebSocketSink, WebSocketStream, WebSocketStreamReceiver,
+    CloseCode, FrameKind, FromWebSocketMessage, RelayEnd, WebSocketCloseFrame, WebSocketMessage,
+    WebSocketReceiver, WebSocketSender, WebSocketSink, WebSocketStream, WebSocketStreamReceiver,
+    WsEvent, WsStats, relay,
 };
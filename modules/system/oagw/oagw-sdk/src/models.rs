@@ -5,6 +5,7 @@
 //! serialization concerns belong to the REST layer.
 
 use std::collections::HashMap;
+use std::fmt;
 
 use uuid::Uuid;
 
@@ -71,8 +72,17 @@ pub struct Server {
 // AuthConfig
 // ---------------------------------------------------------------------------
 
+/// Config keys whose values are shown by `AuthConfig`'s `Debug`. Keys ending
+/// in `_ref` are shown too: they name a credstore secret, not its value.
+const NON_SECRET_AUTH_KEYS: &[&str] =
+    &["header", "prefix", "issuer_url", "token_endpoint", "scopes"];
+
 /// Authentication plugin configuration for an upstream.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// `Debug` redacts the value of every config key not known to be safe, since
+/// plugins may accept inline credentials; `Display` prints only the plugin
+/// type and config key names.
+#[derive(Clone, PartialEq)]
 pub struct AuthConfig {
     /// GTS identifier of the auth plugin type.
     pub plugin_type: String,
@@ -81,6 +91,74 @@ pub struct AuthConfig {
     pub config: Option<HashMap<String, String>>,
 }
 
+impl AuthConfig {
+    /// Config keys in sorted order, so formatted output is stable.
+    fn sorted_config(&self) -> Vec<(&str, &str)> {
+        let mut entries: Vec<_> = self
+            .config
+            .iter()
+            .flatten()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        entries.sort_unstable();
+        entries
+    }
+}
+
+fn is_non_secret_auth_key(key: &str) -> bool {
+    key.ends_with("_ref") || NON_SECRET_AUTH_KEYS.contains(&key)
+}
+
+impl fmt::Debug for AuthConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct RedactedConfig<'a>(Vec<(&'a str, &'a str)>);
+
+        impl fmt::Debug for RedactedConfig<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut map = f.debug_map();
+                for (key, value) in &self.0 {
+                    if is_non_secret_auth_key(key) {
+                        map.entry(key, value);
+                    } else {
+                        map.entry(key, &format_args!("[REDACTED]"));
+                    }
+                }
+                map.finish()
+            }
+        }
+
+        f.debug_struct("AuthConfig")
+            .field("plugin_type", &self.plugin_type)
+            .field("sharing", &self.sharing)
+            .field(
+                "config",
+                &self
+                    .config
+                    .as_ref()
+                    .map(|_| RedactedConfig(self.sorted_config())),
+            )
+            .finish()
+    }
+}
+
+impl fmt::Display for AuthConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.plugin_type)?;
+        let entries = self.sorted_config();
+        if !entries.is_empty() {
+            f.write_str(" [")?;
+            for (i, (key, _)) in entries.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                f.write_str(key)?;
+            }
+            f.write_str("]")?;
+        }
+        Ok(())
+    }
+}
+
 // ---------------------------------------------------------------------------
 // HeadersConfig
 // ---------------------------------------------------------------------------
@@ -340,6 +418,24 @@ pub struct Upstream {
     pub tags: Vec<String>,
 }
 
+impl Upstream {
+    /// Check that every endpoint uses one of the `allowed` schemes, e.g. to
+    /// enforce TLS-only upstreams client-side.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FieldError`] naming each endpoint whose scheme is not allowed.
+    pub fn validate_schemes(&self, allowed: &[Scheme]) -> Result<(), Vec<FieldError>> {
+        let mut errors = Vec::new();
+        validate_schemes(&self.server, allowed, &mut errors);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Pagination
 // ---------------------------------------------------------------------------
@@ -359,6 +455,114 @@ impl Default for ListQuery {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Builder validation
+// ---------------------------------------------------------------------------
+
+/// A single validation problem found by a request builder's `try_build`.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{field}: {message}")]
+pub struct FieldError {
+    /// Dotted path of the offending field (e.g. `server.endpoints[1].host`).
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+fn validate_endpoints(server: &Server, errors: &mut Vec<FieldError>) {
+    let Some(first) = server.endpoints.first() else {
+        errors.push(FieldError::new(
+            "server.endpoints",
+            "must contain at least one endpoint",
+        ));
+        return;
+    };
+    for (i, ep) in server.endpoints.iter().enumerate() {
+        if ep.host.trim().is_empty() {
+            errors.push(FieldError::new(
+                format!("server.endpoints[{i}].host"),
+                "is required",
+            ));
+        }
+        if ep.port == 0 {
+            errors.push(FieldError::new(
+                format!("server.endpoints[{i}].port"),
+                "must be non-zero",
+            ));
+        }
+        if ep.scheme != first.scheme {
+            errors.push(FieldError::new(
+                format!("server.endpoints[{i}].scheme"),
+                format!(
+                    "{:?} differs from endpoints[0] scheme {:?}; all endpoints must share the same scheme",
+                    ep.scheme, first.scheme
+                ),
+            ));
+        }
+    }
+}
+
+fn validate_schemes(server: &Server, allowed: &[Scheme], errors: &mut Vec<FieldError>) {
+    for (i, ep) in server.endpoints.iter().enumerate() {
+        if !allowed.contains(&ep.scheme) {
+            errors.push(FieldError::new(
+                format!("server.endpoints[{i}].scheme"),
+                format!("{:?} is not allowed (allowed: {allowed:?})", ep.scheme),
+            ));
+        }
+    }
+}
+
+fn validate_rate_limit(rate_limit: &RateLimitConfig, errors: &mut Vec<FieldError>) {
+    if rate_limit.sustained.rate == 0 {
+        errors.push(FieldError::new(
+            "rate_limit.sustained.rate",
+            "must be at least 1",
+        ));
+    }
+    if let Some(burst) = &rate_limit.burst
+        && burst.capacity == 0
+    {
+        errors.push(FieldError::new(
+            "rate_limit.burst.capacity",
+            "must be at least 1",
+        ));
+    }
+    if let Some(budget) = &rate_limit.budget {
+        match budget.mode {
+            BudgetMode::Allocated | BudgetMode::Shared if budget.total.is_none() => {
+                errors.push(FieldError::new(
+                    "rate_limit.budget.total",
+                    "is required when budget.mode is 'allocated' or 'shared'",
+                ));
+            }
+            _ => {}
+        }
+        if budget.total == Some(0) {
+            errors.push(FieldError::new(
+                "rate_limit.budget.total",
+                "must be at least 1",
+            ));
+        }
+        if let Some(ratio) = budget.overcommit_ratio
+            && !(1.0..=2.0).contains(&ratio)
+        {
+            errors.push(FieldError::new(
+                "rate_limit.budget.overcommit_ratio",
+                "must be between 1.0 and 2.0",
+            ));
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Upstream DTOs
 // ---------------------------------------------------------------------------
@@ -392,6 +596,7 @@ impl CreateUpstreamRequest {
             cors: None,
             tags: vec![],
             enabled: true,
+            allowed_schemes: None,
         }
     }
 
@@ -438,6 +643,8 @@ pub struct CreateUpstreamRequestBuilder {
     cors: Option<CorsConfig>,
     tags: Vec<String>,
     enabled: bool,
+    /// Schemes accepted by `try_build`; `None` allows all.
+    allowed_schemes: Option<Vec<Scheme>>,
 }
 
 impl CreateUpstreamRequestBuilder {
@@ -473,6 +680,47 @@ impl CreateUpstreamRequestBuilder {
         self.enabled = enabled;
         self
     }
+    /// Restrict the endpoint schemes accepted by [`try_build`](Self::try_build),
+    /// e.g. `[Scheme::Https, Scheme::Wss]` to forbid plaintext upstreams.
+    ///
+    /// All schemes are allowed unless this is set.
+    pub fn allowed_schemes(mut self, schemes: impl IntoIterator<Item = Scheme>) -> Self {
+        self.allowed_schemes = Some(schemes.into_iter().collect());
+        self
+    }
+    /// Validate every field and build the request.
+    ///
+    /// Unlike [`build`](Self::build), which performs no checks, this reports
+    /// all problems at once: empty protocol or alias, missing or inconsistent
+    /// endpoints, schemes outside [`allowed_schemes`](Self::allowed_schemes),
+    /// and invalid rate-limit settings. The gateway still performs
+    /// its own validation; this lets callers catch mistakes client-side.
+    ///
+    /// # Errors
+    ///
+    /// Returns every [`FieldError`] found, in field order.
+    pub fn try_build(self) -> Result<CreateUpstreamRequest, Vec<FieldError>> {
+        let mut errors = Vec::new();
+        if self.protocol.trim().is_empty() {
+            errors.push(FieldError::new("protocol", "is required"));
+        }
+        if self.alias.as_deref().is_some_and(|a| a.trim().is_empty()) {
+            errors.push(FieldError::new("alias", "must not be empty when set"));
+        }
+        validate_endpoints(&self.server, &mut errors);
+        if let Some(allowed) = &self.allowed_schemes {
+            validate_schemes(&self.server, allowed, &mut errors);
+        }
+        if let Some(rate_limit) = &self.rate_limit {
+            validate_rate_limit(rate_limit, &mut errors);
+        }
+
+        if errors.is_empty() {
+            Ok(self.build())
+        } else {
+            Err(errors)
+        }
+    }
     pub fn build(self) -> CreateUpstreamRequest {
         CreateUpstreamRequest {
             server: self.server,
@@ -830,6 +1078,49 @@ impl UpdateRouteRequestBuilder {
 mod tests {
     use super::*;
 
+    fn auth_with_inline_credentials() -> AuthConfig {
+        AuthConfig {
+            plugin_type: "gts.x.core.oagw.auth_plugin.v1~x.core.oagw.basic_auth.v1".into(),
+            sharing: SharingMode::Private,
+            config: Some(HashMap::from([
+                ("header".into(), "authorization".into()),
+                ("username".into(), "svc-user".into()),
+                ("password".into(), "hunter2-p4ss".into()),
+                ("api_key".into(), "sk-live-abc123".into()),
+                ("secret_ref".into(), "cred://upstream-key".into()),
+            ])),
+        }
+    }
+
+    #[test]
+    fn auth_config_debug_redacts_secret_values() {
+        let debug = format!("{:?}", auth_with_inline_credentials());
+
+        for secret in ["hunter2-p4ss", "sk-live-abc123", "svc-user"] {
+            assert!(!debug.contains(secret), "{secret} leaked: {debug}");
+        }
+        assert!(debug.contains(r#""password": [REDACTED]"#), "{debug}");
+        assert!(debug.contains(r#""header": "authorization""#), "{debug}");
+        assert!(
+            debug.contains(r#""secret_ref": "cred://upstream-key""#),
+            "{debug}"
+        );
+
+        let pretty = format!("{:#?}", auth_with_inline_credentials());
+        assert!(!pretty.contains("hunter2-p4ss"), "{pretty}");
+    }
+
+    #[test]
+    fn auth_config_display_shows_type_and_keys_only() {
+        let display = auth_with_inline_credentials().to_string();
+
+        assert_eq!(
+            display,
+            "gts.x.core.oagw.auth_plugin.v1~x.core.oagw.basic_auth.v1 \
+             [api_key, header, password, secret_ref, username]"
+        );
+    }
+
     #[test]
     fn alias_standard_port_omitted() {
         let ep = Endpoint {
@@ -938,4 +1229,147 @@ mod tests {
     fn default_path_suffix_mode_is_append() {
         assert_eq!(PathSuffixMode::default(), PathSuffixMode::Append);
     }
+
+    fn https_server(hosts: &[&str]) -> Server {
+        Server {
+            endpoints: hosts
+                .iter()
+                .map(|h| Endpoint {
+                    scheme: Scheme::Https,
+                    host: (*h).into(),
+                    port: 443,
+                })
+                .collect(),
+        }
+    }
+
+    fn rate_limit(rate: u32) -> RateLimitConfig {
+        RateLimitConfig {
+            sharing: SharingMode::Private,
+            algorithm: RateLimitAlgorithm::TokenBucket,
+            sustained: SustainedRate {
+                rate,
+                window: Window::Second,
+            },
+            burst: None,
+            budget: None,
+            scope: RateLimitScope::Tenant,
+            strategy: RateLimitStrategy::Reject,
+            cost: 1,
+            response_headers: false,
+        }
+    }
+
+    #[test]
+    fn try_build_accepts_valid_request() {
+        let req = CreateUpstreamRequest::builder(https_server(&["api.openai.com"]), "http")
+            .alias("openai")
+            .rate_limit(rate_limit(10))
+            .try_build()
+            .unwrap();
+        assert_eq!(req.alias(), Some("openai"));
+    }
+
+    #[test]
+    fn try_build_reports_every_problem() {
+        let mut server = https_server(&["a.example.com", ""]);
+        server.endpoints[1].scheme = Scheme::Http;
+        server.endpoints[1].port = 0;
+        let mut rl = rate_limit(0);
+        rl.budget = Some(BudgetConfig {
+            mode: BudgetMode::Allocated,
+            total: None,
+            overcommit_ratio: Some(3.0),
+        });
+
+        let errors = CreateUpstreamRequest::builder(server, " ")
+            .alias("")
+            .rate_limit(rl)
+            .try_build()
+            .unwrap_err();
+
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec![
+                "protocol",
+                "alias",
+                "server.endpoints[1].host",
+                "server.endpoints[1].port",
+                "server.endpoints[1].scheme",
+                "rate_limit.sustained.rate",
+                "rate_limit.budget.total",
+                "rate_limit.budget.overcommit_ratio",
+            ]
+        );
+    }
+
+    #[test]
+    fn try_build_rejects_empty_server() {
+        let errors = CreateUpstreamRequest::builder(Server { endpoints: vec![] }, "http")
+            .try_build()
+            .unwrap_err();
+        assert_eq!(
+            errors,
+            vec![FieldError {
+                field: "server.endpoints".into(),
+                message: "must contain at least one endpoint".into(),
+            }]
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "server.endpoints: must contain at least one endpoint"
+        );
+    }
+
+    #[test]
+    fn try_build_allows_all_schemes_by_default() {
+        let mut server = https_server(&["internal.local"]);
+        server.endpoints[0].scheme = Scheme::Http;
+        assert!(
+            CreateUpstreamRequest::builder(server, "http")
+                .try_build()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn try_build_rejects_disallowed_scheme() {
+        let mut server = https_server(&["internal.local"]);
+        server.endpoints[0].scheme = Scheme::Http;
+
+        let errors = CreateUpstreamRequest::builder(server, "http")
+            .allowed_schemes([Scheme::Https, Scheme::Wss])
+            .try_build()
+            .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "server.endpoints[0].scheme");
+        assert_eq!(
+            errors[0].message,
+            "Http is not allowed (allowed: [Https, Wss])"
+        );
+    }
+
+    #[test]
+    fn upstream_validate_schemes() {
+        let upstream = Upstream {
+            id: Uuid::nil(),
+            tenant_id: Uuid::nil(),
+            alias: "api.openai.com".into(),
+            server: https_server(&["api.openai.com"]),
+            protocol: "http".into(),
+            enabled: true,
+            auth: None,
+            headers: None,
+            plugins: None,
+            rate_limit: None,
+            cors: None,
+            tags: vec![],
+        };
+        assert!(upstream.validate_schemes(&[Scheme::Https]).is_ok());
+
+        let errors = upstream.validate_schemes(&[Scheme::Grpc]).unwrap_err();
+        assert_eq!(errors[0].field, "server.endpoints[0].scheme");
+    }
 }
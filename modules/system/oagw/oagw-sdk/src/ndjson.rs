@@ -0,0 +1,388 @@
+//! Newline-delimited JSON (NDJSON / JSON Lines) stream parsing.
+//!
+//! Many upstreams stream one JSON document per line instead of SSE. Bodies
+//! are split on `\n` as chunks arrive, so a value is yielded as soon as its
+//! line is complete, wherever the chunk boundaries fall.
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+
+use futures_core::Stream;
+use futures_util::StreamExt;
+use serde::de::DeserializeOwned;
+
+use crate::body::BodyStream;
+use crate::error::StreamingError;
+
+/// Default for [`NdJsonDecoder::with_max_line_length`].
+const DEFAULT_MAX_LINE_LENGTH: usize = 1024 * 1024;
+
+/// Incremental, sans-IO NDJSON decoder.
+///
+/// Bytes are pushed in arbitrarily sized slices; each value is returned as
+/// soon as its terminating `\n` arrives. Only newly pushed bytes are scanned
+/// for line breaks, so a long line split over many slices costs linear time.
+/// [`parse_ndjson_stream`] is a thin wrapper over it.
+///
+/// ```
+/// use oagw_sdk::ndjson::NdJsonDecoder;
+///
+/// let mut decoder = NdJsonDecoder::new();
+/// assert!(decoder.push::<u32>(b"1").is_empty());
+/// let values: Vec<u32> = decoder.push(b"2\n3").into_iter().map(Result::unwrap).collect();
+/// assert_eq!(values, [12]);
+/// assert_eq!(decoder.finish::<u32>().unwrap().unwrap(), 3);
+/// ```
+#[derive(Debug)]
+pub struct NdJsonDecoder {
+    /// Bytes of the current unterminated line.
+    buf: Vec<u8>,
+    /// Number of lines consumed so far, for error messages.
+    line: usize,
+    /// Longest line, in bytes, accepted before failing.
+    max_line_length: usize,
+}
+
+impl Default for NdJsonDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NdJsonDecoder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            buf: Vec::new(),
+            line: 0,
+            max_line_length: DEFAULT_MAX_LINE_LENGTH,
+        }
+    }
+
+    /// Fail once a line exceeds `bytes` (default: 1 MiB), instead of
+    /// buffering an upstream that never sends a newline until memory runs
+    /// out.
+    ///
+    /// The unterminated line is checked after each slice, so the buffer may
+    /// overshoot by at most one slice.
+    #[must_use]
+    pub fn with_max_line_length(mut self, bytes: usize) -> Self {
+        self.max_line_length = bytes;
+        self
+    }
+
+    /// Feed the next slice of the byte stream, returning a result for each
+    /// non-blank line it completes.
+    ///
+    /// A line that is not valid JSON for `T` yields
+    /// [`StreamingError::NdJsonParse`]; later lines are still decoded.
+    ///
+    /// A line longer than the [limit](Self::with_max_line_length), complete
+    /// or not, yields [`StreamingError::NdJsonLineTooLong`] as the last
+    /// result; the rest of the slice and the buffered partial line are
+    /// discarded.
+    pub fn push<T: DeserializeOwned>(&mut self, chunk: &[u8]) -> Vec<Result<T, StreamingError>> {
+        let scan_from = self.buf.len();
+        self.buf.extend_from_slice(chunk);
+
+        let mut results = Vec::new();
+        let mut line_start = 0;
+        let newlines = self.buf[scan_from..]
+            .iter()
+            .enumerate()
+            .filter(|&(_, &b)| b == b'\n')
+            .map(|(i, _)| scan_from + i);
+        for end in newlines {
+            let line = &self.buf[line_start..end];
+            self.line += 1;
+            if line.len() > self.max_line_length {
+                self.buf.clear();
+                results.push(Err(self.too_long()));
+                return results;
+            }
+            results.extend(parse_line(line, self.line));
+            line_start = end + 1;
+        }
+        self.buf.drain(..line_start);
+
+        if self.buf.len() > self.max_line_length {
+            self.buf.clear();
+            results.push(Err(self.too_long()));
+        }
+        results
+    }
+
+    /// Signal end of stream, parsing the trailing line left without a
+    /// terminating `\n`, if any.
+    pub fn finish<T: DeserializeOwned>(&mut self) -> Option<Result<T, StreamingError>> {
+        let rest = std::mem::take(&mut self.buf);
+        if rest.is_empty() {
+            return None;
+        }
+        self.line += 1;
+        parse_line(&rest, self.line)
+    }
+
+    fn too_long(&self) -> StreamingError {
+        StreamingError::NdJsonLineTooLong {
+            limit: self.max_line_length,
+        }
+    }
+}
+
+/// Parse one line; blank lines (after trimming a `\r`) yield nothing.
+fn parse_line<T: DeserializeOwned>(
+    line: &[u8],
+    number: usize,
+) -> Option<Result<T, StreamingError>> {
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    if line.iter().all(u8::is_ascii_whitespace) {
+        return None;
+    }
+    Some(
+        serde_json::from_slice(line).map_err(|e| StreamingError::NdJsonParse {
+            detail: format!("line {number}: {e}"),
+        }),
+    )
+}
+
+struct ParseState<T> {
+    body: BodyStream,
+    decoder: NdJsonDecoder,
+    pending: VecDeque<Result<T, StreamingError>>,
+    done: bool,
+}
+
+/// Parse a raw byte stream of newline-delimited JSON into values of `T`.
+///
+/// Chunks are buffered internally and split on `\n`, so lines may span any
+/// number of chunks; `\r\n` line endings and blank lines are accepted. A
+/// final line without a trailing newline is parsed when the body ends.
+///
+/// A line that fails to deserialize yields
+/// [`StreamingError::NdJsonParse`] and parsing continues with the next line.
+/// A line over 1 MiB yields [`StreamingError::NdJsonLineTooLong`], and an
+/// error from the body itself is yielded as [`StreamingError::Stream`];
+/// both end the stream. Use [`parse_ndjson_stream_with_decoder`] to change
+/// the line limit.
+///
+/// Pair with [`StreamingContentTypes::is_ndjson`](crate::StreamingContentTypes::is_ndjson)
+/// to pick this parser for a response:
+///
+/// ```ignore
+/// if StreamingContentTypes::default().is_ndjson(resp.headers()) {
+///     let mut rows = parse_ndjson_stream::<Row>(resp.into_body().into_stream());
+///     while let Some(row) = rows.next().await {
+///         handle(row?);
+///     }
+/// }
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn parse_ndjson_stream<T>(
+    body: BodyStream,
+) -> Pin<Box<dyn Stream<Item = Result<T, StreamingError>> + Send>>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    parse_ndjson_stream_with_decoder(body, NdJsonDecoder::new())
+}
+
+/// Like [`parse_ndjson_stream`], but decodes with a configured `decoder`,
+/// e.g. `NdJsonDecoder::new().with_max_line_length(64 * 1024)`.
+#[allow(clippy::type_complexity)]
+pub fn parse_ndjson_stream_with_decoder<T>(
+    body: BodyStream,
+    decoder: NdJsonDecoder,
+) -> Pin<Box<dyn Stream<Item = Result<T, StreamingError>> + Send>>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    let state = ParseState {
+        body,
+        decoder,
+        pending: VecDeque::new(),
+        done: false,
+    };
+
+    Box::pin(futures_util::stream::unfold(
+        state,
+        |mut state| async move {
+            loop {
+                if let Some(item) = state.pending.pop_front() {
+                    return Some((item, state));
+                }
+                if state.done {
+                    let rest = state.decoder.finish()?;
+                    return Some((rest, state));
+                }
+                match state.body.next().await {
+                    Some(Ok(chunk)) => {
+                        let results = state.decoder.push(&chunk);
+                        // The decoder has discarded its buffer; stop reading.
+                        if matches!(
+                            results.last(),
+                            Some(Err(StreamingError::NdJsonLineTooLong { .. }))
+                        ) {
+                            state.done = true;
+                        }
+                        state.pending.extend(results);
+                    }
+                    Some(Err(e)) => {
+                        // The body cannot continue; drop any partial line.
+                        state.decoder.buf.clear();
+                        state.done = true;
+                        return Some((Err(StreamingError::Stream(e)), state));
+                    }
+                    None => state.done = true,
+                }
+            }
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::body::BoxError;
+    use crate::codec::NdJson;
+
+    #[derive(Debug, PartialEq, Deserialize, serde::Serialize)]
+    struct Row {
+        id: u32,
+    }
+
+    fn body(chunks: &[&'static str]) -> BodyStream {
+        let chunks: Vec<Result<Bytes, BoxError>> = chunks
+            .iter()
+            .map(|c| Ok(Bytes::from_static(c.as_bytes())))
+            .collect();
+        Box::pin(futures_util::stream::iter(chunks))
+    }
+
+    async fn ids(chunks: &[&'static str]) -> Vec<u32> {
+        parse_ndjson_stream::<Row>(body(chunks))
+            .map(|row| row.unwrap().id)
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn lines_split_across_chunks() {
+        assert_eq!(
+            ids(&["{\"id\":", "1}\n{\"i", "d\":2}\n", "{\"id\":3}\n"]).await,
+            [1, 2, 3]
+        );
+    }
+
+    #[tokio::test]
+    async fn trailing_partial_line_is_flushed_at_end() {
+        assert_eq!(ids(&["{\"id\":1}\n{\"id\"", ":2}"]).await, [1, 2]);
+    }
+
+    #[tokio::test]
+    async fn crlf_and_blank_lines_are_accepted() {
+        assert_eq!(
+            ids(&["{\"id\":1}\r\n\r\n", "\n{\"id\":2}\r\n"]).await,
+            [1, 2]
+        );
+        assert!(ids(&[]).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn invalid_line_reports_line_number_and_continues() {
+        let items: Vec<_> = parse_ndjson_stream::<Row>(body(&["{\"id\":1}\nnope\n{\"id\":3}\n"]))
+            .collect()
+            .await;
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].as_ref().unwrap().id, 1);
+        let err = items[1].as_ref().unwrap_err();
+        assert!(
+            matches!(err, StreamingError::NdJsonParse { detail } if detail.starts_with("line 2:")),
+            "got: {err}"
+        );
+        assert_eq!(items[2].as_ref().unwrap().id, 3);
+    }
+
+    #[tokio::test]
+    async fn body_error_ends_stream() {
+        let chunks = futures_util::stream::iter([
+            Ok(Bytes::from("{\"id\":1}\n{\"id\"")),
+            Err(BoxError::from("reset")),
+        ]);
+        let items: Vec<_> = parse_ndjson_stream::<Row>(Box::pin(chunks)).collect().await;
+        assert_eq!(items.len(), 2);
+        assert!(matches!(items[1], Err(StreamingError::Stream(_))));
+    }
+
+    #[tokio::test]
+    async fn unterminated_line_over_limit_ends_stream() {
+        let decoder = NdJsonDecoder::new().with_max_line_length(16);
+        let items: Vec<_> = parse_ndjson_stream_with_decoder::<Row>(
+            body(&[
+                "{\"id\":1}\n{\"id\":",
+                "          ",
+                "          ",
+                "2}\n{\"id\":3}\n",
+            ]),
+            decoder,
+        )
+        .collect()
+        .await;
+        assert_eq!(items.len(), 2, "stream must stop after the error");
+        assert_eq!(items[0].as_ref().unwrap().id, 1);
+        assert!(matches!(
+            items[1],
+            Err(StreamingError::NdJsonLineTooLong { limit: 16 })
+        ));
+    }
+
+    #[test]
+    fn complete_line_over_limit_fails_and_discards_rest() {
+        let mut decoder = NdJsonDecoder::new().with_max_line_length(10);
+        let results = decoder.push::<Row>(b"{\"id\":1}\n{\"id\":     2}\n{\"id\":3}\n");
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap().id, 1);
+        assert!(matches!(
+            results[1],
+            Err(StreamingError::NdJsonLineTooLong { limit: 10 })
+        ));
+        assert!(decoder.finish::<Row>().is_none());
+    }
+
+    #[test]
+    fn long_line_fed_one_byte_at_a_time() {
+        // Rescanning the whole buffer on every push would make this quadratic
+        // (~10^11 byte comparisons) and never finish in practice.
+        const LEN: usize = 500_000;
+        let line = format!("\"{}\"\n", "a".repeat(LEN));
+        let mut decoder = NdJsonDecoder::new();
+        let mut results = Vec::new();
+        for byte in line.as_bytes() {
+            results.extend(decoder.push::<String>(std::slice::from_ref(byte)));
+        }
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].as_ref().unwrap().len(), LEN);
+        assert!(decoder.finish::<String>().is_none());
+    }
+
+    #[tokio::test]
+    async fn ndjson_codec_round_trips() {
+        let wire = [NdJson(Row { id: 7 }), NdJson(Row { id: 8 })]
+            .iter()
+            .map(|row| row.to_line().unwrap())
+            .collect::<Vec<_>>()
+            .concat();
+        assert_eq!(wire, b"{\"id\":7}\n{\"id\":8}\n");
+
+        let chunk: Result<Bytes, BoxError> = Ok(Bytes::from(wire));
+        let rows: Vec<_> =
+            parse_ndjson_stream::<NdJson<Row>>(Box::pin(futures_util::stream::iter([chunk])))
+                .map(|row| row.unwrap().into_inner())
+                .collect()
+                .await;
+        assert_eq!(rows, [Row { id: 7 }, Row { id: 8 }]);
+    }
+}
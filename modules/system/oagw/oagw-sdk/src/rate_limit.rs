@@ -0,0 +1,197 @@
+//! Rate-limit quota reported in response headers.
+
+use std::time::{Duration, SystemTime};
+
+use http::{HeaderMap, HeaderValue};
+
+/// Header names the gateway emits, also used by many upstreams.
+const LIMIT_HEADER: &str = "x-ratelimit-limit";
+const REMAINING_HEADER: &str = "x-ratelimit-remaining";
+const RESET_HEADER: &str = "x-ratelimit-reset";
+
+/// Unprefixed names from the IETF `RateLimit` header draft.
+const DRAFT_LIMIT_HEADER: &str = "ratelimit-limit";
+const DRAFT_REMAINING_HEADER: &str = "ratelimit-remaining";
+const DRAFT_RESET_HEADER: &str = "ratelimit-reset";
+
+/// Reset values at or above this are Unix timestamps rather than
+/// delta-seconds (2001-09-09; no window is that long).
+const EPOCH_THRESHOLD: u64 = 1_000_000_000;
+
+/// Remaining quota reported by the gateway or an upstream.
+///
+/// Parsed from `X-RateLimit-Limit`, `-Remaining` and `-Reset`, falling back
+/// to the unprefixed `RateLimit-*` names. The gateway attaches the same
+/// values to [`ServiceGatewayError::RateLimitExceeded`].
+///
+/// [`ServiceGatewayError::RateLimitExceeded`]: crate::error::ServiceGatewayError::RateLimitExceeded
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// Requests allowed per window.
+    pub limit: Option<u64>,
+    /// Requests left in the current window.
+    pub remaining: Option<u64>,
+    /// When the window resets, as Unix epoch seconds.
+    pub reset_epoch: Option<u64>,
+}
+
+impl RateLimitInfo {
+    /// Parse rate-limit headers, or `None` if none are present and valid.
+    ///
+    /// The reset header may be either a Unix timestamp (GitHub style) or
+    /// seconds from now (IETF draft style); both are normalised to
+    /// [`reset_epoch`](Self::reset_epoch). Fractional delta-seconds round up.
+    #[must_use]
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        Self::from_headers_at(headers, now_epoch())
+    }
+
+    /// Like [`from_headers`](Self::from_headers), with the current time
+    /// given as Unix epoch seconds.
+    #[must_use]
+    pub fn from_headers_at(headers: &HeaderMap, now_epoch: u64) -> Option<Self> {
+        let info = Self {
+            limit: header_u64(headers, LIMIT_HEADER, DRAFT_LIMIT_HEADER),
+            remaining: header_u64(headers, REMAINING_HEADER, DRAFT_REMAINING_HEADER),
+            reset_epoch: header_str(headers, RESET_HEADER, DRAFT_RESET_HEADER)
+                .and_then(|value| parse_reset(value, now_epoch)),
+        };
+        (info != Self::default()).then_some(info)
+    }
+
+    /// Time until the window resets, or `None` if unknown. Zero once the
+    /// reset time has passed.
+    #[must_use]
+    pub fn reset_after(&self) -> Option<Duration> {
+        self.reset_after_at(now_epoch())
+    }
+
+    /// Like [`reset_after`](Self::reset_after), relative to `now_epoch`.
+    #[must_use]
+    pub fn reset_after_at(&self, now_epoch: u64) -> Option<Duration> {
+        self.reset_epoch
+            .map(|reset| Duration::from_secs(reset.saturating_sub(now_epoch)))
+    }
+
+    /// Write the known values as `x-ratelimit-*` headers.
+    pub fn insert_headers(&self, headers: &mut HeaderMap) {
+        let fields = [
+            (LIMIT_HEADER, self.limit),
+            (REMAINING_HEADER, self.remaining),
+            (RESET_HEADER, self.reset_epoch),
+        ];
+        for (name, value) in fields {
+            if let Some(value) = value {
+                headers.insert(name, HeaderValue::from(value));
+            }
+        }
+    }
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str, fallback: &str) -> Option<&'a str> {
+    headers
+        .get(name)
+        .or_else(|| headers.get(fallback))
+        .and_then(|v| v.to_str().ok())
+        .map(str::trim)
+}
+
+fn header_u64(headers: &HeaderMap, name: &str, fallback: &str) -> Option<u64> {
+    header_str(headers, name, fallback).and_then(|v| v.parse().ok())
+}
+
+fn parse_reset(value: &str, now_epoch: u64) -> Option<u64> {
+    let secs = match value.parse::<u64>() {
+        Ok(secs) => secs,
+        Err(_) => {
+            let secs = value
+                .parse::<f64>()
+                .ok()
+                .filter(|s| s.is_finite() && *s >= 0.0)?;
+            Duration::try_from_secs_f64(secs.ceil()).ok()?.as_secs()
+        }
+    };
+    if secs >= EPOCH_THRESHOLD {
+        Some(secs)
+    } else {
+        Some(now_epoch.saturating_add(secs))
+    }
+}
+
+fn now_epoch() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1_700_000_000;
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|(name, value)| {
+                (
+                    http::HeaderName::from_static(name),
+                    HeaderValue::from_static(value),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parses_gateway_headers_with_epoch_reset() {
+        let h = headers(&[
+            ("x-ratelimit-limit", "100"),
+            ("x-ratelimit-remaining", "7"),
+            ("x-ratelimit-reset", "1700000030"),
+        ]);
+        let info = RateLimitInfo::from_headers_at(&h, NOW).unwrap();
+
+        assert_eq!(
+            info,
+            RateLimitInfo {
+                limit: Some(100),
+                remaining: Some(7),
+                reset_epoch: Some(NOW + 30),
+            }
+        );
+        assert_eq!(info.reset_after_at(NOW), Some(Duration::from_secs(30)));
+        assert_eq!(info.reset_after_at(NOW + 60), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn delta_reset_and_draft_names_are_normalised() {
+        let h = headers(&[("ratelimit-remaining", "0"), ("ratelimit-reset", "2.5")]);
+        let info = RateLimitInfo::from_headers_at(&h, NOW).unwrap();
+
+        assert_eq!(info.limit, None);
+        assert_eq!(info.remaining, Some(0));
+        assert_eq!(info.reset_epoch, Some(NOW + 3));
+    }
+
+    #[test]
+    fn missing_or_malformed_headers_yield_none() {
+        assert_eq!(RateLimitInfo::from_headers_at(&HeaderMap::new(), NOW), None);
+        let h = headers(&[("x-ratelimit-limit", "lots"), ("x-ratelimit-reset", "-1")]);
+        assert_eq!(RateLimitInfo::from_headers_at(&h, NOW), None);
+    }
+
+    #[test]
+    fn insert_headers_round_trips() {
+        let info = RateLimitInfo {
+            limit: Some(10),
+            remaining: None,
+            reset_epoch: Some(NOW + 5),
+        };
+        let mut h = HeaderMap::new();
+        info.insert_headers(&mut h);
+
+        assert!(h.get("x-ratelimit-remaining").is_none());
+        assert_eq!(RateLimitInfo::from_headers_at(&h, NOW), Some(info));
+    }
+}
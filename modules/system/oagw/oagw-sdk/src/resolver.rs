@@ -0,0 +1,537 @@
+//! Client-side caching of proxy target resolution.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use modkit_security::SecurityContext;
+use parking_lot::Mutex;
+use uuid::Uuid;
+
+use crate::api::ServiceGatewayClientV1;
+use crate::body::Body;
+use crate::error::ServiceGatewayError;
+use crate::{
+    CreateRouteRequest, CreateUpstreamRequest, ListQuery, Route, UpdateRouteRequest,
+    UpdateUpstreamRequest, Upstream,
+};
+
+/// Default cap on the number of cached resolutions.
+const DEFAULT_MAX_ENTRIES: usize = 1024;
+
+/// Cache key: resolutions are scoped to the caller's tenant and subject, so
+/// one caller never observes another caller's (possibly differently
+/// authorized) result.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ResolveKey {
+    tenant_id: Uuid,
+    subject_id: Uuid,
+    alias: String,
+    method: String,
+    path: String,
+}
+
+struct CachedTarget {
+    upstream: Upstream,
+    route: Route,
+    expires_at: Instant,
+}
+
+/// A [`ServiceGatewayClientV1`] decorator that memoizes
+/// [`resolve_proxy_target`](ServiceGatewayClientV1::resolve_proxy_target)
+/// results for a fixed TTL.
+///
+/// Useful on hot paths that repeatedly resolve the same upstream alias.
+/// Entries are keyed by tenant, subject, alias, method and path; errors are
+/// never cached. Every successful create/update/delete of an upstream or
+/// route made **through this wrapper** clears the whole cache, since a change
+/// anywhere in the tenant hierarchy can alter merged resolutions. Changes made
+/// by other clients become visible once the TTL expires.
+///
+/// All other calls are forwarded unchanged.
+pub struct CachingResolver {
+    inner: Arc<dyn ServiceGatewayClientV1>,
+    ttl: Duration,
+    max_entries: usize,
+    entries: Mutex<HashMap<ResolveKey, CachedTarget>>,
+}
+
+impl CachingResolver {
+    /// Wrap `inner`, caching resolutions for `ttl`.
+    pub fn new(inner: Arc<dyn ServiceGatewayClientV1>, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            max_entries: DEFAULT_MAX_ENTRIES,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Cap the number of cached resolutions (default 1024). When the cap is
+    /// reached, expired entries are evicted first; if none are, the cache is
+    /// cleared.
+    #[must_use]
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// Drop every cached resolution.
+    pub fn invalidate_all(&self) {
+        self.entries.lock().clear();
+    }
+
+    fn lookup(&self, key: &ResolveKey) -> Option<(Upstream, Route)> {
+        let mut entries = self.entries.lock();
+        let entry = entries.get(key)?;
+        if entry.expires_at <= Instant::now() {
+            entries.remove(key);
+            return None;
+        }
+        Some((entry.upstream.clone(), entry.route.clone()))
+    }
+
+    fn store(&self, key: ResolveKey, upstream: &Upstream, route: &Route) {
+        if self.max_entries == 0 {
+            return;
+        }
+        let now = Instant::now();
+        let mut entries = self.entries.lock();
+        if entries.len() >= self.max_entries && !entries.contains_key(&key) {
+            entries.retain(|_, e| e.expires_at > now);
+            if entries.len() >= self.max_entries {
+                entries.clear();
+            }
+        }
+        entries.insert(
+            key,
+            CachedTarget {
+                upstream: upstream.clone(),
+                route: route.clone(),
+                expires_at: now + self.ttl,
+            },
+        );
+    }
+
+    /// Clear the cache if a mutation succeeded, passing the result through.
+    fn invalidate_on_ok<T>(
+        &self,
+        result: Result<T, ServiceGatewayError>,
+    ) -> Result<T, ServiceGatewayError> {
+        if result.is_ok() {
+            self.invalidate_all();
+        }
+        result
+    }
+}
+
+#[async_trait]
+impl ServiceGatewayClientV1 for CachingResolver {
+    async fn create_upstream(
+        &self,
+        ctx: SecurityContext,
+        req: CreateUpstreamRequest,
+    ) -> Result<Upstream, ServiceGatewayError> {
+        self.invalidate_on_ok(self.inner.create_upstream(ctx, req).await)
+    }
+
+    async fn get_upstream(
+        &self,
+        ctx: SecurityContext,
+        id: Uuid,
+    ) -> Result<Upstream, ServiceGatewayError> {
+        self.inner.get_upstream(ctx, id).await
+    }
+
+    async fn list_upstreams(
+        &self,
+        ctx: SecurityContext,
+        query: &ListQuery,
+    ) -> Result<Vec<Upstream>, ServiceGatewayError> {
+        self.inner.list_upstreams(ctx, query).await
+    }
+
+    async fn update_upstream(
+        &self,
+        ctx: SecurityContext,
+        id: Uuid,
+        req: UpdateUpstreamRequest,
+    ) -> Result<Upstream, ServiceGatewayError> {
+        self.invalidate_on_ok(self.inner.update_upstream(ctx, id, req).await)
+    }
+
+    async fn delete_upstream(
+        &self,
+        ctx: SecurityContext,
+        id: Uuid,
+    ) -> Result<(), ServiceGatewayError> {
+        self.invalidate_on_ok(self.inner.delete_upstream(ctx, id).await)
+    }
+
+    async fn create_route(
+        &self,
+        ctx: SecurityContext,
+        req: CreateRouteRequest,
+    ) -> Result<Route, ServiceGatewayError> {
+        self.invalidate_on_ok(self.inner.create_route(ctx, req).await)
+    }
+
+    async fn get_route(
+        &self,
+        ctx: SecurityContext,
+        id: Uuid,
+    ) -> Result<Route, ServiceGatewayError> {
+        self.inner.get_route(ctx, id).await
+    }
+
+    async fn list_routes(
+        &self,
+        ctx: SecurityContext,
+        upstream_id: Option<Uuid>,
+        query: &ListQuery,
+    ) -> Result<Vec<Route>, ServiceGatewayError> {
+        self.inner.list_routes(ctx, upstream_id, query).await
+    }
+
+    async fn update_route(
+        &self,
+        ctx: SecurityContext,
+        id: Uuid,
+        req: UpdateRouteRequest,
+    ) -> Result<Route, ServiceGatewayError> {
+        self.invalidate_on_ok(self.inner.update_route(ctx, id, req).await)
+    }
+
+    async fn delete_route(
+        &self,
+        ctx: SecurityContext,
+        id: Uuid,
+    ) -> Result<(), ServiceGatewayError> {
+        self.invalidate_on_ok(self.inner.delete_route(ctx, id).await)
+    }
+
+    async fn resolve_proxy_target(
+        &self,
+        ctx: SecurityContext,
+        alias: &str,
+        method: &str,
+        path: &str,
+    ) -> Result<(Upstream, Route), ServiceGatewayError> {
+        let key = ResolveKey {
+            tenant_id: ctx.subject_tenant_id(),
+            subject_id: ctx.subject_id(),
+            alias: alias.to_owned(),
+            method: method.to_owned(),
+            path: path.to_owned(),
+        };
+        if let Some(hit) = self.lookup(&key) {
+            return Ok(hit);
+        }
+
+        let (upstream, route) = self
+            .inner
+            .resolve_proxy_target(ctx, alias, method, path)
+            .await?;
+        self.store(key, &upstream, &route);
+        Ok((upstream, route))
+    }
+
+    async fn proxy_request(
+        &self,
+        ctx: SecurityContext,
+        req: http::Request<Body>,
+    ) -> Result<http::Response<Body>, ServiceGatewayError> {
+        self.inner.proxy_request(ctx, req).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::{HttpMatch, HttpMethod, MatchRules, PathSuffixMode, Server};
+
+    /// Gateway stub that answers resolutions with the caller's tenant and
+    /// counts how often it is asked.
+    #[derive(Default)]
+    struct CountingGateway {
+        resolves: AtomicUsize,
+    }
+
+    impl CountingGateway {
+        fn resolves(&self) -> usize {
+            self.resolves.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl ServiceGatewayClientV1 for CountingGateway {
+        async fn create_upstream(
+            &self,
+            _: SecurityContext,
+            _: CreateUpstreamRequest,
+        ) -> Result<Upstream, ServiceGatewayError> {
+            unimplemented!()
+        }
+
+        async fn get_upstream(
+            &self,
+            _: SecurityContext,
+            _: Uuid,
+        ) -> Result<Upstream, ServiceGatewayError> {
+            unimplemented!()
+        }
+
+        async fn list_upstreams(
+            &self,
+            _: SecurityContext,
+            _: &ListQuery,
+        ) -> Result<Vec<Upstream>, ServiceGatewayError> {
+            unimplemented!()
+        }
+
+        async fn update_upstream(
+            &self,
+            _: SecurityContext,
+            _: Uuid,
+            _: UpdateUpstreamRequest,
+        ) -> Result<Upstream, ServiceGatewayError> {
+            unimplemented!()
+        }
+
+        async fn delete_upstream(
+            &self,
+            _: SecurityContext,
+            _: Uuid,
+        ) -> Result<(), ServiceGatewayError> {
+            Ok(())
+        }
+
+        async fn create_route(
+            &self,
+            _: SecurityContext,
+            _: CreateRouteRequest,
+        ) -> Result<Route, ServiceGatewayError> {
+            unimplemented!()
+        }
+
+        async fn get_route(
+            &self,
+            _: SecurityContext,
+            _: Uuid,
+        ) -> Result<Route, ServiceGatewayError> {
+            unimplemented!()
+        }
+
+        async fn list_routes(
+            &self,
+            _: SecurityContext,
+            _: Option<Uuid>,
+            _: &ListQuery,
+        ) -> Result<Vec<Route>, ServiceGatewayError> {
+            unimplemented!()
+        }
+
+        async fn update_route(
+            &self,
+            _: SecurityContext,
+            _: Uuid,
+            _: UpdateRouteRequest,
+        ) -> Result<Route, ServiceGatewayError> {
+            unimplemented!()
+        }
+
+        async fn delete_route(
+            &self,
+            _: SecurityContext,
+            _: Uuid,
+        ) -> Result<(), ServiceGatewayError> {
+            unimplemented!()
+        }
+
+        async fn resolve_proxy_target(
+            &self,
+            ctx: SecurityContext,
+            alias: &str,
+            _: &str,
+            _: &str,
+        ) -> Result<(Upstream, Route), ServiceGatewayError> {
+            self.resolves.fetch_add(1, Ordering::SeqCst);
+            if alias == "missing" {
+                return Err(ServiceGatewayError::RouteNotFound {
+                    instance: alias.to_owned(),
+                });
+            }
+            Ok(target(ctx.subject_tenant_id(), alias))
+        }
+
+        async fn proxy_request(
+            &self,
+            _: SecurityContext,
+            _: http::Request<Body>,
+        ) -> Result<http::Response<Body>, ServiceGatewayError> {
+            unimplemented!()
+        }
+    }
+
+    fn target(tenant_id: Uuid, alias: &str) -> (Upstream, Route) {
+        let upstream = Upstream {
+            id: Uuid::new_v4(),
+            tenant_id,
+            alias: alias.to_owned(),
+            server: Server { endpoints: vec![] },
+            protocol: "http".into(),
+            enabled: true,
+            auth: None,
+            headers: None,
+            plugins: None,
+            rate_limit: None,
+            cors: None,
+            tags: vec![],
+        };
+        let route = Route {
+            id: Uuid::new_v4(),
+            tenant_id,
+            upstream_id: upstream.id,
+            match_rules: MatchRules {
+                http: Some(HttpMatch {
+                    methods: vec![HttpMethod::Get],
+                    path: "/".into(),
+                    query_allowlist: vec![],
+                    path_suffix_mode: PathSuffixMode::Append,
+                }),
+                grpc: None,
+            },
+            plugins: None,
+            rate_limit: None,
+            cors: None,
+            tags: vec![],
+            priority: 0,
+            enabled: true,
+        };
+        (upstream, route)
+    }
+
+    fn ctx_for(tenant_id: Uuid) -> SecurityContext {
+        SecurityContext::builder()
+            .subject_id(Uuid::new_v4())
+            .subject_tenant_id(tenant_id)
+            .build()
+            .unwrap()
+    }
+
+    fn resolver(ttl: Duration) -> (Arc<CountingGateway>, CachingResolver) {
+        let inner = Arc::new(CountingGateway::default());
+        let resolver = CachingResolver::new(inner.clone(), ttl);
+        (inner, resolver)
+    }
+
+    #[tokio::test]
+    async fn repeated_resolution_hits_cache() {
+        let (inner, resolver) = resolver(Duration::from_secs(60));
+        let ctx = ctx_for(Uuid::new_v4());
+
+        let (first, _) = resolver
+            .resolve_proxy_target(ctx.clone(), "openai", "POST", "/v1/chat")
+            .await
+            .unwrap();
+        let (second, _) = resolver
+            .resolve_proxy_target(ctx, "openai", "POST", "/v1/chat")
+            .await
+            .unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(inner.resolves(), 1);
+    }
+
+    #[tokio::test]
+    async fn cache_is_scoped_per_tenant() {
+        let (inner, resolver) = resolver(Duration::from_secs(60));
+        let tenant_a = Uuid::new_v4();
+        let tenant_b = Uuid::new_v4();
+
+        let (a, _) = resolver
+            .resolve_proxy_target(ctx_for(tenant_a), "openai", "GET", "/")
+            .await
+            .unwrap();
+        let (b, _) = resolver
+            .resolve_proxy_target(ctx_for(tenant_b), "openai", "GET", "/")
+            .await
+            .unwrap();
+
+        assert_eq!(a.tenant_id, tenant_a);
+        assert_eq!(b.tenant_id, tenant_b);
+        assert_eq!(inner.resolves(), 2);
+    }
+
+    #[tokio::test]
+    async fn entries_expire_after_ttl() {
+        let (inner, resolver) = resolver(Duration::from_millis(20));
+        let ctx = ctx_for(Uuid::new_v4());
+
+        resolver
+            .resolve_proxy_target(ctx.clone(), "openai", "GET", "/")
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        resolver
+            .resolve_proxy_target(ctx, "openai", "GET", "/")
+            .await
+            .unwrap();
+
+        assert_eq!(inner.resolves(), 2);
+    }
+
+    #[tokio::test]
+    async fn mutation_through_wrapper_invalidates() {
+        let (inner, resolver) = resolver(Duration::from_secs(60));
+        let ctx = ctx_for(Uuid::new_v4());
+
+        let (upstream, _) = resolver
+            .resolve_proxy_target(ctx.clone(), "openai", "GET", "/")
+            .await
+            .unwrap();
+        resolver
+            .delete_upstream(ctx.clone(), upstream.id)
+            .await
+            .unwrap();
+        resolver
+            .resolve_proxy_target(ctx, "openai", "GET", "/")
+            .await
+            .unwrap();
+
+        assert_eq!(inner.resolves(), 2);
+    }
+
+    #[tokio::test]
+    async fn errors_are_not_cached() {
+        let (inner, resolver) = resolver(Duration::from_secs(60));
+        let ctx = ctx_for(Uuid::new_v4());
+
+        for _ in 0..2 {
+            let err = resolver
+                .resolve_proxy_target(ctx.clone(), "missing", "GET", "/")
+                .await
+                .unwrap_err();
+            assert!(matches!(err, ServiceGatewayError::RouteNotFound { .. }));
+        }
+        assert_eq!(inner.resolves(), 2);
+    }
+
+    #[tokio::test]
+    async fn max_entries_bounds_cache_size() {
+        let (_, resolver) = resolver(Duration::from_secs(60));
+        let resolver = resolver.with_max_entries(2);
+        let ctx = ctx_for(Uuid::new_v4());
+
+        for path in ["/a", "/b", "/c"] {
+            resolver
+                .resolve_proxy_target(ctx.clone(), "openai", "GET", path)
+                .await
+                .unwrap();
+        }
+        assert!(resolver.entries.lock().len() <= 2);
+    }
+}
@@ -0,0 +1,267 @@
+//! Transparent `Content-Encoding` decoding for SSE bodies.
+//!
+//! Some upstreams compress `text/event-stream` responses. The body is
+//! inflated chunk by chunk as it arrives, so events are still delivered as
+//! soon as their compressed bytes are flushed by the upstream.
+//!
+//! The output of each compressed chunk is capped, so a small
+//! "decompression bomb" cannot inflate to gigabytes before the SSE decoder's
+//! buffer limit gets a chance to see it.
+
+use std::io::Write;
+
+use bytes::Bytes;
+use flate2::write::{GzDecoder, ZlibDecoder};
+use futures_util::{StreamExt, stream};
+use http::HeaderMap;
+
+use crate::body::BodyStream;
+
+/// Most bytes a single compressed chunk may inflate to.
+const MAX_INFLATED_CHUNK_SIZE: usize = 16 * 1024 * 1024;
+
+/// Streaming decoder for a supported `Content-Encoding`.
+enum Decoder {
+    Gzip(GzDecoder<Vec<u8>>),
+    /// HTTP `deflate` is the zlib format (RFC 9110 §8.4.1.2).
+    Deflate(ZlibDecoder<Vec<u8>>),
+}
+
+impl Decoder {
+    /// The decoder for the response's `Content-Encoding`, or `None` when it
+    /// is absent or not one we decode.
+    fn for_headers(headers: &HeaderMap) -> Option<Self> {
+        let encoding = headers
+            .get(http::header::CONTENT_ENCODING)?
+            .to_str()
+            .ok()?
+            .trim();
+        if encoding.eq_ignore_ascii_case("gzip") || encoding.eq_ignore_ascii_case("x-gzip") {
+            Some(Self::Gzip(GzDecoder::new(Vec::new())))
+        } else if encoding.eq_ignore_ascii_case("deflate") {
+            Some(Self::Deflate(ZlibDecoder::new(Vec::new())))
+        } else {
+            None
+        }
+    }
+
+    /// Feed one compressed chunk and return whatever it inflated to.
+    ///
+    /// The chunk is written piecewise — each `write` inflates at most the
+    /// decoder's internal buffer — so the output can be checked against
+    /// `limit` before it grows much past it.
+    fn push(&mut self, mut chunk: &[u8], limit: usize) -> std::io::Result<Bytes> {
+        while !chunk.is_empty() {
+            let written = match self {
+                Self::Gzip(d) => d.write(chunk)?,
+                Self::Deflate(d) => d.write(chunk)?,
+            };
+            if written == 0 {
+                return Err(std::io::ErrorKind::WriteZero.into());
+            }
+            chunk = &chunk[written..];
+            self.check_size(limit)?;
+        }
+        match self {
+            Self::Gzip(d) => d.flush()?,
+            Self::Deflate(d) => d.flush()?,
+        }
+        self.check_size(limit)?;
+        Ok(self.take_output())
+    }
+
+    /// Finish the stream, failing if it was truncated.
+    fn finish(&mut self, limit: usize) -> std::io::Result<Bytes> {
+        match self {
+            Self::Gzip(d) => d.try_finish()?,
+            Self::Deflate(d) => d.try_finish()?,
+        }
+        self.check_size(limit)?;
+        Ok(self.take_output())
+    }
+
+    /// Fail if the output inflated so far exceeds `limit`.
+    fn check_size(&self, limit: usize) -> std::io::Result<()> {
+        let len = match self {
+            Self::Gzip(d) => d.get_ref().len(),
+            Self::Deflate(d) => d.get_ref().len(),
+        };
+        if len > limit {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("compressed SSE chunk inflates past the {limit}-byte limit"),
+            ));
+        }
+        Ok(())
+    }
+
+    fn take_output(&mut self) -> Bytes {
+        match self {
+            Self::Gzip(d) => Bytes::from(std::mem::take(d.get_mut())),
+            Self::Deflate(d) => Bytes::from(std::mem::take(d.get_mut())),
+        }
+    }
+}
+
+/// Wrap `body` in a decoder for the `gzip` or `deflate` `Content-Encoding`
+/// in `headers`; other bodies are returned unchanged.
+///
+/// A corrupt or truncated body ends the stream with an I/O error, as does a
+/// chunk that inflates to more than 16 MiB.
+pub(crate) fn decode_body(headers: &HeaderMap, body: BodyStream) -> BodyStream {
+    decode_body_with_limit(headers, body, MAX_INFLATED_CHUNK_SIZE)
+}
+
+fn decode_body_with_limit(headers: &HeaderMap, body: BodyStream, limit: usize) -> BodyStream {
+    let Some(decoder) = Decoder::for_headers(headers) else {
+        return body;
+    };
+    let inflated = stream::unfold(Some((body, decoder)), move |state| async move {
+        let (mut body, mut decoder) = state?;
+        loop {
+            let (result, more) = match body.next().await {
+                Some(Ok(chunk)) => (decoder.push(&chunk, limit), true),
+                Some(Err(e)) => return Some((Err(e), None)),
+                None => (decoder.finish(limit), false),
+            };
+            match result {
+                // Compressed input that completed no output yet.
+                Ok(bytes) if bytes.is_empty() && more => {}
+                Ok(bytes) if bytes.is_empty() => return None,
+                Ok(bytes) => return Some((Ok(bytes), more.then_some((body, decoder)))),
+                Err(e) => return Some((Err(e.into()), None)),
+            }
+        }
+    });
+    Box::pin(inflated)
+}
+
+#[cfg(test)]
+mod tests {
+    use flate2::Compression;
+    use flate2::write::{GzEncoder, ZlibEncoder};
+
+    use super::*;
+    use crate::body::{Body, BoxError};
+    use crate::sse::{ServerEvent, ServerEventsResponse, ServerEventsStream};
+
+    const EVENTS: &str = "id: 1\ndata: hello\n\nevent: done\ndata: world\n\n";
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut enc = GzEncoder::new(Vec::new(), Compression::default());
+        enc.write_all(data).unwrap();
+        enc.finish().unwrap()
+    }
+
+    fn zlib(data: &[u8]) -> Vec<u8> {
+        let mut enc = ZlibEncoder::new(Vec::new(), Compression::default());
+        enc.write_all(data).unwrap();
+        enc.finish().unwrap()
+    }
+
+    /// An SSE response whose compressed body arrives in `chunk`-byte pieces.
+    fn response(encoding: &str, body: &[u8], chunk: usize) -> http::Response<Body> {
+        let chunks: Vec<Result<Bytes, BoxError>> = body
+            .chunks(chunk)
+            .map(|c| Ok(Bytes::copy_from_slice(c)))
+            .collect();
+        let stream: BodyStream = Box::pin(stream::iter(chunks));
+        http::Response::builder()
+            .header(http::header::CONTENT_TYPE, "text/event-stream")
+            .header(http::header::CONTENT_ENCODING, encoding)
+            .body(Body::Stream(stream))
+            .unwrap()
+    }
+
+    async fn events(resp: http::Response<Body>) -> Vec<ServerEvent> {
+        let ServerEventsResponse::Events(events) =
+            ServerEventsStream::from_response::<ServerEvent>(resp)
+        else {
+            panic!("expected an SSE response");
+        };
+        events.map(Result::unwrap).collect().await
+    }
+
+    #[tokio::test]
+    async fn gzip_body_is_decoded_before_parsing() {
+        let parsed = events(response("gzip", &gzip(EVENTS.as_bytes()), 7)).await;
+        assert_eq!(
+            parsed,
+            [
+                ServerEvent::builder().id("1").data("hello").build(),
+                ServerEvent::builder().event("done").data("world").build(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn deflate_body_is_decoded_before_parsing() {
+        let parsed = events(response("Deflate", &zlib(EVENTS.as_bytes()), 5)).await;
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[1].data, "world");
+    }
+
+    #[tokio::test]
+    async fn identity_body_passes_through() {
+        let parsed = events(response("identity", EVENTS.as_bytes(), 4)).await;
+        assert_eq!(parsed.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn truncated_gzip_body_ends_with_error() {
+        let compressed = gzip(EVENTS.as_bytes());
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            http::header::CONTENT_ENCODING,
+            http::HeaderValue::from_static("gzip"),
+        );
+        let chunk: Result<Bytes, BoxError> =
+            Ok(Bytes::copy_from_slice(&compressed[..compressed.len() - 4]));
+        let body: BodyStream = Box::pin(stream::iter([chunk]));
+
+        let items: Vec<_> = decode_body(&headers, body).collect().await;
+        assert!(items.last().unwrap().is_err(), "got: {items:?}");
+    }
+
+    #[tokio::test]
+    async fn gzip_bomb_chunk_fails_before_inflating_fully() {
+        // 8 MiB of zeros compresses to ~8 KiB.
+        let bomb = gzip(&vec![0; 8 * 1024 * 1024]);
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            http::header::CONTENT_ENCODING,
+            http::HeaderValue::from_static("gzip"),
+        );
+        let chunk: Result<Bytes, BoxError> = Ok(Bytes::from(bomb));
+        let body: BodyStream = Box::pin(stream::iter([chunk]));
+
+        let items: Vec<_> = decode_body_with_limit(&headers, body, 1024 * 1024)
+            .collect()
+            .await;
+        assert_eq!(items.len(), 1, "stream must stop after the error");
+        let err = items[0].as_ref().unwrap_err();
+        assert!(err.to_string().contains("1048576-byte limit"), "got: {err}");
+    }
+
+    #[tokio::test]
+    async fn output_under_limit_is_delivered_per_chunk() {
+        let payload = "data: x\n\n".repeat(1000);
+        let compressed = gzip(payload.as_bytes());
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            http::header::CONTENT_ENCODING,
+            http::HeaderValue::from_static("gzip"),
+        );
+        let chunks: Vec<Result<Bytes, BoxError>> = compressed
+            .chunks(16)
+            .map(|c| Ok(Bytes::copy_from_slice(c)))
+            .collect();
+        let body: BodyStream = Box::pin(stream::iter(chunks));
+
+        let inflated: Vec<u8> = decode_body_with_limit(&headers, body, payload.len())
+            .map(|chunk| chunk.unwrap().to_vec())
+            .concat()
+            .await;
+        assert_eq!(inflated, payload.as_bytes());
+    }
+}
@@ -1,14 +1,41 @@
 use http::HeaderMap;
 
+use crate::content_type::StreamingContentTypes;
+
 /// Check if the response headers indicate an SSE stream.
 ///
-/// Returns `true` when `Content-Type` starts with `text/event-stream`.
+/// Returns `true` when `Content-Type` starts with `text/event-stream`, per
+/// the default [`StreamingContentTypes`]. Case and surrounding whitespace are
+/// ignored, and parameters such as `; charset=utf-8` are accepted.
 #[must_use]
 pub fn is_server_events_response(headers: &HeaderMap) -> bool {
-    headers
-        .get(http::header::CONTENT_TYPE)
-        .and_then(|v| v.to_str().ok())
-        .is_some_and(|ct| ct.starts_with("text/event-stream"))
+    StreamingContentTypes::default_ref().is_server_events(headers)
+}
+
+/// Check whether a body prefix looks like an SSE stream.
+///
+/// Returns `true` when the first line (after an optional UTF-8 BOM) starts
+/// with a `data:`, `event:` or `id:` field, or a `:` comment. Intended for
+/// sniffing responses whose `Content-Type` is missing or generic.
+#[must_use]
+pub fn looks_like_server_events(prefix: &[u8]) -> bool {
+    let prefix = prefix.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(prefix);
+    [b"data:".as_slice(), b"event:", b"id:", b":"]
+        .iter()
+        .any(|field| prefix.starts_with(field))
+}
+
+/// Check whether the `Content-Type` is absent or generic enough that the
+/// body may be sniffed for SSE (`application/octet-stream`).
+pub(crate) fn is_sniffable_content_type(headers: &HeaderMap) -> bool {
+    let Some(value) = headers.get(http::header::CONTENT_TYPE) else {
+        return true;
+    };
+    value.to_str().is_ok_and(|ct| {
+        ct.split(';')
+            .next()
+            .is_some_and(|mime| mime.trim().eq_ignore_ascii_case("application/octet-stream"))
+    })
 }
 
 #[cfg(test)]
@@ -36,6 +63,19 @@ mod tests {
         assert!(is_server_events_response(&headers));
     }
 
+    #[test]
+    fn detects_event_stream_ignoring_case_and_whitespace() {
+        for value in [
+            "Text/Event-Stream",
+            " text/event-stream ",
+            "TEXT/EVENT-STREAM; charset=utf-8",
+        ] {
+            let mut headers = HeaderMap::new();
+            headers.insert(http::header::CONTENT_TYPE, HeaderValue::from_static(value));
+            assert!(is_server_events_response(&headers), "{value:?}");
+        }
+    }
+
     #[test]
     fn rejects_json() {
         let mut headers = HeaderMap::new();
@@ -51,4 +91,39 @@ mod tests {
         let headers = HeaderMap::new();
         assert!(!is_server_events_response(&headers));
     }
+
+    #[test]
+    fn sniffs_sse_field_prefixes() {
+        assert!(looks_like_server_events(b"data: hello\n\n"));
+        assert!(looks_like_server_events(b"event: update\n"));
+        assert!(looks_like_server_events(b"id: 1\n"));
+        assert!(looks_like_server_events(b": keep-alive\n"));
+        assert!(looks_like_server_events(b"\xEF\xBB\xBFdata: x\n"));
+    }
+
+    #[test]
+    fn sniff_rejects_non_sse_bodies() {
+        assert!(!looks_like_server_events(b"{\"data\": 1}"));
+        assert!(!looks_like_server_events(b"<html>"));
+        assert!(!looks_like_server_events(b" data: x"));
+        assert!(!looks_like_server_events(b""));
+    }
+
+    #[test]
+    fn sniffable_content_types() {
+        assert!(is_sniffable_content_type(&HeaderMap::new()));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            http::header::CONTENT_TYPE,
+            HeaderValue::from_static("Application/Octet-Stream; foo=bar"),
+        );
+        assert!(is_sniffable_content_type(&headers));
+
+        headers.insert(
+            http::header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        assert!(!is_sniffable_content_type(&headers));
+    }
 }
@@ -1,3 +1,16 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use bytes::Bytes;
+
+use crate::error::StreamingError;
+use crate::sse::parse::normalize_line_endings;
+
+/// Field names defined by the spec; they cannot be used as `extra` names.
+const RESERVED_FIELDS: [&str; 4] = ["data", "id", "event", "retry"];
+
 /// A parsed Server-Sent Event.
 ///
 /// Follows the W3C EventSource specification fields.
@@ -11,17 +24,481 @@ pub struct ServerEvent {
     pub data: String,
     /// The `retry` field in milliseconds.
     pub retry: Option<u64>,
+    /// Fields outside the spec (e.g. `x-usage`), keyed by field name.
+    ///
+    /// Empty unless capture is enabled with
+    /// [`SseDecoder::with_extra_fields`](crate::sse::SseDecoder::with_extra_fields);
+    /// by default unknown fields are ignored, per spec. A field repeated
+    /// within one event keeps all values, joined with newlines like `data`.
+    ///
+    /// When writing, names must be non-empty, free of `:` and line breaks,
+    /// and not one of the spec fields; values must be single-line. See
+    /// [`to_wire`](Self::to_wire).
+    pub extra: BTreeMap<String, String>,
 }
 
 impl ServerEvent {
+    /// A data-only event, the common case when emitting events.
+    #[must_use]
+    pub fn data(data: impl Into<String>) -> Self {
+        Self {
+            data: data.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Start building an event field by field.
+    ///
+    /// ```
+    /// use oagw_sdk::sse::ServerEvent;
+    ///
+    /// let event = ServerEvent::builder().id("7").event("delta").data("hi").build();
+    /// assert_eq!(event.id.as_deref(), Some("7"));
+    /// ```
+    #[must_use]
+    pub fn builder() -> ServerEventBuilder {
+        ServerEventBuilder::default()
+    }
+
+    /// Field marking `data` as base64-encoded binary, see
+    /// [`with_binary_data`](Self::with_binary_data).
+    pub const DATA_ENCODING_FIELD: &'static str = "data-encoding";
+
+    /// Set `data` to the base64 encoding of `bytes` and mark the event with
+    /// a `data-encoding: base64` field.
+    ///
+    /// Lets binary payloads cross the text-only SSE wire format, including
+    /// intermediaries that assume UTF-8. Receivers parse with
+    /// [`SseDecoder::with_binary_data`](crate::sse::SseDecoder::with_binary_data)
+    /// (or [`with_extra_fields`](crate::sse::SseDecoder::with_extra_fields))
+    /// to keep the marker, then call [`binary_data`](Self::binary_data).
+    #[must_use]
+    pub fn with_binary_data(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.data = BASE64.encode(bytes);
+        self.extra
+            .insert(Self::DATA_ENCODING_FIELD.to_owned(), "base64".to_owned());
+        self
+    }
+
+    /// Whether `data` carries base64-encoded binary.
+    #[must_use]
+    pub fn is_binary(&self) -> bool {
+        self.extra
+            .get(Self::DATA_ENCODING_FIELD)
+            .is_some_and(|v| v == "base64")
+    }
+
+    /// The payload as bytes: base64-decoded when the event
+    /// [is binary](Self::is_binary), otherwise the UTF-8 bytes of `data`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::ServerEventsParse`] if a binary event's
+    /// `data` is not valid base64.
+    pub fn binary_data(&self) -> Result<Bytes, StreamingError> {
+        if !self.is_binary() {
+            return Ok(Bytes::copy_from_slice(self.data.as_bytes()));
+        }
+        BASE64
+            .decode(&self.data)
+            .map(Bytes::from)
+            .map_err(|e| StreamingError::ServerEventsParse {
+                detail: format!("invalid base64 data: {e}"),
+            })
+    }
+
+    /// Serialize the event in SSE wire format, terminated by a blank line.
+    ///
+    /// Fields are written as `id`, `event`, `retry`, the
+    /// [`extra`](Self::extra) fields, then `data`. Each line of multi-line
+    /// `data` gets its own field line; CRLF and bare CR count as line breaks.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::ServerEventsSerialize`] if a field would
+    /// corrupt the stream or inject fields: `id`, `event` or an `extra`
+    /// value containing a line break, or an `extra` name that is empty,
+    /// contains `:` or a line break, or is `data`, `id`, `event` or `retry`.
+    pub fn to_wire(&self) -> Result<Bytes, StreamingError> {
+        self.validate()?;
+        let mut buf = String::new();
+        if let Some(ref id) = self.id {
+            buf.push_str("id: ");
+            buf.push_str(id);
+            buf.push('\n');
+        }
+        if let Some(ref event_type) = self.event {
+            buf.push_str("event: ");
+            buf.push_str(event_type);
+            buf.push('\n');
+        }
+        if let Some(retry) = self.retry {
+            buf.push_str("retry: ");
+            buf.push_str(&retry.to_string());
+            buf.push('\n');
+        }
+        for (field, value) in &self.extra {
+            buf.push_str(field);
+            buf.push_str(": ");
+            buf.push_str(value);
+            buf.push('\n');
+        }
+        // Each line of data gets its own "data:" prefix. Data may be set
+        // programmatically, so CRLF and bare CR are treated as line breaks too —
+        // otherwise a stray `\r` would end the field early on the client side.
+        for line in normalize_line_endings(&self.data).split('\n') {
+            buf.push_str("data: ");
+            buf.push_str(line);
+            buf.push('\n');
+        }
+        buf.push('\n'); // Blank line terminates the event.
+        Ok(Bytes::from(buf))
+    }
+
+    /// Check that the event can be written without corrupting the stream.
+    ///
+    /// `id`, `event` and `extra` values are single-line fields: a `\n` or
+    /// `\r` would end the field early and let the rest of the value be read
+    /// as further fields. An `extra` name is written verbatim before `: `,
+    /// so it must not contain a line break or `:` either, must not be empty
+    /// (the line would be a comment) and must not shadow a spec field. Such
+    /// events are rejected rather than silently altered.
+    pub(crate) fn validate(&self) -> Result<(), StreamingError> {
+        let single_line = [("id", self.id.as_deref()), ("event", self.event.as_deref())]
+            .into_iter()
+            .chain(
+                self.extra
+                    .iter()
+                    .map(|(k, v)| (k.as_str(), Some(v.as_str()))),
+            );
+        for (field, value) in single_line {
+            if let Some(value) = value
+                && value.contains(['\n', '\r'])
+            {
+                return Err(StreamingError::ServerEventsSerialize {
+                    detail: format!("`{field}` must not contain line breaks: {value:?}"),
+                });
+            }
+        }
+        for field in self.extra.keys() {
+            if field.is_empty() || field.contains(['\n', '\r', ':']) {
+                return Err(StreamingError::ServerEventsSerialize {
+                    detail: format!("invalid extra field name: {field:?}"),
+                });
+            }
+            if RESERVED_FIELDS.contains(&field.as_str()) {
+                return Err(StreamingError::ServerEventsSerialize {
+                    detail: format!("extra field name `{field}` is reserved"),
+                });
+            }
+        }
+        Ok(())
+    }
+
     /// Returns true if this event has no meaningful content.
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.data.is_empty() && self.id.is_none() && self.event.is_none() && self.retry.is_none()
+        self.data.is_empty()
+            && self.id.is_none()
+            && self.event.is_none()
+            && self.retry.is_none()
+            && self.extra.is_empty()
+    }
+
+    /// `data` with control characters other than `\n` and `\t` (e.g. NUL)
+    /// replaced by U+FFFD, for logging and consumers that reject them.
+    ///
+    /// `data` itself keeps the bytes the server sent, as the spec requires;
+    /// [`json`](Self::json) and [`json_value`](Self::json_value) parse the
+    /// raw field. Borrows when there is nothing to replace.
+    #[must_use]
+    pub fn data_lossy(&self) -> Cow<'_, str> {
+        let is_unsafe = |c: char| c.is_control() && c != '\n' && c != '\t';
+        if self.data.contains(is_unsafe) {
+            Cow::Owned(self.data.replace(is_unsafe, "\u{FFFD}"))
+        } else {
+            Cow::Borrowed(&self.data)
+        }
     }
 
     /// Deserialize the `data` field as JSON into type `T`.
     pub fn json<T: serde::de::DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
         serde_json::from_str(&self.data)
     }
+
+    /// Parse the `data` field as an untyped JSON value.
+    ///
+    /// Handy for dispatch code that inspects a field (e.g. `type`) before
+    /// choosing a concrete type for [`json`](Self::json).
+    pub fn json_value(&self) -> Result<serde_json::Value, serde_json::Error> {
+        self.json()
+    }
+}
+
+/// Builder for [`ServerEvent`], created by [`ServerEvent::builder`].
+#[derive(Debug, Clone, Default)]
+pub struct ServerEventBuilder {
+    event: ServerEvent,
+}
+
+impl ServerEventBuilder {
+    /// Set the `id` field.
+    #[must_use]
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.event.id = Some(id.into());
+        self
+    }
+
+    /// Set the `event` field (the event type).
+    #[must_use]
+    pub fn event(mut self, event: impl Into<String>) -> Self {
+        self.event.event = Some(event.into());
+        self
+    }
+
+    /// Set the `data` field; embedded newlines become separate `data:` lines
+    /// on the wire.
+    #[must_use]
+    pub fn data(mut self, data: impl Into<String>) -> Self {
+        self.event.data = data.into();
+        self
+    }
+
+    /// Set the `retry` field, in milliseconds.
+    #[must_use]
+    pub fn retry(mut self, retry_ms: u64) -> Self {
+        self.event.retry = Some(retry_ms);
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> ServerEvent {
+        self.event
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_data_only() {
+        let event = ServerEvent {
+            data: "hello".into(),
+            ..Default::default()
+        };
+        let bytes = event.to_wire().unwrap();
+        assert_eq!(bytes.as_ref(), b"data: hello\n\n");
+    }
+
+    #[test]
+    fn serialize_all_fields() {
+        let event = ServerEvent {
+            id: Some("42".into()),
+            event: Some("update".into()),
+            data: "payload".into(),
+            retry: Some(3000),
+            ..Default::default()
+        };
+        let bytes = event.to_wire().unwrap();
+        let expected = "id: 42\nevent: update\nretry: 3000\ndata: payload\n\n";
+        assert_eq!(std::str::from_utf8(&bytes).unwrap(), expected);
+    }
+
+    #[test]
+    fn serialize_extra_fields() {
+        let event = ServerEvent {
+            data: "ok".into(),
+            extra: [("x-usage".to_owned(), "42".to_owned())].into(),
+            ..Default::default()
+        };
+        let bytes = event.to_wire().unwrap();
+        assert_eq!(
+            std::str::from_utf8(&bytes).unwrap(),
+            "x-usage: 42\ndata: ok\n\n"
+        );
+    }
+
+    #[test]
+    fn serialize_multiline_data() {
+        let event = ServerEvent {
+            data: "line1\nline2\nline3".into(),
+            ..Default::default()
+        };
+        let bytes = event.to_wire().unwrap();
+        let expected = "data: line1\ndata: line2\ndata: line3\n\n";
+        assert_eq!(std::str::from_utf8(&bytes).unwrap(), expected);
+    }
+
+    #[test]
+    fn serialize_data_with_cr_line_endings() {
+        let event = ServerEvent {
+            data: "a\r\nb\rc\nd".into(),
+            ..Default::default()
+        };
+        let bytes = event.to_wire().unwrap();
+        let expected = "data: a\ndata: b\ndata: c\ndata: d\n\n";
+        assert_eq!(std::str::from_utf8(&bytes).unwrap(), expected);
+    }
+
+    #[test]
+    fn serialize_rejects_line_breaks_in_single_line_fields() {
+        for event in [
+            ServerEvent {
+                id: Some("1\ndata: injected".into()),
+                ..Default::default()
+            },
+            ServerEvent {
+                event: Some("update\r".into()),
+                ..Default::default()
+            },
+        ] {
+            let err = event.to_wire().unwrap_err();
+            assert!(
+                matches!(err, StreamingError::ServerEventsSerialize { .. }),
+                "got: {err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn serialize_rejects_injecting_extra_field_names() {
+        for name in ["", "x\ndata", "x\r", "x:y", "data", "id", "event", "retry"] {
+            let event = ServerEvent {
+                data: "ok".into(),
+                extra: [(name.to_owned(), "1".to_owned())].into(),
+                ..Default::default()
+            };
+            let err = event.to_wire().unwrap_err();
+            assert!(
+                matches!(err, StreamingError::ServerEventsSerialize { .. }),
+                "name {name:?} got: {err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn serialize_rejects_line_breaks_in_extra_values() {
+        for value in ["1\ndata: injected", "1\r", "a\r\n\r\ndata: x"] {
+            let event = ServerEvent {
+                data: "ok".into(),
+                extra: [("x-usage".to_owned(), value.to_owned())].into(),
+                ..Default::default()
+            };
+            let err = event.to_wire().unwrap_err();
+            assert!(
+                matches!(err, StreamingError::ServerEventsSerialize { .. }),
+                "value {value:?} got: {err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn to_wire_writes_every_field_and_splits_data_lines() {
+        let event = ServerEvent {
+            id: Some("7".into()),
+            event: Some("delta".into()),
+            data: "first\nsecond\r\nthird".into(),
+            retry: Some(1500),
+            extra: [("x-usage".to_owned(), "42".to_owned())].into(),
+        };
+        assert_eq!(
+            event.to_wire().unwrap(),
+            "id: 7\nevent: delta\nretry: 1500\nx-usage: 42\n\
+             data: first\ndata: second\ndata: third\n\n"
+        );
+    }
+
+    #[test]
+    fn builder_sets_each_field() {
+        let event = ServerEvent::builder()
+            .id("42")
+            .event("update")
+            .data("payload")
+            .retry(3000)
+            .build();
+        assert_eq!(
+            event,
+            ServerEvent {
+                id: Some("42".into()),
+                event: Some("update".into()),
+                data: "payload".into(),
+                retry: Some(3000),
+                ..ServerEvent::default()
+            }
+        );
+        assert_eq!(
+            ServerEvent::data("hi"),
+            ServerEvent::builder().data("hi").build()
+        );
+    }
+
+    #[test]
+    fn data_lossy_replaces_control_characters() {
+        let event = ServerEvent::data("a\0b\x1b[31mc\rd\u{7f}e\u{85}");
+        assert_eq!(
+            event.data_lossy(),
+            "a\u{FFFD}b\u{FFFD}[31mc\u{FFFD}d\u{FFFD}e\u{FFFD}"
+        );
+        assert_eq!(event.data, "a\0b\x1b[31mc\rd\u{7f}e\u{85}");
+    }
+
+    #[test]
+    fn data_lossy_borrows_clean_data() {
+        let event = ServerEvent::data("line 1\n\tline 2 \u{20ac}");
+        assert!(matches!(
+            event.data_lossy(),
+            Cow::Borrowed("line 1\n\tline 2 \u{20ac}")
+        ));
+    }
+
+    #[test]
+    fn json_value_exposes_fields() {
+        let event = ServerEvent {
+            data: r#"{"type":"delta","text":"hi"}"#.into(),
+            ..ServerEvent::default()
+        };
+        let value = event.json_value().unwrap();
+        assert_eq!(value["type"], "delta");
+        assert_eq!(value["text"], "hi");
+    }
+
+    #[test]
+    fn binary_data_round_trips_bytes() {
+        let payload = [0u8, 0xff, 0xfe, b'\n', b'\r', 0x80];
+        let event = ServerEvent::default().with_binary_data(payload);
+        assert!(event.is_binary());
+        assert!(event.data.is_ascii());
+        assert_eq!(event.binary_data().unwrap().as_ref(), payload);
+    }
+
+    #[test]
+    fn binary_data_of_text_event_is_utf8_bytes() {
+        let event = ServerEvent {
+            data: "h\u{e9}llo".into(),
+            ..ServerEvent::default()
+        };
+        assert!(!event.is_binary());
+        assert_eq!(
+            event.binary_data().unwrap().as_ref(),
+            "h\u{e9}llo".as_bytes()
+        );
+    }
+
+    #[test]
+    fn binary_data_rejects_invalid_base64() {
+        let mut event = ServerEvent::default().with_binary_data(b"x");
+        event.data = "not base64!".into();
+        let err = event.binary_data().unwrap_err();
+        assert!(matches!(err, StreamingError::ServerEventsParse { .. }));
+    }
+
+    #[test]
+    fn json_value_rejects_non_json() {
+        let event = ServerEvent {
+            data: "[DONE]".into(),
+            ..ServerEvent::default()
+        };
+        assert!(event.json_value().is_err());
+    }
 }
@@ -1,13 +1,32 @@
+#[cfg(feature = "sse-compression")]
+mod decompress;
 mod detect;
 mod event;
 mod parse;
+mod reconnect;
+mod reconnecting;
 #[cfg(feature = "axum")]
 mod response;
+mod router;
 mod stream;
+#[cfg(feature = "axum")]
+mod writer;
 
-pub use detect::is_server_events_response;
-pub use event::ServerEvent;
+pub(crate) use detect::is_sniffable_content_type;
+pub use detect::{is_server_events_response, looks_like_server_events};
+pub use event::{ServerEvent, ServerEventBuilder};
+#[cfg(all(test, feature = "axum"))]
 pub(crate) use parse::parse_server_events_stream;
+pub(crate) use parse::{ParseActivity, parse_server_events_stream_tracked};
+pub use parse::{SseDecoder, Utf8Mode};
+pub use reconnect::ReconnectPolicy;
+pub use reconnecting::ReconnectingServerEvents;
+#[cfg(feature = "axum")]
+pub(crate) use response::{server_events_response, server_events_response_with_keepalive};
+pub use router::ServerEventRouter;
+pub use stream::{
+    FromServerEvent, IdleActivity, MergePolicy, ResponseKind, ServerEventsResponse,
+    ServerEventsStream, SseStats, ToServerEvent,
+};
 #[cfg(feature = "axum")]
-pub(crate) use response::server_events_response;
-pub use stream::{FromServerEvent, ServerEventsResponse, ServerEventsStream};
+pub use writer::{SseWriter, sse_channel};
@@ -1,5 +1,8 @@
+use std::borrow::Cow;
 use std::collections::VecDeque;
 use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use futures_core::Stream;
 use futures_util::StreamExt;
@@ -8,23 +11,356 @@ use crate::body::BodyStream;
 use crate::error::StreamingError;
 use crate::sse::ServerEvent;
 
-struct ParseState {
-    body: BodyStream,
+/// Default for [`SseDecoder::with_max_buffer_size`].
+const DEFAULT_MAX_BUFFER_SIZE: usize = 1024 * 1024;
+
+type CommentFn = dyn FnMut(&str) + Send;
+
+/// How [`SseDecoder`] handles bytes that are not valid UTF-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Utf8Mode {
+    /// Fail the slice with [`StreamingError::ServerEventsParse`] and discard it.
+    #[default]
+    Strict,
+    /// Replace each invalid sequence with U+FFFD, as
+    /// [`String::from_utf8_lossy`] does, and keep decoding.
+    Lossy,
+}
+
+/// Callback set by [`SseDecoder::with_comment_observer`].
+struct CommentObserver(Box<CommentFn>);
+
+impl std::fmt::Debug for CommentObserver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CommentObserver")
+    }
+}
+
+/// Incremental, sans-IO SSE decoder.
+///
+/// Bytes are pushed in arbitrarily sized slices; completed events are
+/// returned as soon as their terminating blank line arrives. Handles UTF-8
+/// sequences split across slices, a leading BOM, and CRLF / CR / LF line
+/// endings. The async parser behind
+/// [`ServerEventsStream`](crate::sse::ServerEventsStream) is a thin wrapper
+/// over it; use the decoder directly for synchronous parsing or fuzzing.
+///
+/// ```
+/// use oagw_sdk::sse::SseDecoder;
+///
+/// let mut decoder = SseDecoder::new();
+/// assert!(decoder.push(b"data: hel").unwrap().is_empty());
+/// let events = decoder.push(b"lo\n\n").unwrap();
+/// assert_eq!(events[0].data, "hello");
+/// assert!(decoder.finish().unwrap().is_empty());
+/// ```
+#[derive(Debug)]
+pub struct SseDecoder {
     buf: String,
-    /// Events parsed from the current buffer but not yet yielded.
-    pending: VecDeque<ServerEvent>,
-    /// Trailing bytes from the previous chunk that form an incomplete UTF-8 sequence.
-    /// Prepended to the next chunk before decoding.
+    /// Trailing bytes from the previous slice that form an incomplete UTF-8 sequence.
+    /// Prepended to the next slice before decoding.
     utf8_tail: Vec<u8>,
-    /// Whether this is the first chunk (for BOM stripping).
+    /// Whether no text has been decoded yet (for BOM stripping).
     first_chunk: bool,
+    /// Number of bytes successfully decoded so far (excludes `utf8_tail`).
+    /// Used to report the stream position of decode failures.
+    decoded_bytes: u64,
+    /// Number of events returned so far. Used to report which event a decode
+    /// failure occurred in.
+    events_emitted: u64,
+    /// Number of blocks consisting only of `:` comment lines (keep-alives).
+    comment_blocks: u64,
+    /// Whether unknown fields are collected into [`ServerEvent::extra`].
+    capture_extra: bool,
+    /// Whether the [`ServerEvent::DATA_ENCODING_FIELD`] marker is kept even
+    /// when other unknown fields are ignored.
+    capture_encoding: bool,
+    /// Largest unterminated event, in bytes, buffered before failing.
+    max_buffer_size: usize,
+    /// Receives the text of each `:` comment line.
+    on_comment: Option<CommentObserver>,
+    utf8_mode: Utf8Mode,
+}
+
+impl Default for SseDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SseDecoder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            buf: String::new(),
+            utf8_tail: Vec::new(),
+            first_chunk: true,
+            decoded_bytes: 0,
+            events_emitted: 0,
+            comment_blocks: 0,
+            capture_extra: false,
+            capture_encoding: false,
+            max_buffer_size: DEFAULT_MAX_BUFFER_SIZE,
+            on_comment: None,
+            utf8_mode: Utf8Mode::Strict,
+        }
+    }
+
+    /// Collect fields the spec does not define (e.g. vendor `x-usage: 42`)
+    /// into [`ServerEvent::extra`] instead of ignoring them.
+    ///
+    /// Off by default. Blocks holding only unknown fields are then yielded as
+    /// events rather than dropped.
+    #[must_use]
+    pub fn with_extra_fields(mut self, enabled: bool) -> Self {
+        self.capture_extra = enabled;
+        self
+    }
+
+    /// Keep the `data-encoding` marker written by
+    /// [`ServerEvent::with_binary_data`] so that
+    /// [`ServerEvent::binary_data`] can decode base64 payloads.
+    ///
+    /// Off by default; implied by [`with_extra_fields`](Self::with_extra_fields).
+    #[must_use]
+    pub fn with_binary_data(mut self, enabled: bool) -> Self {
+        self.capture_encoding = enabled;
+        self
+    }
+
+    /// Fail once an unterminated event exceeds `bytes` (default: 1 MiB),
+    /// instead of buffering an upstream that never sends a blank line until
+    /// memory runs out.
+    ///
+    /// The check runs after each slice is split into events, so the buffer
+    /// may overshoot by at most one slice.
+    #[must_use]
+    pub fn with_max_buffer_size(mut self, bytes: usize) -> Self {
+        self.max_buffer_size = bytes;
+        self
+    }
+
+    /// Choose how invalid UTF-8 is handled (default: [`Utf8Mode::Strict`]).
+    ///
+    /// [`Utf8Mode::Lossy`] suits upstreams that occasionally corrupt a frame
+    /// but continue with valid data: the damaged bytes become U+FFFD inside
+    /// the event and no error is reported, including for a stream that ends
+    /// mid-sequence.
+    #[must_use]
+    pub fn with_utf8_mode(mut self, mode: Utf8Mode) -> Self {
+        self.utf8_mode = mode;
+        self
+    }
+
+    /// Call `observer` with the text of every `:` comment line, e.g. `ping`
+    /// for `: ping`, as its block is decoded.
+    ///
+    /// Comments are otherwise dropped, per spec; this lets monitoring code
+    /// see keep-alives, including comment-only blocks that yield no event.
+    /// A single space after the colon is stripped, as for field values.
+    #[must_use]
+    pub fn with_comment_observer(mut self, observer: impl FnMut(&str) + Send + 'static) -> Self {
+        self.on_comment = Some(CommentObserver(Box::new(observer)));
+        self
+    }
+
+    /// Number of comment-only blocks (e.g. `: keep-alive\n\n`) seen so far.
+    ///
+    /// Such blocks produce no event, so this is the only trace they leave.
+    #[must_use]
+    pub fn comment_blocks(&self) -> u64 {
+        self.comment_blocks
+    }
+
+    /// Feed the next slice of the byte stream, returning any events it completes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::ServerEventsParse`] if the slice contains
+    /// invalid UTF-8 under [`Utf8Mode::Strict`]. The offending slice (and any
+    /// buffered partial sequence) is discarded; the decoder stays usable for
+    /// subsequent slices.
+    ///
+    /// Returns [`StreamingError::ServerEventsBufferExceeded`] if the slice
+    /// completes no event and leaves more than the
+    /// [buffer limit](Self::with_max_buffer_size) unterminated. The buffered
+    /// partial event is discarded.
+    pub fn push(&mut self, chunk: &[u8]) -> Result<Vec<ServerEvent>, StreamingError> {
+        // Prepend any leftover bytes from a split multibyte sequence.
+        let bytes = if self.utf8_tail.is_empty() {
+            chunk.to_vec()
+        } else {
+            let mut combined = std::mem::take(&mut self.utf8_tail);
+            combined.extend_from_slice(chunk);
+            combined
+        };
+
+        let text = match std::str::from_utf8(&bytes) {
+            Ok(t) => {
+                self.decoded_bytes += bytes.len() as u64;
+                Cow::Borrowed(t)
+            }
+            Err(e) if e.error_len().is_none() => {
+                // Incomplete multibyte sequence at the end — buffer
+                // the trailing bytes and decode the valid prefix.
+                //
+                // `error_len() == None` is only reported for a valid *prefix* of
+                // a sequence (a lead byte plus at most two continuation bytes),
+                // so the tail never exceeds 3 bytes no matter how finely the
+                // input is chunked. Stray continuation bytes without a lead byte
+                // have `error_len() == Some(_)` and are rejected below.
+                let valid_up_to = e.valid_up_to();
+                self.decoded_bytes += valid_up_to as u64;
+                self.utf8_tail = bytes[valid_up_to..].to_vec();
+                // Safety: valid_up_to is guaranteed to be valid UTF-8.
+                Cow::Borrowed(std::str::from_utf8(&bytes[..valid_up_to]).unwrap())
+            }
+            Err(_) if self.utf8_mode == Utf8Mode::Lossy => Cow::Owned(self.decode_lossy(&bytes)),
+            Err(e) => {
+                // Truly invalid UTF-8 byte(s) — unrecoverable.
+                return Err(StreamingError::ServerEventsParse {
+                    detail: format!(
+                        "invalid UTF-8 at byte {} (event {}): {e}",
+                        self.decoded_bytes + e.valid_up_to() as u64,
+                        self.events_emitted,
+                    ),
+                });
+            }
+        };
+
+        if text.is_empty() {
+            self.debug_check_invariants();
+            return Ok(Vec::new());
+        }
+        // Strip UTF-8 BOM from the very first text (per W3C spec).
+        let text = &*text;
+        let text = if self.first_chunk {
+            self.first_chunk = false;
+            text.strip_prefix('\u{FEFF}').unwrap_or(text)
+        } else {
+            text
+        };
+        self.buf.push_str(&normalize_line_endings(text));
+        let events = self.extract_events();
+        // Events completed by this slice are returned first; a remainder
+        // still over the limit fails on the next slice.
+        if events.is_empty() && self.buf.len() > self.max_buffer_size {
+            self.buf.clear();
+            self.utf8_tail.clear();
+            return Err(StreamingError::ServerEventsBufferExceeded {
+                limit: self.max_buffer_size,
+            });
+        }
+        self.events_emitted += events.len() as u64;
+        self.debug_check_invariants();
+        Ok(events)
+    }
+
+    /// Signal end of stream, returning the final event if the stream ended
+    /// without a trailing blank line.
+    ///
+    /// Calling `finish` again (or after a fully terminated stream) returns
+    /// an empty vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::ServerEventsParse`] if the stream ended in
+    /// the middle of a multibyte UTF-8 sequence, which usually means the
+    /// upstream response was truncated. The incomplete trailing event is
+    /// discarded; a subsequent call returns `Ok` with no events.
+    pub fn finish(&mut self) -> Result<Vec<ServerEvent>, StreamingError> {
+        if !self.utf8_tail.is_empty() && self.utf8_mode == Utf8Mode::Lossy {
+            self.utf8_tail.clear();
+            self.buf.push(char::REPLACEMENT_CHARACTER);
+        }
+        if !self.utf8_tail.is_empty() {
+            self.utf8_tail.clear();
+            self.buf.clear();
+            return Err(StreamingError::ServerEventsParse {
+                detail: format!(
+                    "stream ended mid UTF-8 sequence at byte {} (event {})",
+                    self.decoded_bytes, self.events_emitted,
+                ),
+            });
+        }
+
+        let mut events = Vec::new();
+        if !self.buf.trim().is_empty() {
+            let block = std::mem::take(&mut self.buf);
+            events.extend(self.parse_block(block.trim_end_matches('\n')));
+        }
+        self.buf.clear();
+        self.events_emitted += events.len() as u64;
+        self.debug_check_invariants();
+        Ok(events)
+    }
+
+    /// Decode `bytes`, replacing invalid sequences with U+FFFD. A trailing
+    /// incomplete sequence is kept in `utf8_tail` for the next slice.
+    fn decode_lossy(&mut self, bytes: &[u8]) -> String {
+        let mut text = String::with_capacity(bytes.len());
+        let mut rest = bytes;
+        loop {
+            match std::str::from_utf8(rest) {
+                Ok(valid) => {
+                    text.push_str(valid);
+                    rest = &[];
+                    break;
+                }
+                Err(e) => {
+                    let (valid, after) = rest.split_at(e.valid_up_to());
+                    // Safety: `valid_up_to` bytes are valid UTF-8.
+                    text.push_str(std::str::from_utf8(valid).unwrap());
+                    let Some(invalid_len) = e.error_len() else {
+                        rest = after;
+                        break;
+                    };
+                    text.push(char::REPLACEMENT_CHARACTER);
+                    rest = &after[invalid_len..];
+                }
+            }
+        }
+        self.utf8_tail = rest.to_vec();
+        self.decoded_bytes += (bytes.len() - rest.len()) as u64;
+        text
+    }
+
+    /// Assert the buffering invariants that hold between calls. Compiled out
+    /// without `debug_assertions`; tests and fuzzing exercise them.
+    fn debug_check_invariants(&self) {
+        debug_assert!(
+            !self.buf.contains("\n\n"),
+            "buffer holds a complete event after extraction: {:?}",
+            self.buf
+        );
+        debug_assert!(
+            !self.buf.contains('\r'),
+            "buffer holds un-normalized line endings: {:?}",
+            self.buf
+        );
+        debug_assert!(
+            self.utf8_tail.len() < 4,
+            "UTF-8 tail must be < 4 bytes, got {}",
+            self.utf8_tail.len()
+        );
+    }
+}
+
+struct ParseState {
+    body: BodyStream,
+    decoder: SseDecoder,
+    activity: Arc<ParseActivity>,
+    /// Events decoded from previous chunks but not yet yielded.
+    pending: VecDeque<ServerEvent>,
     done: bool,
 }
 
 /// Parse a field line within an SSE event block.
 ///
-/// Malformed lines are silently skipped (per W3C spec).
-fn parse_line(line: &str, event: &mut ServerEvent) {
+/// Malformed lines are silently skipped (per W3C spec). Unknown fields are
+/// ignored unless `capture_extra` is set; the data-encoding marker is also
+/// kept when `capture_encoding` is set.
+fn parse_line(line: &str, event: &mut ServerEvent, capture_extra: bool, capture_encoding: bool) {
     // Comment lines start with ':'
     if line.starts_with(':') {
         return;
@@ -63,6 +399,16 @@ fn parse_line(line: &str, event: &mut ServerEvent) {
                 event.retry = Some(ms);
             }
         }
+        _ if capture_extra || (capture_encoding && field == ServerEvent::DATA_ENCODING_FIELD) => {
+            event
+                .extra
+                .entry(field.to_owned())
+                .and_modify(|v| {
+                    v.push('\n');
+                    v.push_str(value);
+                })
+                .or_insert_with(|| value.to_owned());
+        }
         _ => {
             // Unknown field — ignore per spec.
             tracing::trace!("ignoring unknown SSE field: {field}");
@@ -75,63 +421,142 @@ fn parse_line(line: &str, event: &mut ServerEvent) {
 /// The W3C EventSource specification requires support for all three line
 /// ending styles. We normalize once at buffer-append time so the rest of
 /// the parser can work exclusively with `\n`.
-fn normalize_line_endings(s: &str) -> String {
+pub(super) fn normalize_line_endings(s: &str) -> String {
     // Replace CRLF first, then any remaining bare CR.
     s.replace("\r\n", "\n").replace('\r', "\n")
 }
 
-/// Split buffered text on event boundaries (`\n\n`), returning completed
-/// event blocks and leaving any partial trailing data in the buffer.
-fn extract_events(buf: &mut String) -> VecDeque<ServerEvent> {
-    let mut events = VecDeque::new();
-
-    // SSE events are separated by blank lines (\n\n).
-    // We split on \n\n and process each block.
-    loop {
-        // Find the next event boundary.
-        let boundary = buf.find("\n\n");
-        let Some(pos) = boundary else {
-            break;
-        };
+impl SseDecoder {
+    /// Split buffered text on event boundaries (`\n\n`), returning completed
+    /// event blocks and leaving any partial trailing data in the buffer.
+    fn extract_events(&mut self) -> Vec<ServerEvent> {
+        let mut events = Vec::new();
+
+        // SSE events are separated by blank lines (\n\n).
+        // We split on \n\n and process each block.
+        loop {
+            // Find the next event boundary.
+            let boundary = self.buf.find("\n\n");
+            let Some(pos) = boundary else {
+                break;
+            };
+
+            let block = self.buf[..pos].to_owned();
+            events.extend(self.parse_block(&block));
+
+            // Remove the consumed block + the two newlines.
+            let drain_to = pos + 2;
+            // There may be more consecutive newlines — skip them.
+            let remainder = &self.buf[drain_to..];
+            let trimmed = remainder.trim_start_matches('\n');
+            let extra_newlines = remainder.len() - trimmed.len();
+            self.buf = self.buf[drain_to + extra_newlines..].to_owned();
+        }
 
-        let block = &buf[..pos];
-        if !block.is_empty() {
-            let mut event = ServerEvent::default();
-            for line in block.lines() {
-                parse_line(line, &mut event);
-            }
-            if !event.is_empty() {
-                events.push_back(event);
+        events
+    }
+
+    /// Parse one event block, counting it if it holds only comments.
+    fn parse_block(&mut self, block: &str) -> Option<ServerEvent> {
+        if block.is_empty() {
+            return None;
+        }
+        if let Some(CommentObserver(observer)) = &mut self.on_comment {
+            for comment in block.lines().filter_map(|line| line.strip_prefix(':')) {
+                observer(comment.strip_prefix(' ').unwrap_or(comment));
             }
         }
+        if block.lines().all(|line| line.starts_with(':')) {
+            self.comment_blocks += 1;
+            return None;
+        }
+        let mut event = ServerEvent::default();
+        for line in block.lines() {
+            parse_line(line, &mut event, self.capture_extra, self.capture_encoding);
+        }
+        (!event.is_empty()).then_some(event)
+    }
+}
+
+/// Liveness counters published by [`parse_server_events_stream_tracked`] so
+/// that wrappers (e.g. the idle timeout) can observe progress that does not
+/// produce events.
+#[derive(Debug, Default)]
+pub(crate) struct ParseActivity {
+    /// Body chunks received.
+    chunks: AtomicU64,
+    /// Raw body bytes received, before UTF-8 decoding or line-ending
+    /// normalization.
+    bytes: AtomicU64,
+    /// Events decoded.
+    events: AtomicU64,
+    /// Errors yielded, from decoding or the body stream.
+    errors: AtomicU64,
+    /// Comment-only blocks decoded.
+    comment_blocks: AtomicU64,
+}
+
+impl ParseActivity {
+    pub(crate) fn chunks(&self) -> u64 {
+        self.chunks.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn bytes(&self) -> u64 {
+        self.bytes.load(Ordering::Relaxed)
+    }
 
-        // Remove the consumed block + the two newlines.
-        let drain_to = pos + 2;
-        // There may be more consecutive newlines — skip them.
-        let remainder = &buf[drain_to..];
-        let trimmed = remainder.trim_start_matches('\n');
-        let extra_newlines = remainder.len() - trimmed.len();
-        *buf = buf[drain_to + extra_newlines..].to_owned();
+    pub(crate) fn events(&self) -> u64 {
+        self.events.load(Ordering::Relaxed)
     }
 
-    events
+    pub(crate) fn errors(&self) -> u64 {
+        self.errors.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn comment_blocks(&self) -> u64 {
+        self.comment_blocks.load(Ordering::Relaxed)
+    }
+
+    fn record_decoded(&self, decoded: &Result<Vec<ServerEvent>, StreamingError>) {
+        match decoded {
+            Ok(events) => self
+                .events
+                .fetch_add(events.len() as u64, Ordering::Relaxed),
+            Err(_) => self.errors.fetch_add(1, Ordering::Relaxed),
+        };
+    }
+}
+
+/// [`parse_server_events_stream_tracked`] without liveness tracking.
+#[cfg(test)]
+#[allow(clippy::type_complexity)]
+pub fn parse_server_events_stream(
+    body: BodyStream,
+) -> Pin<Box<dyn Stream<Item = Result<ServerEvent, StreamingError>> + Send>> {
+    parse_server_events_stream_tracked(body, SseDecoder::new(), Arc::default())
 }
 
 /// Parse a raw byte stream into a stream of SSE events.
 ///
 /// Chunks are buffered internally and split on blank-line boundaries (`\n\n`).
 /// Malformed lines within an event are silently skipped (per W3C EventSource spec).
-/// Empty events (comment-only blocks) are not yielded.
+/// Empty events (comment-only blocks) are not yielded. Decoding is delegated
+/// to `decoder`, which carries the parse options.
+///
+/// Liveness counters are published to `activity` as chunks are read, so
+/// wrappers such as the idle timeout can observe progress that does not
+/// produce events.
 #[allow(clippy::type_complexity)]
-pub fn parse_server_events_stream(
+pub(crate) fn parse_server_events_stream_tracked(
     body: BodyStream,
+    decoder: SseDecoder,
+    activity: Arc<ParseActivity>,
 ) -> Pin<Box<dyn Stream<Item = Result<ServerEvent, StreamingError>> + Send>> {
     let state = ParseState {
         body,
-        buf: String::new(),
+        decoder,
+        activity,
         pending: VecDeque::new(),
-        utf8_tail: Vec::new(),
-        first_chunk: true,
         done: false,
     };
 
@@ -145,68 +570,50 @@ pub fn parse_server_events_stream(
                 }
 
                 if state.done {
-                    // Stream is finished. Flush any remaining data in the buffer.
-                    if !state.buf.trim().is_empty() {
-                        let mut event = ServerEvent::default();
-                        for line in state.buf.lines() {
-                            parse_line(line, &mut event);
-                        }
-                        state.buf.clear();
-                        if !event.is_empty() {
-                            return Some((Ok(event), state));
-                        }
+                    // Stream is finished. Flush any remaining data in the decoder;
+                    // `finish` is idempotent, so this terminates once drained.
+                    let finished = state.decoder.finish();
+                    state.activity.record_decoded(&finished);
+                    match finished {
+                        Ok(rest) if rest.is_empty() => return None,
+                        Ok(rest) => state.pending.extend(rest),
+                        Err(e) => return Some((Err(e), state)),
                     }
-                    return None;
+                    continue;
                 }
 
                 // Read the next chunk from the body stream.
+                debug_assert!(
+                    state.pending.is_empty(),
+                    "pending events must be drained before reading more input"
+                );
                 match state.body.next().await {
                     Some(Ok(chunk)) => {
-                        // Prepend any leftover bytes from a split multibyte sequence.
-                        let bytes = if state.utf8_tail.is_empty() {
-                            chunk.to_vec()
-                        } else {
-                            let mut combined = std::mem::take(&mut state.utf8_tail);
-                            combined.extend_from_slice(&chunk);
-                            combined
-                        };
-
-                        let text = match std::str::from_utf8(&bytes) {
-                            Ok(t) => t.to_owned(),
-                            Err(e) if e.error_len().is_none() => {
-                                // Incomplete multibyte sequence at the end — buffer
-                                // the trailing bytes and decode the valid prefix.
-                                let valid_up_to = e.valid_up_to();
-                                state.utf8_tail = bytes[valid_up_to..].to_vec();
-                                // Safety: valid_up_to is guaranteed to be valid UTF-8.
-                                String::from_utf8(bytes[..valid_up_to].to_vec()).unwrap()
-                            }
+                        state.activity.chunks.fetch_add(1, Ordering::Relaxed);
+                        state
+                            .activity
+                            .bytes
+                            .fetch_add(chunk.len() as u64, Ordering::Relaxed);
+                        let decoded = state.decoder.push(&chunk);
+                        state.activity.record_decoded(&decoded);
+                        state
+                            .activity
+                            .comment_blocks
+                            .store(state.decoder.comment_blocks(), Ordering::Relaxed);
+                        match decoded {
+                            // Loop back to yield pending events.
+                            Ok(events) => state.pending.extend(events),
                             Err(e) => {
-                                // Truly invalid UTF-8 byte(s) — unrecoverable.
-                                return Some((
-                                    Err(StreamingError::ServerEventsParse {
-                                        detail: format!("invalid UTF-8: {e}"),
-                                    }),
-                                    state,
-                                ));
+                                if matches!(e, StreamingError::ServerEventsBufferExceeded { .. }) {
+                                    state.done = true;
+                                }
+                                return Some((Err(e), state));
                             }
-                        };
-
-                        if !text.is_empty() {
-                            // Strip UTF-8 BOM from the very first chunk (per W3C spec).
-                            let text = if state.first_chunk {
-                                state.first_chunk = false;
-                                text.strip_prefix('\u{FEFF}').unwrap_or(&text).to_owned()
-                            } else {
-                                text
-                            };
-                            state.buf.push_str(&normalize_line_endings(&text));
-                            state.pending = extract_events(&mut state.buf);
                         }
-                        // Loop back to yield pending events.
                     }
                     Some(Err(e)) => {
                         state.done = true;
+                        state.activity.errors.fetch_add(1, Ordering::Relaxed);
                         return Some((Err(StreamingError::Stream(e)), state));
                     }
                     None => {
@@ -450,6 +857,49 @@ mod tests {
         assert!(events[0].is_err());
     }
 
+    #[tokio::test]
+    async fn invalid_utf8_error_reports_position() {
+        // Two complete events (24 bytes), then 4 valid bytes and an invalid one.
+        let owned: Vec<Result<Bytes, BoxError>> = vec![
+            Ok(Bytes::from_static(b"data: one\n\ndata: two\n\n")),
+            Ok(Bytes::from_static(b"data\xFF")),
+        ];
+        let body: BodyStream = Box::pin(futures_util::stream::iter(owned));
+
+        let events: Vec<_> = parse_server_events_stream(body).collect::<Vec<_>>().await;
+
+        assert_eq!(events.len(), 3);
+        let Err(StreamingError::ServerEventsParse { detail }) = &events[2] else {
+            panic!("expected ServerEventsParse, got {:?}", events[2]);
+        };
+        assert!(
+            detail.starts_with("invalid UTF-8 at byte 26 (event 2)"),
+            "unexpected detail: {detail}"
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_utf8_position_accounts_for_split_sequence() {
+        // "€" split across chunks must not shift the reported offset.
+        let owned: Vec<Result<Bytes, BoxError>> = vec![
+            Ok(Bytes::from_static(b"data: \xE2")),
+            Ok(Bytes::from_static(b"\x82\xAC\n\n\xFF")),
+        ];
+        let body: BodyStream = Box::pin(futures_util::stream::iter(owned));
+
+        let events: Vec<_> = parse_server_events_stream(body).collect::<Vec<_>>().await;
+
+        // The whole second chunk is rejected, so no event is yielded first.
+        assert_eq!(events.len(), 1);
+        let Err(StreamingError::ServerEventsParse { detail }) = &events[0] else {
+            panic!("expected ServerEventsParse, got {:?}", events[0]);
+        };
+        assert!(
+            detail.starts_with("invalid UTF-8 at byte 11 (event 0)"),
+            "unexpected detail: {detail}"
+        );
+    }
+
     // -- W3C spec: value space stripping -----------------------------------
 
     #[tokio::test]
@@ -698,6 +1148,85 @@ mod tests {
         assert_eq!(events[0].data, "👍");
     }
 
+    #[tokio::test]
+    async fn four_byte_emoji_one_byte_per_chunk() {
+        // 👍 (F0 9F 91 8D) delivered as four 1-byte chunks.
+        let mut owned: Vec<Result<Bytes, BoxError>> = vec![Ok(Bytes::from_static(b"data: "))];
+        owned.extend(
+            "👍"
+                .as_bytes()
+                .iter()
+                .map(|&b| Ok(Bytes::copy_from_slice(&[b]))),
+        );
+        owned.push(Ok(Bytes::from_static(b"\n\n")));
+        let body: BodyStream = Box::pin(futures_util::stream::iter(owned));
+
+        let events: Vec<_> = parse_server_events_stream(body)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .map(|r| r.unwrap())
+            .collect();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].data, "👍");
+    }
+
+    #[test]
+    fn utf8_tail_stays_bounded_with_one_byte_chunks() {
+        let mut decoder = SseDecoder::new();
+        decoder.push(b"data: ").unwrap();
+        for (i, &b) in "👍".as_bytes()[..3].iter().enumerate() {
+            assert!(decoder.push(&[b]).unwrap().is_empty());
+            assert_eq!(decoder.utf8_tail.len(), i + 1);
+        }
+        assert!(decoder.push(&[0x8D]).unwrap().is_empty());
+        assert!(decoder.utf8_tail.is_empty());
+
+        let events = decoder.push(b"\n\n").unwrap();
+        assert_eq!(events[0].data, "👍");
+    }
+
+    #[tokio::test]
+    async fn continuation_only_bytes_error_per_chunk_and_continue() {
+        // A long run of continuation bytes with no lead byte, one per chunk.
+        // Each chunk must fail on its own instead of accumulating in the tail.
+        let owned: Vec<Result<Bytes, BoxError>> =
+            (0..64).map(|_| Ok(Bytes::from_static(b"\x80"))).collect();
+        let body: BodyStream = Box::pin(futures_util::stream::iter(owned));
+
+        let items: Vec<_> = parse_server_events_stream(body).collect::<Vec<_>>().await;
+
+        assert_eq!(items.len(), 64);
+        for item in &items {
+            let Err(StreamingError::ServerEventsParse { detail }) = item else {
+                panic!("expected ServerEventsParse, got {item:?}");
+            };
+            assert!(detail.starts_with("invalid UTF-8 at byte 0 (event 0)"));
+        }
+    }
+
+    #[test]
+    fn continuation_only_run_in_one_chunk_errors_once() {
+        let mut decoder = SseDecoder::new();
+        assert!(decoder.push(&[0x80; 1024]).is_err());
+        assert!(decoder.utf8_tail.is_empty());
+    }
+
+    #[test]
+    fn truncated_sequence_followed_by_new_lead_byte_errors() {
+        // F0 starts a 4-byte sequence, but the next chunk begins a new one.
+        let mut decoder = SseDecoder::new();
+        assert!(decoder.push(b"data: \xF0").unwrap().is_empty());
+        let err = decoder.push("👍\n\n".as_bytes()).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("invalid UTF-8 at byte 6 (event 0)"),
+            "unexpected error: {err}"
+        );
+        assert!(decoder.utf8_tail.is_empty());
+    }
+
     #[tokio::test]
     async fn multiple_events_split_across_chunks() {
         let body = body_from_chunks(vec!["data: hel", "lo\n\ndata:", " world\n\n"]);
@@ -755,4 +1284,298 @@ mod tests {
 
         assert_eq!(events[0].data, "\ttest");
     }
+
+    // -- Sans-IO decoder ---------------------------------------------------
+
+    #[test]
+    fn decoder_emits_events_as_boundaries_arrive() {
+        let mut decoder = SseDecoder::new();
+        assert!(decoder.push(b"id: 1\ndata: a").unwrap().is_empty());
+        let events = decoder.push(b"\n\ndata: b\n\ndata: c").unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].id.as_deref(), Some("1"));
+        assert_eq!(events[0].data, "a");
+        assert_eq!(events[1].data, "b");
+
+        let rest = decoder.finish().unwrap();
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].data, "c");
+        assert!(decoder.finish().unwrap().is_empty());
+    }
+
+    #[test]
+    fn decoder_handles_bom_crlf_and_split_utf8() {
+        let mut decoder = SseDecoder::new();
+        let bytes = "\u{FEFF}data: caf\u{e9}\r\n\r\n".as_bytes();
+        // Split inside the two-byte 'é'.
+        let split = bytes.iter().position(|&b| b == 0xC3).unwrap() + 1;
+        assert!(decoder.push(&bytes[..split]).unwrap().is_empty());
+        let events = decoder.push(&bytes[split..]).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].data, "caf\u{e9}");
+    }
+
+    #[test]
+    fn decoder_reports_invalid_utf8_and_recovers() {
+        let mut decoder = SseDecoder::new();
+        decoder.push(b"data: ok\n\n").unwrap();
+        let err = decoder.push(b"data: \xFF\n\n").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("invalid UTF-8 at byte 16 (event 1)"),
+            "unexpected error: {err}"
+        );
+
+        let events = decoder.push(b"data: next\n\n").unwrap();
+        assert_eq!(events[0].data, "next");
+    }
+
+    #[tokio::test]
+    async fn unterminated_event_over_default_limit_ends_stream() {
+        // 2 MiB of `data:` with no blank line, plus a later event that must
+        // not be reached.
+        let huge = format!("data: {}", "x".repeat(2 * DEFAULT_MAX_BUFFER_SIZE));
+        let owned: Vec<Result<Bytes, BoxError>> = vec![
+            Ok(Bytes::from(huge)),
+            Ok(Bytes::from_static(b"\n\ndata: after\n\n")),
+        ];
+        let body: BodyStream = Box::pin(futures_util::stream::iter(owned));
+
+        let events: Vec<_> = parse_server_events_stream(body).collect().await;
+
+        assert_eq!(events.len(), 1, "stream must stop after the error");
+        assert!(matches!(
+            events[0],
+            Err(StreamingError::ServerEventsBufferExceeded {
+                limit: DEFAULT_MAX_BUFFER_SIZE
+            })
+        ));
+    }
+
+    #[test]
+    fn decoder_buffer_limit_counts_unterminated_event_only() {
+        let mut decoder = SseDecoder::new().with_max_buffer_size(16);
+
+        // Complete events are fine however large; the remainder fails only
+        // once a slice completes nothing.
+        let events = decoder
+            .push(format!("data: {}\n\ndata: 0123456789abcdef", "y".repeat(64)).as_bytes())
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        let err = decoder.push(b"more").unwrap_err();
+        assert!(
+            matches!(
+                err,
+                StreamingError::ServerEventsBufferExceeded { limit: 16 }
+            ),
+            "got: {err:?}"
+        );
+        assert!(
+            decoder.finish().unwrap().is_empty(),
+            "partial event discarded"
+        );
+    }
+
+    #[test]
+    fn lossy_decoder_replaces_invalid_bytes_and_continues() {
+        let mut decoder = SseDecoder::new().with_utf8_mode(Utf8Mode::Lossy);
+
+        let events = decoder.push(b"data: a\xFFb\n\n").unwrap();
+        assert_eq!(events[0].data, "a\u{FFFD}b");
+
+        // A split sequence is still joined across slices, not replaced.
+        assert!(decoder.push(b"data: \xE2\x82").unwrap().is_empty());
+        let events = decoder.push(b"\xAC\xFE\n\n").unwrap();
+        assert_eq!(events[0].data, "\u{20AC}\u{FFFD}");
+
+        // Ending mid-sequence yields a replacement instead of an error.
+        assert!(decoder.push(b"data: z\xE2").unwrap().is_empty());
+        let events = decoder.finish().unwrap();
+        assert_eq!(events[0].data, "z\u{FFFD}");
+    }
+
+    #[tokio::test]
+    async fn lossy_stream_keeps_going_after_corrupt_chunk() {
+        let owned: Vec<Result<Bytes, BoxError>> = vec![
+            Ok(Bytes::from_static(b"\xFF\xFE\n\n")),
+            Ok(Bytes::from_static(b"data: after\n\n")),
+        ];
+        let body: BodyStream = Box::pin(futures_util::stream::iter(owned));
+        let decoder = SseDecoder::new().with_utf8_mode(Utf8Mode::Lossy);
+
+        let events: Vec<_> = parse_server_events_stream_tracked(body, decoder, Arc::default())
+            .collect()
+            .await;
+
+        // The corrupt block has no field name the spec knows, so it is
+        // dropped; no error is yielded and the next event arrives.
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].as_ref().unwrap().data, "after");
+    }
+
+    #[tokio::test]
+    async fn stream_matches_decoder_output() {
+        let chunks = vec!["data: x\r", "\n\r\nevent: e\ndata: y\n", "\ndata: tail"];
+        let streamed: Vec<_> = parse_server_events_stream(body_from_chunks(chunks.clone()))
+            .map(|r| r.unwrap())
+            .collect()
+            .await;
+
+        let mut decoder = SseDecoder::new();
+        let mut decoded = Vec::new();
+        for chunk in chunks {
+            decoded.extend(decoder.push(chunk.as_bytes()).unwrap());
+        }
+        decoded.extend(decoder.finish().unwrap());
+
+        assert_eq!(streamed, decoded);
+        assert_eq!(decoded.len(), 3);
+    }
+
+    #[test]
+    fn decoder_reports_comments_to_observer() {
+        let comments = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&comments);
+        let mut decoder = SseDecoder::new()
+            .with_comment_observer(move |text| sink.lock().unwrap().push(text.to_owned()));
+
+        let events = decoder
+            .push(b": ping\n\n:raw\ndata: x\n: tail\n\n")
+            .unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(*comments.lock().unwrap(), ["ping", "raw", "tail"]);
+    }
+
+    #[test]
+    fn decoder_counts_comment_only_blocks() {
+        let mut decoder = SseDecoder::new();
+        let events = decoder
+            .push(b": ping\n\n: a\n: b\n\n: note\ndata: x\n\n")
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(decoder.comment_blocks(), 2);
+
+        decoder.push(b": trailing").unwrap();
+        assert!(decoder.finish().unwrap().is_empty());
+        assert_eq!(decoder.comment_blocks(), 3);
+    }
+
+    #[test]
+    fn decoder_finish_errors_on_truncated_utf8() {
+        let mut decoder = SseDecoder::new();
+        decoder.push(b"data: ok\n\n").unwrap();
+        // First two bytes of the three-byte euro sign (E2 82 AC).
+        assert!(decoder.push(b"data: \xE2\x82").unwrap().is_empty());
+
+        let err = decoder.finish().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "SSE parse error: stream ended mid UTF-8 sequence at byte 16 (event 1)"
+        );
+        assert!(decoder.finish().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn stream_ending_mid_utf8_sequence_yields_error() {
+        let body: BodyStream = Box::pin(futures_util::stream::iter(vec![
+            Ok(Bytes::from_static(b"data: a\n\n")),
+            Ok(Bytes::from_static(b"data: \xF0\x9F")),
+        ]));
+        let results: Vec<_> = parse_server_events_stream(body).collect().await;
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap().data, "a");
+        let err = results[1].as_ref().unwrap_err();
+        assert!(
+            err.to_string().contains("stream ended mid UTF-8 sequence"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn decoder_captures_extra_fields_when_enabled() {
+        let block = b"id: 7\nx-usage: 42\nevent: done\nx-trace: a\ndata: ok\nx-trace: b\n\n";
+
+        let mut decoder = SseDecoder::new().with_extra_fields(true);
+        let events = decoder.push(block).unwrap();
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.id.as_deref(), Some("7"));
+        assert_eq!(event.event.as_deref(), Some("done"));
+        assert_eq!(event.data, "ok");
+        assert_eq!(event.extra.len(), 2);
+        assert_eq!(event.extra["x-usage"], "42");
+        assert_eq!(event.extra["x-trace"], "a\nb");
+
+        // Default: unknown fields are ignored, per spec.
+        let events = SseDecoder::new().push(block).unwrap();
+        assert!(events[0].extra.is_empty());
+        assert_eq!(events[0].data, "ok");
+    }
+
+    #[test]
+    fn decoder_yields_extra_only_block_when_capturing() {
+        let mut decoder = SseDecoder::new().with_extra_fields(true);
+        let events = decoder.push(b"x-usage: 42\n\n").unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].extra["x-usage"], "42");
+
+        assert!(
+            SseDecoder::new()
+                .push(b"x-usage: 42\n\n")
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn decoder_keeps_only_encoding_marker_in_binary_mode() {
+        let block = b"data-encoding: base64\nx-usage: 42\ndata: AP8=\n\n";
+
+        let mut decoder = SseDecoder::new().with_binary_data(true);
+        let events = decoder.push(block).unwrap();
+        assert_eq!(events[0].extra.len(), 1);
+        assert_eq!(events[0].binary_data().unwrap().as_ref(), [0x00, 0xff]);
+
+        // Without the option the marker is dropped and data stays text.
+        let events = SseDecoder::new().push(block).unwrap();
+        assert!(!events[0].is_binary());
+        assert_eq!(events[0].binary_data().unwrap().as_ref(), b"AP8=");
+    }
+
+    proptest::proptest! {
+        #![proptest_config(proptest::prelude::ProptestConfig {
+            failure_persistence: None,
+            ..proptest::prelude::ProptestConfig::default()
+        })]
+        /// Arbitrarily chunked input must never violate the decoder's
+        /// invariants (checked by `debug_check_invariants` on every call).
+        #[test]
+        fn invariants_hold_for_arbitrary_chunking(
+            input in proptest::collection::vec(
+                proptest::prop_oneof![
+                    proptest::prelude::Just(b"data: x".to_vec()),
+                    proptest::prelude::Just(b"\n".to_vec()),
+                    proptest::prelude::Just(b"\r".to_vec()),
+                    proptest::prelude::Just(b": ping".to_vec()),
+                    proptest::prelude::Just("\u{20ac}".as_bytes().to_vec()),
+                    proptest::collection::vec(proptest::prelude::any::<u8>(), 1),
+                ],
+                0..40,
+            ),
+            cuts in proptest::collection::vec(0usize..8, 0..20),
+        ) {
+            let bytes: Vec<u8> = input.concat();
+            let mut decoder = SseDecoder::new();
+            let mut rest = bytes.as_slice();
+            for cut in cuts {
+                let (chunk, tail) = rest.split_at(cut.min(rest.len()));
+                let _ = decoder.push(chunk);
+                rest = tail;
+            }
+            let _ = decoder.push(rest);
+            let _ = decoder.finish();
+        }
+    }
 }
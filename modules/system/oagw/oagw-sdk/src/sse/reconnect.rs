@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+/// Exponential backoff for re-establishing a dropped SSE connection.
+///
+/// The delay for attempt `n` (0-based) is `base * 2^n`, capped at `max`. Per
+/// the EventSource spec, the last `retry:` value sent by the server replaces
+/// `base` — pass it from
+/// [`ServerEventsStream::current_retry`](crate::sse::ServerEventsStream::current_retry)
+/// to [`delay`](Self::delay). A server-provided base larger than `max` is
+/// honoured as-is; only the exponential growth is capped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    base: Duration,
+    max: Duration,
+}
+
+impl Default for ReconnectPolicy {
+    /// 3 second base (the common EventSource default), capped at 60 seconds.
+    fn default() -> Self {
+        Self {
+            base: Duration::from_secs(3),
+            max: Duration::from_secs(60),
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    #[must_use]
+    pub fn new(base: Duration, max: Duration) -> Self {
+        Self { base, max }
+    }
+
+    /// Client-side base delay, used until the server sends `retry:`.
+    #[must_use]
+    pub fn base(&self) -> Duration {
+        self.base
+    }
+
+    /// Upper bound for the exponential growth of the delay.
+    #[must_use]
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// Delay before reconnect attempt `attempt` (0-based).
+    ///
+    /// `server_retry` is the latest `retry:` value observed on the stream; when
+    /// present it overrides the policy's base.
+    #[must_use]
+    pub fn delay(&self, attempt: u32, server_retry: Option<Duration>) -> Duration {
+        let base = server_retry.unwrap_or(self.base);
+        let factor = 2u32.saturating_pow(attempt);
+        base.saturating_mul(factor).min(self.max.max(base))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> ReconnectPolicy {
+        ReconnectPolicy::new(Duration::from_millis(100), Duration::from_secs(1))
+    }
+
+    #[test]
+    fn delay_doubles_from_base_up_to_max() {
+        let p = policy();
+        assert_eq!(p.delay(0, None), Duration::from_millis(100));
+        assert_eq!(p.delay(1, None), Duration::from_millis(200));
+        assert_eq!(p.delay(3, None), Duration::from_millis(800));
+        assert_eq!(p.delay(4, None), Duration::from_secs(1));
+        assert_eq!(p.delay(u32::MAX, None), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn server_retry_replaces_base() {
+        let p = policy();
+        let retry = Some(Duration::from_millis(250));
+        assert_eq!(p.delay(0, retry), Duration::from_millis(250));
+        assert_eq!(p.delay(1, retry), Duration::from_millis(500));
+        assert_eq!(p.delay(5, retry), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn server_retry_above_max_is_honoured() {
+        let p = policy();
+        let retry = Some(Duration::from_secs(5));
+        assert_eq!(p.delay(0, retry), Duration::from_secs(5));
+        assert_eq!(p.delay(3, retry), Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,163 @@
+use std::future::Future;
+use std::time::Duration;
+
+use futures_util::StreamExt;
+
+use crate::body::Body;
+use crate::error::{ServiceGatewayError, StreamingError};
+use crate::sse::{ReconnectPolicy, ServerEvent, ServerEventsResponse, ServerEventsStream};
+
+/// An SSE client that reconnects when the stream ends or fails, resuming
+/// from the last seen event id.
+///
+/// `connect` opens a new upstream stream — typically by building a request
+/// and calling
+/// [`ServiceGatewayClientV1::proxy_request`](crate::api::ServiceGatewayClientV1::proxy_request).
+/// It receives the latest `id:` seen so far, to send as the `Last-Event-ID`
+/// header. Reconnects wait for [`ReconnectPolicy::delay`], using the server's
+/// `retry:` hint when one was sent; the attempt counter resets whenever an
+/// event arrives.
+///
+/// ```ignore
+/// let mut events = ReconnectingServerEvents::new(|last_event_id| {
+///     let mut req = http::Request::get("/openai/v1/stream");
+///     if let Some(id) = last_event_id {
+///         req = req.header("last-event-id", id);
+///     }
+///     let req = req.body(Body::Empty).unwrap();
+///     gw.proxy_request(ctx.clone(), req)
+/// });
+/// while let Some(event) = events.next().await {
+///     println!("{}", event?.data);
+/// }
+/// ```
+pub struct ReconnectingServerEvents<F> {
+    connect: F,
+    policy: ReconnectPolicy,
+    max_attempts: Option<u32>,
+    current: Option<ServerEventsStream>,
+    last_event_id: Option<String>,
+    retry: Option<Duration>,
+    /// Reconnects since the last event; drives the backoff.
+    attempt: u32,
+    /// Consecutive `connect` failures; compared against `max_attempts`.
+    failures: u32,
+    connected_once: bool,
+    done: bool,
+}
+
+impl<F> std::fmt::Debug for ReconnectingServerEvents<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReconnectingServerEvents")
+            .field("policy", &self.policy)
+            .field("last_event_id", &self.last_event_id)
+            .field("attempt", &self.attempt)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F, Fut> ReconnectingServerEvents<F>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<http::Response<Body>, ServiceGatewayError>>,
+{
+    /// Create a client that connects lazily on the first
+    /// [`next`](Self::next), with the default [`ReconnectPolicy`] and no
+    /// limit on reconnect attempts.
+    pub fn new(connect: F) -> Self {
+        Self {
+            connect,
+            policy: ReconnectPolicy::default(),
+            max_attempts: None,
+            current: None,
+            last_event_id: None,
+            retry: None,
+            attempt: 0,
+            failures: 0,
+            connected_once: false,
+            done: false,
+        }
+    }
+
+    /// Backoff used between reconnect attempts.
+    #[must_use]
+    pub fn with_policy(mut self, policy: ReconnectPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Give up after `n` consecutive failed `connect` calls, yielding the
+    /// last error.
+    #[must_use]
+    pub fn with_max_attempts(mut self, n: u32) -> Self {
+        self.max_attempts = Some(n);
+        self
+    }
+
+    /// The latest `id:` value seen across all connections; passed to
+    /// `connect` on reconnect.
+    #[must_use]
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.last_event_id.as_deref()
+    }
+
+    /// The next event, reconnecting as needed.
+    ///
+    /// Stream errors and dropped connections are not yielded; they trigger a
+    /// reconnect. Returns `Some(Err(_))` once before ending when:
+    /// - `connect` failed as many times in a row as allowed by
+    ///   [`with_max_attempts`](Self::with_max_attempts) — the gateway error
+    ///   is wrapped in [`StreamingError::Stream`];
+    /// - the upstream replied with something other than an SSE stream —
+    ///   [`StreamingError::ServerEventsUnexpectedResponse`].
+    ///
+    /// Must be polled within a Tokio runtime with the time driver enabled.
+    pub async fn next(&mut self) -> Option<Result<ServerEvent, StreamingError>> {
+        loop {
+            if self.done {
+                return None;
+            }
+            if let Some(stream) = &mut self.current {
+                let item = stream.next().await;
+                self.retry = stream.current_retry().or(self.retry);
+                if let Some(id) = stream.last_event_id()
+                    && self.last_event_id.as_deref() != Some(id)
+                {
+                    self.last_event_id = Some(id.to_owned());
+                }
+                if let Some(Ok(event)) = item {
+                    self.attempt = 0;
+                    return Some(Ok(event));
+                }
+                self.current = None;
+            }
+
+            if self.connected_once {
+                tokio::time::sleep(self.policy.delay(self.attempt, self.retry)).await;
+                self.attempt = self.attempt.saturating_add(1);
+            }
+            self.connected_once = true;
+            match (self.connect)(self.last_event_id.clone()).await {
+                Ok(resp) => {
+                    self.failures = 0;
+                    match ServerEventsStream::from_response::<ServerEvent>(resp) {
+                        ServerEventsResponse::Events(stream) => self.current = Some(stream),
+                        ServerEventsResponse::Response(resp) => {
+                            self.done = true;
+                            return Some(Err(StreamingError::ServerEventsUnexpectedResponse {
+                                status: resp.status(),
+                            }));
+                        }
+                    }
+                }
+                Err(err) => {
+                    self.failures = self.failures.saturating_add(1);
+                    if self.max_attempts.is_some_and(|max| self.failures >= max) {
+                        self.done = true;
+                        return Some(Err(StreamingError::Stream(Box::new(err))));
+                    }
+                }
+            }
+        }
+    }
+}
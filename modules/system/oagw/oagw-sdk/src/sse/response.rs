@@ -1,28 +1,66 @@
 use std::pin::Pin;
+use std::time::Duration;
 
 use axum::body::Body;
 use bytes::Bytes;
 use futures_core::Stream;
-use futures_util::StreamExt;
+use futures_util::future::{self, Either};
+use futures_util::{StreamExt, stream};
+use tokio::time::Instant;
 
 use crate::error::StreamingError;
 use crate::sse::ServerEvent;
 
+/// Comment sent by [`server_events_response_with_keepalive`]; clients ignore
+/// it, proxies see traffic.
+const KEEPALIVE: &[u8] = b": keepalive\n\n";
+
 /// Build an axum Response that streams SSE events to the client.
 ///
 /// Sets `Content-Type: text/event-stream`, `Cache-Control: no-cache`,
 /// `Connection: keep-alive`, and `X-Accel-Buffering: no` (to prevent
 /// reverse-proxy buffering). Each [`ServerEvent`] is serialized into the
-/// SSE wire format.
+/// SSE wire format; an event whose `id` or `event` contains a line break
+/// ends the body with [`StreamingError::ServerEventsSerialize`].
 #[allow(clippy::type_complexity)]
 pub fn server_events_response(
     events: Pin<Box<dyn Stream<Item = Result<ServerEvent, StreamingError>> + Send>>,
 ) -> http::Response<Body> {
-    let byte_stream = events.map(|result| {
-        result
-            .map(|event| serialize_event(&event))
-            .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    let byte_stream = events.map(|result| result.and_then(|event| event.to_wire()));
+    sse_body_response(byte_stream)
+}
+
+/// Like [`server_events_response`], but writes a `: keepalive` comment
+/// whenever `events` has been quiet for `interval`, so reverse proxies and
+/// load balancers do not drop the idle connection.
+///
+/// The timer restarts after every event; heartbeats stop once `events`
+/// completes.
+#[allow(clippy::type_complexity)]
+pub fn server_events_response_with_keepalive(
+    events: Pin<Box<dyn Stream<Item = Result<ServerEvent, StreamingError>> + Send>>,
+    interval: Duration,
+) -> http::Response<Body> {
+    let tick = Box::pin(tokio::time::sleep(interval));
+    let byte_stream = stream::unfold((events, tick), move |(mut events, mut tick)| async move {
+        // `select` polls `events` first, so a ready event wins over the tick.
+        let bytes = match future::select(events.next(), tick.as_mut()).await {
+            Either::Left((item, _)) => item?.and_then(|event| event.to_wire()),
+            Either::Right(((), _)) => Ok(Bytes::from_static(KEEPALIVE)),
+        };
+        tick.as_mut().reset(Instant::now() + interval);
+        Some((bytes, (events, tick)))
     });
+    sse_body_response(byte_stream)
+}
+
+/// Wrap serialized SSE bytes in a response carrying the SSE headers.
+fn sse_body_response<S>(byte_stream: S) -> http::Response<Body>
+where
+    S: Stream<Item = Result<Bytes, StreamingError>> + Send + 'static,
+{
+    let byte_stream = byte_stream
+        .map(|result| result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>));
 
     http::Response::builder()
         .header(http::header::CONTENT_TYPE, "text/event-stream")
@@ -33,69 +71,137 @@ pub fn server_events_response(
         .expect("SSE response builder should not fail")
 }
 
-/// Serialize an SSE event into wire format bytes.
-fn serialize_event(event: &ServerEvent) -> Bytes {
-    let mut buf = String::new();
-    if let Some(ref id) = event.id {
-        buf.push_str("id: ");
-        buf.push_str(id);
-        buf.push('\n');
-    }
-    if let Some(ref event_type) = event.event {
-        buf.push_str("event: ");
-        buf.push_str(event_type);
-        buf.push('\n');
-    }
-    if let Some(retry) = event.retry {
-        buf.push_str("retry: ");
-        buf.push_str(&retry.to_string());
-        buf.push('\n');
-    }
-    // Each line of data gets its own "data:" prefix.
-    for line in event.data.split('\n') {
-        buf.push_str("data: ");
-        buf.push_str(line);
-        buf.push('\n');
-    }
-    buf.push('\n'); // Blank line terminates the event.
-    Bytes::from(buf)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::sse::parse::normalize_line_endings;
 
     #[test]
-    fn serialize_data_only() {
-        let event = ServerEvent {
-            data: "hello".into(),
-            ..Default::default()
-        };
-        let bytes = serialize_event(&event);
-        assert_eq!(bytes.as_ref(), b"data: hello\n\n");
+    fn binary_event_round_trips_through_decoder() {
+        let payload: Vec<u8> = (0..=255).collect();
+        let bytes = ServerEvent::default()
+            .with_binary_data(&payload)
+            .to_wire()
+            .unwrap();
+        assert!(
+            std::str::from_utf8(&bytes)
+                .unwrap()
+                .starts_with("data-encoding: base64\ndata: ")
+        );
+
+        let mut decoder = crate::sse::SseDecoder::new().with_binary_data(true);
+        let events = decoder.push(&bytes).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].binary_data().unwrap().as_ref(),
+            payload.as_slice()
+        );
     }
 
     #[test]
-    fn serialize_all_fields() {
-        let event = ServerEvent {
-            id: Some("42".into()),
-            event: Some("update".into()),
-            data: "payload".into(),
-            retry: Some(3000),
-        };
-        let bytes = serialize_event(&event);
-        let expected = "id: 42\nevent: update\nretry: 3000\ndata: payload\n\n";
-        assert_eq!(std::str::from_utf8(&bytes).unwrap(), expected);
+    fn built_events_round_trip_through_parser() {
+        for event in [
+            ServerEvent::data("hello"),
+            ServerEvent::data("line1\nline2"),
+            ServerEvent::builder()
+                .id("42")
+                .event("update")
+                .data("payload")
+                .retry(3000)
+                .build(),
+            ServerEvent::builder().id("").data("reset id").build(),
+        ] {
+            assert_eq!(round_trip(&event), [event]);
+        }
     }
 
-    #[test]
-    fn serialize_multiline_data() {
-        let event = ServerEvent {
-            data: "line1\nline2\nline3".into(),
-            ..Default::default()
-        };
-        let bytes = serialize_event(&event);
-        let expected = "data: line1\ndata: line2\ndata: line3\n\n";
-        assert_eq!(std::str::from_utf8(&bytes).unwrap(), expected);
+    #[tokio::test]
+    async fn invalid_event_ends_response_body_with_error() {
+        let events = futures_util::stream::iter(vec![
+            Ok(ServerEvent {
+                data: "ok".into(),
+                ..Default::default()
+            }),
+            Ok(ServerEvent {
+                event: Some("a\nb".into()),
+                ..Default::default()
+            }),
+        ]);
+        let response = server_events_response(Box::pin(events));
+        let err = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("line breaks"), "got: {err}");
+    }
+
+    #[tokio::test]
+    async fn keepalive_fills_gaps_and_stops_with_source() {
+        let events = futures_util::stream::iter([Ok(ServerEvent::data("first"))]).chain(
+            futures_util::stream::once(async {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                Ok(ServerEvent::data("second"))
+            }),
+        );
+        let response =
+            server_events_response_with_keepalive(Box::pin(events), Duration::from_millis(20));
+        assert_eq!(
+            response.headers().get(http::header::CONTENT_TYPE).unwrap(),
+            "text/event-stream"
+        );
+
+        // Completes only if the heartbeat stops with the source.
+        let body = tokio::time::timeout(
+            Duration::from_secs(5),
+            axum::body::to_bytes(response.into_body(), usize::MAX),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        let body = std::str::from_utf8(&body).unwrap();
+        let gap = body
+            .strip_prefix("data: first\n\n")
+            .and_then(|rest| rest.strip_suffix("data: second\n\n"))
+            .unwrap_or_else(|| panic!("unexpected body: {body:?}"));
+        assert!(!gap.is_empty(), "expected a keepalive during the gap");
+        assert_eq!(gap.replace(": keepalive\n\n", ""), "", "got: {gap:?}");
+    }
+
+    /// Serialize a single event and parse it back with the SDK parser.
+    fn round_trip(event: &ServerEvent) -> Vec<ServerEvent> {
+        let chunk: Result<Bytes, crate::body::BoxError> = Ok(event.to_wire().unwrap());
+        let body: crate::body::BodyStream = Box::pin(futures_util::stream::iter(vec![chunk]));
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(
+                crate::sse::parse_server_events_stream(body)
+                    .map(Result::unwrap)
+                    .collect::<Vec<_>>(),
+            )
+    }
+
+    proptest::proptest! {
+        #![proptest_config(proptest::prelude::ProptestConfig {
+            failure_persistence: None,
+            ..proptest::prelude::ProptestConfig::default()
+        })]
+        #[test]
+        fn data_round_trips_through_parser(data in "[a-z \r\n:€]{0,40}") {
+            let parsed = round_trip(&ServerEvent {
+                data: data.clone(),
+                ..Default::default()
+            });
+
+            // The parser normalizes line endings and skips leading empty
+            // `data:` lines (see `empty_data_value` in the parser tests).
+            let normalized = normalize_line_endings(&data);
+            let expected = normalized.trim_start_matches('\n');
+            if expected.is_empty() {
+                proptest::prop_assert!(parsed.is_empty());
+            } else {
+                proptest::prop_assert_eq!(parsed.len(), 1);
+                proptest::prop_assert_eq!(parsed[0].data.as_str(), expected);
+            }
+        }
     }
 }
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use futures_core::Stream;
+use futures_util::StreamExt;
+
+use crate::error::StreamingError;
+use crate::sse::ServerEvent;
+
+/// Event type of events sent without an `event:` line, per the W3C spec.
+const DEFAULT_EVENT_TYPE: &str = "message";
+
+type HandlerFn<E> = dyn FnMut(ServerEvent) -> Result<(), E> + Send;
+
+/// Dispatches SSE events to handlers registered by event name.
+///
+/// Replaces a `match` on [`ServerEvent::event`] in consumers that handle many
+/// event types. Events sent without an `event:` line have the type
+/// `message`, as in the browser `EventSource` API. Events with no matching
+/// handler go to the [`on_default`](Self::on_default) handler, or are
+/// skipped when there is none.
+///
+/// Handlers return `Result<(), E>`; stream errors are converted into `E`
+/// with `From<StreamingError>`, so [`run`](Self::run) surfaces both kinds.
+/// [`new`](ServerEventRouter::new) uses `E = StreamingError`.
+///
+/// ```ignore
+/// ServerEventRouter::new()
+///     .on("progress", |ev| { println!("{}", ev.data); Ok(()) })
+///     .on("done", |_| Ok(()))
+///     .on_default(|ev| { tracing::debug!(?ev.event, "unhandled"); Ok(()) })
+///     .run(events)
+///     .await?;
+/// ```
+pub struct ServerEventRouter<E = StreamingError> {
+    handlers: HashMap<String, Box<HandlerFn<E>>>,
+    default: Option<Box<HandlerFn<E>>>,
+}
+
+impl<E> std::fmt::Debug for ServerEventRouter<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut names: Vec<_> = self.handlers.keys().collect();
+        names.sort();
+        f.debug_struct("ServerEventRouter")
+            .field("handlers", &names)
+            .field("default", &self.default.is_some())
+            .finish()
+    }
+}
+
+impl<E> Default for ServerEventRouter<E> {
+    fn default() -> Self {
+        Self {
+            handlers: HashMap::new(),
+            default: None,
+        }
+    }
+}
+
+impl ServerEventRouter {
+    /// A router whose handlers fail with [`StreamingError`]. For another
+    /// error type use `ServerEventRouter::<E>::default()`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<E> ServerEventRouter<E> {
+    /// Handle events of type `name`, replacing any handler registered for it.
+    #[must_use]
+    pub fn on(
+        mut self,
+        name: impl Into<String>,
+        handler: impl FnMut(ServerEvent) -> Result<(), E> + Send + 'static,
+    ) -> Self {
+        self.handlers.insert(name.into(), Box::new(handler));
+        self
+    }
+
+    /// Handle events that no [`on`](Self::on) handler matches.
+    #[must_use]
+    pub fn on_default(
+        mut self,
+        handler: impl FnMut(ServerEvent) -> Result<(), E> + Send + 'static,
+    ) -> Self {
+        self.default = Some(Box::new(handler));
+        self
+    }
+
+    /// Pass one event to its handler.
+    ///
+    /// # Errors
+    ///
+    /// Returns the handler's error.
+    pub fn dispatch(&mut self, event: ServerEvent) -> Result<(), E> {
+        let name = event.event.as_deref().unwrap_or(DEFAULT_EVENT_TYPE);
+        match self.handlers.get_mut(name) {
+            Some(handler) => handler(event),
+            None => self
+                .default
+                .as_mut()
+                .map_or(Ok(()), |handler| handler(event)),
+        }
+    }
+
+    /// Drive `events` to completion, dispatching each event.
+    ///
+    /// # Errors
+    ///
+    /// Stops at and returns the first handler error or stream error; the
+    /// rest of the stream is dropped unread.
+    pub async fn run<S>(&mut self, events: S) -> Result<(), E>
+    where
+        S: Stream<Item = Result<ServerEvent, StreamingError>>,
+        E: From<StreamingError>,
+    {
+        let mut events = std::pin::pin!(events);
+        while let Some(event) = events.next().await {
+            self.dispatch(event?)?;
+        }
+        Ok(())
+    }
+}
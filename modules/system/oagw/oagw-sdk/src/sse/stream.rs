@@ -1,14 +1,25 @@
+use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::task::{Context, Poll};
+use std::time::Duration;
 
 use futures_core::Stream;
-use futures_util::StreamExt;
+use futures_util::{StreamExt, future, stream};
 use http::{HeaderMap, StatusCode};
 
 use crate::body::Body;
 use crate::codec::Json;
+use crate::content_type::StreamingContentTypes;
 use crate::error::StreamingError;
-use crate::sse::{ServerEvent, is_server_events_response, parse_server_events_stream};
+use crate::sse::{
+    ParseActivity, ServerEvent, SseDecoder, is_server_events_response, is_sniffable_content_type,
+    looks_like_server_events, parse_server_events_stream_tracked,
+};
+
+/// Bytes read from the body when sniffing for SSE: enough for a BOM plus the
+/// longest recognised field name (`event:`).
+const SNIFF_LEN: usize = 16;
 
 /// Trait for types that can be extracted from an SSE event.
 ///
@@ -38,6 +49,68 @@ where
     }
 }
 
+/// Trait for types that can be written as an SSE event — the inverse of
+/// [`FromServerEvent`].
+///
+/// Used by [`ServerEventsStream::into_response`] to serialize typed items back
+/// onto the wire.
+pub trait ToServerEvent {
+    /// Convert `self` into the event to emit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::ServerEventsSerialize`] if `self` cannot be
+    /// represented as an event.
+    fn into_server_event(self) -> Result<ServerEvent, StreamingError>;
+}
+
+/// Pass-through: raw `ServerEvent` requires no conversion.
+impl ToServerEvent for ServerEvent {
+    fn into_server_event(self) -> Result<ServerEvent, StreamingError> {
+        Ok(self)
+    }
+}
+
+/// Emits the JSON encoding of the wrapped value as the event's `data`.
+impl<T: serde::Serialize> ToServerEvent for Json<T> {
+    fn into_server_event(self) -> Result<ServerEvent, StreamingError> {
+        serde_json::to_string(&self.0)
+            .map(ServerEvent::data)
+            .map_err(|e| StreamingError::ServerEventsSerialize {
+                detail: e.to_string(),
+            })
+    }
+}
+
+/// Decodes [`binary_data`](ServerEvent::binary_data) — base64 when the event
+/// is marked binary, otherwise the raw `data` bytes.
+#[cfg(feature = "msgpack")]
+impl<T> FromServerEvent for crate::codec::MsgPack<T>
+where
+    T: serde::de::DeserializeOwned + Send + 'static,
+{
+    fn from_server_event(event: ServerEvent) -> Result<Self, StreamingError> {
+        let bytes = event.binary_data()?;
+        rmp_serde::from_slice(&bytes)
+            .map(crate::codec::MsgPack)
+            .map_err(|e| StreamingError::ServerEventsParse {
+                detail: format!("invalid MessagePack payload: {e}"),
+            })
+    }
+}
+
+/// Emits the MessagePack encoding of the wrapped value as base64 `data`.
+#[cfg(feature = "msgpack")]
+impl<T: serde::Serialize> ToServerEvent for crate::codec::MsgPack<T> {
+    fn into_server_event(self) -> Result<ServerEvent, StreamingError> {
+        rmp_serde::to_vec_named(&self.0)
+            .map(|bytes| ServerEvent::default().with_binary_data(bytes))
+            .map_err(|e| StreamingError::ServerEventsSerialize {
+                detail: e.to_string(),
+            })
+    }
+}
+
 /// The result of trying to interpret an HTTP response as a server-sent events stream.
 ///
 /// Both variants are valid outcomes — use `match` to handle the streaming
@@ -60,24 +133,163 @@ pub enum ServerEventsResponse<T: FromServerEvent = ServerEvent> {
     Response(http::Response<Body>),
 }
 
+/// How [`ServerEventsStream::from_response`] would treat a response, as
+/// reported by [`ServerEventsStream::inspect_response`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseKind {
+    /// `Content-Type: text/event-stream` — `from_response` yields
+    /// [`ServerEventsResponse::Events`].
+    Sse,
+    /// Any other content type — `from_response` returns the response
+    /// unchanged. Responses without a content type may still be detected by
+    /// [`from_response_sniffing`](ServerEventsStream::from_response_sniffing).
+    Other,
+}
+
+impl ResponseKind {
+    /// Returns `true` for [`ResponseKind::Sse`].
+    #[must_use]
+    pub fn is_sse(self) -> bool {
+        self == ResponseKind::Sse
+    }
+
+    /// Returns `true` for [`ResponseKind::Other`].
+    #[must_use]
+    pub fn is_other(self) -> bool {
+        self == ResponseKind::Other
+    }
+}
+
+/// What counts as activity for [`ServerEventsStream::with_idle_timeout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IdleActivity {
+    /// Any bytes received from the upstream reset the timer, including
+    /// keep-alive comments and partial events.
+    #[default]
+    AnyBytes,
+    /// Complete events and comment-only blocks (heartbeats) reset the timer;
+    /// partial data does not.
+    EventsAndComments,
+    /// Only complete events reset the timer. Keep-alive comments are not
+    /// treated as liveness, so a stream that only sends heartbeats times out.
+    EventsOnly,
+}
+
+impl IdleActivity {
+    /// Current value of the activity counter this mode observes.
+    fn observed(self, activity: &ParseActivity) -> u64 {
+        match self {
+            IdleActivity::AnyBytes => activity.chunks(),
+            IdleActivity::EventsAndComments => activity.comment_blocks(),
+            IdleActivity::EventsOnly => 0,
+        }
+    }
+}
+
+/// When a stream built by [`ServerEventsStream::merge_with`] ends.
+///
+/// Under either policy the first error from any source is yielded and then
+/// the merged stream ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergePolicy {
+    /// Sources that end drop out; the merged stream ends once all have ended.
+    #[default]
+    UntilAllEnd,
+    /// The merged stream ends as soon as any source ends.
+    UntilFirstEnd,
+}
+
+/// Parse counters returned by [`ServerEventsStream::stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SseStats {
+    /// Events decoded, including any not yet yielded.
+    pub events: u64,
+    /// Raw body bytes read, before decoding and line-ending normalization.
+    /// With the `sse-compression` feature, counted after decompression.
+    pub bytes: u64,
+    /// Errors produced while decoding or reading the body.
+    pub errors: u64,
+}
+
+/// Stream-level state taken from events as they are yielded.
+#[derive(Default)]
+struct Observed {
+    /// Latest server-provided `retry:` value.
+    retry: parking_lot::Mutex<Option<Duration>>,
+    /// Latest `id:` value, including an explicit empty one.
+    last_event_id: parking_lot::Mutex<Option<String>>,
+    /// `data` value that ends the stream, set by
+    /// [`ServerEventsStream::stop_on`].
+    stop_on: parking_lot::Mutex<Option<String>>,
+}
+
+impl Observed {
+    fn record(&self, event: &ServerEvent) {
+        if let Some(ms) = event.retry {
+            *self.retry.lock() = Some(Duration::from_millis(ms));
+        }
+        if let Some(id) = &event.id {
+            *self.last_event_id.lock() = Some(id.clone());
+        }
+    }
+
+    /// Whether `event` is the configured end-of-stream sentinel.
+    fn is_sentinel(&self, event: &ServerEvent) -> bool {
+        self.stop_on.lock().as_deref() == Some(event.data.as_str())
+    }
+
+    /// Copy the latest id into `local` if it changed.
+    fn sync_last_event_id(&self, local: &mut Option<String>) {
+        let latest = self.last_event_id.lock();
+        if *local != *latest {
+            local.clone_from(&latest);
+        }
+    }
+}
+
+struct IdleTimer {
+    timeout: Duration,
+    sleep: Pin<Box<tokio::time::Sleep>>,
+    /// Activity counter value when the timer was last reset.
+    seen: u64,
+}
+
+impl IdleTimer {
+    fn reset(&mut self) {
+        self.sleep
+            .as_mut()
+            .reset(tokio::time::Instant::now() + self.timeout);
+    }
+}
+
 /// A stream of server-sent events extracted from an HTTP response.
 ///
 /// Generic over the event type `T`:
 /// - `ServerEventsStream<ServerEvent>` (default) — yields raw parsed events.
 /// - `ServerEventsStream<YourType>` — yields events deserialized via
-///   [`FromServerEvent`].
+///   [`FromServerEvent`], or transformed with
+///   [`map_ok`](ServerEventsStream::map_ok).
 ///
 /// Created via [`from_response`](ServerEventsStream::from_response), which
 /// checks the `Content-Type` header and returns a [`ServerEventsResponse`]
 /// — either an event stream or the original response unchanged.
 #[allow(clippy::type_complexity)]
-pub struct ServerEventsStream<T: FromServerEvent = ServerEvent> {
+pub struct ServerEventsStream<T = ServerEvent> {
     inner: Pin<Box<dyn Stream<Item = Result<T, StreamingError>> + Send>>,
     status: StatusCode,
     headers: HeaderMap,
+    activity: Arc<ParseActivity>,
+    observed: Arc<Observed>,
+    /// [`Observed::last_event_id`] as of the last yielded item, kept here so
+    /// it can be borrowed.
+    last_event_id: Option<String>,
+    idle_activity: IdleActivity,
+    idle: Option<IdleTimer>,
+    /// Set once the idle timeout fired; the stream is finished.
+    timed_out: bool,
 }
 
-impl<T: FromServerEvent> std::fmt::Debug for ServerEventsStream<T> {
+impl<T> std::fmt::Debug for ServerEventsStream<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ServerEventsStream")
             .field("status", &self.status)
@@ -86,6 +298,20 @@ impl<T: FromServerEvent> std::fmt::Debug for ServerEventsStream<T> {
 }
 
 impl ServerEventsStream {
+    /// Classify a response without consuming it, using the same check as
+    /// [`from_response`](Self::from_response).
+    ///
+    /// Lets middleware log or branch on status and headers before committing
+    /// to the SSE or fallback path.
+    #[must_use]
+    pub fn inspect_response<B>(resp: &http::Response<B>) -> ResponseKind {
+        if is_server_events_response(resp.headers()) {
+            ResponseKind::Sse
+        } else {
+            ResponseKind::Other
+        }
+    }
+
     /// Try to interpret an HTTP response as a server-sent events stream.
     ///
     /// Returns [`ServerEventsResponse::Events`] if the response has
@@ -97,25 +323,177 @@ impl ServerEventsStream {
     /// losing the response.
     pub fn from_response<T: FromServerEvent>(
         resp: impl Into<http::Response<Body>>,
+    ) -> ServerEventsResponse<T> {
+        Self::from_response_with_decoder(resp, SseDecoder::new())
+    }
+
+    /// Like [`from_response`](Self::from_response), but parses the body with
+    /// a caller-configured [`SseDecoder`] — e.g. one built with
+    /// [`with_extra_fields`](SseDecoder::with_extra_fields) to capture vendor
+    /// fields.
+    pub fn from_response_with_decoder<T: FromServerEvent>(
+        resp: impl Into<http::Response<Body>>,
+        decoder: SseDecoder,
     ) -> ServerEventsResponse<T> {
         let resp = resp.into();
         if !is_server_events_response(resp.headers()) {
             return ServerEventsResponse::Response(resp);
         }
+        Self::events_from(resp, decoder)
+    }
+
+    /// Like [`from_response`](Self::from_response), but decides whether the
+    /// response is SSE with deployment-configured `content_types` instead of
+    /// the default `text/event-stream` check.
+    ///
+    /// A response whose content type matches an NDJSON rule (or none) is
+    /// returned as [`ServerEventsResponse::Response`].
+    pub fn from_response_with_content_types<T: FromServerEvent>(
+        resp: impl Into<http::Response<Body>>,
+        content_types: &StreamingContentTypes,
+    ) -> ServerEventsResponse<T> {
+        let resp = resp.into();
+        if !content_types.is_server_events(resp.headers()) {
+            return ServerEventsResponse::Response(resp);
+        }
+        Self::events_from(resp, SseDecoder::new())
+    }
+
+    /// Like [`from_response`](Self::from_response), but also recognises SSE
+    /// from upstreams that omit the content type.
+    ///
+    /// When `Content-Type` is absent or `application/octet-stream`, the first
+    /// bytes of the body are peeked and the response is treated as SSE if the
+    /// first line starts with `data:`, `event:`, `id:` or a `:` comment. Any
+    /// other content type is handled exactly like `from_response`.
+    ///
+    /// The body is reconstructed after peeking, so no bytes are lost whichever
+    /// variant is returned. This is opt-in because sniffing can misclassify a
+    /// non-SSE body that happens to start with one of those prefixes.
+    pub async fn from_response_sniffing<T: FromServerEvent>(
+        resp: impl Into<http::Response<Body>>,
+    ) -> ServerEventsResponse<T> {
+        let resp = resp.into();
+        if is_server_events_response(resp.headers()) {
+            return Self::events_from(resp, SseDecoder::new());
+        }
+        if !is_sniffable_content_type(resp.headers()) {
+            return ServerEventsResponse::Response(resp);
+        }
 
         let (parts, body) = resp.into_parts();
-        let event_stream = parse_server_events_stream(body.into_stream());
-        let mapped = event_stream.map(|r| r.and_then(T::from_server_event));
+        let (prefix, body) = body.peek(SNIFF_LEN).await;
+        let resp = http::Response::from_parts(parts, body);
+        if looks_like_server_events(&prefix) {
+            Self::events_from(resp, SseDecoder::new())
+        } else {
+            ServerEventsResponse::Response(resp)
+        }
+    }
+
+    fn events_from<T: FromServerEvent>(
+        resp: http::Response<Body>,
+        decoder: SseDecoder,
+    ) -> ServerEventsResponse<T> {
+        let (parts, body) = resp.into_parts();
+        let body = body.into_stream();
+        #[cfg(feature = "sse-compression")]
+        let body = crate::sse::decompress::decode_body(&parts.headers, body);
+        let activity = Arc::new(ParseActivity::default());
+        let event_stream = parse_server_events_stream_tracked(body, decoder, Arc::clone(&activity));
+        let observed = Arc::new(Observed::default());
+        let sentinel = Arc::clone(&observed);
+        let recorder = Arc::clone(&observed);
+        let mapped = event_stream
+            .take_while(move |r| {
+                future::ready(!matches!(r, Ok(event) if sentinel.is_sentinel(event)))
+            })
+            .map(move |r| {
+                r.and_then(|event| {
+                    recorder.record(&event);
+                    T::from_server_event(event)
+                })
+            });
 
         ServerEventsResponse::Events(ServerEventsStream {
             inner: Box::pin(mapped),
             status: parts.status,
             headers: parts.headers,
+            activity,
+            observed,
+            last_event_id: None,
+            idle_activity: IdleActivity::default(),
+            idle: None,
+            timed_out: false,
         })
     }
 }
 
-impl<T: FromServerEvent> ServerEventsStream<T> {
+impl<T: Send + 'static> ServerEventsStream<T> {
+    /// Interleave events from several streams as they arrive (fan-in).
+    ///
+    /// Equivalent to [`merge_with`](Self::merge_with) using
+    /// [`MergePolicy::UntilAllEnd`] and the first stream's status and headers.
+    #[must_use]
+    pub fn merge(streams: Vec<ServerEventsStream<T>>) -> Self {
+        Self::merge_with(streams, MergePolicy::default(), 0)
+    }
+
+    /// Interleave events from several streams as they arrive, ending as set
+    /// by `policy`.
+    ///
+    /// Status, headers and [`current_retry`](Self::current_retry) are taken
+    /// from `streams[head_from]`; if there is no such stream the result
+    /// reports `200 OK` with no headers. Each source keeps its own idle
+    /// timeout; one set on the merged stream is reset only by yielded events.
+    #[must_use]
+    pub fn merge_with(
+        streams: Vec<ServerEventsStream<T>>,
+        policy: MergePolicy,
+        head_from: usize,
+    ) -> Self {
+        let (status, headers, observed) = streams.get(head_from).map_or_else(
+            || (StatusCode::OK, HeaderMap::new(), Arc::default()),
+            |head| {
+                (
+                    head.status,
+                    head.headers.clone(),
+                    Arc::clone(&head.observed),
+                )
+            },
+        );
+        // Each source reports its end as a trailing `None`, so the policy
+        // can react to individual sources finishing.
+        let sources = stream::select_all(
+            streams
+                .into_iter()
+                .map(|s| s.map(Some).chain(stream::once(future::ready(None)))),
+        );
+        let merged = stream::unfold(Some(sources), move |sources| async move {
+            let mut sources = sources?;
+            loop {
+                match sources.next().await? {
+                    Some(Ok(event)) => return Some((Ok(event), Some(sources))),
+                    Some(Err(e)) => return Some((Err(e), None)),
+                    None if policy == MergePolicy::UntilFirstEnd => return None,
+                    None => {}
+                }
+            }
+        });
+
+        ServerEventsStream {
+            inner: Box::pin(merged),
+            status,
+            headers,
+            activity: Arc::new(ParseActivity::default()),
+            observed,
+            last_event_id: None,
+            idle_activity: IdleActivity::default(),
+            idle: None,
+            timed_out: false,
+        }
+    }
+
     /// The HTTP status code of the original response.
     #[must_use]
     pub fn status(&self) -> StatusCode {
@@ -127,26 +505,331 @@ impl<T: FromServerEvent> ServerEventsStream<T> {
     pub fn headers(&self) -> &HeaderMap {
         &self.headers
     }
+
+    /// The raw `Content-Type` value of the original response, including any
+    /// parameters (e.g. `text/event-stream; charset=utf-8`).
+    ///
+    /// Returns `None` if the header is absent or not valid visible ASCII.
+    #[must_use]
+    pub fn content_type(&self) -> Option<&str> {
+        self.headers
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+    }
+
+    /// The latest reconnection time sent by the server in a `retry:` field.
+    ///
+    /// Updated as events are yielded and kept until a newer `retry:` arrives,
+    /// including across events of type `T` that drop the field. `None` until
+    /// the server sends one. Feed it to [`ReconnectPolicy::delay`] so the
+    /// server's backoff guidance overrides the client-side base.
+    ///
+    /// [`ReconnectPolicy::delay`]: crate::sse::ReconnectPolicy::delay
+    #[must_use]
+    pub fn current_retry(&self) -> Option<Duration> {
+        *self.observed.retry.lock()
+    }
+
+    /// The reconnection time to wait before reconnecting, as last suggested
+    /// by the server; the same value as [`current_retry`](Self::current_retry).
+    ///
+    /// Non-numeric `retry:` fields are ignored and leave the previous value
+    /// in place, as browsers do.
+    #[must_use]
+    pub fn suggested_retry(&self) -> Option<Duration> {
+        self.current_retry()
+    }
+
+    /// Parse counters so far, updated as the stream is polled.
+    ///
+    /// Counts reflect the parser, not what this stream yields: events cut
+    /// off by [`take_events`](Self::take_events) may already be counted. A
+    /// stream built by [`merge`](Self::merge) has no parser of its own and
+    /// reports zeros.
+    #[must_use]
+    pub fn stats(&self) -> SseStats {
+        SseStats {
+            events: self.activity.events(),
+            bytes: self.activity.bytes(),
+            errors: self.activity.errors(),
+        }
+    }
+
+    /// The latest `id:` value sent by the server, to send back as the
+    /// `Last-Event-ID` header when reconnecting.
+    ///
+    /// Updated as events are yielded and kept when later events omit `id`.
+    /// An explicit empty `id:` resets it to `Some("")`, per the W3C spec.
+    /// `None` until the server sends one.
+    #[must_use]
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.last_event_id.as_deref()
+    }
+
+    /// Fail the stream if no activity is seen for `timeout`.
+    ///
+    /// When the window elapses, the stream yields
+    /// [`StreamingError::IdleTimeout`] once and then ends. What counts as
+    /// activity is set by [`with_idle_activity`](Self::with_idle_activity);
+    /// by default any received bytes do, so keep-alive comments keep the
+    /// stream alive.
+    ///
+    /// Must be polled within a Tokio runtime with the time driver enabled.
+    #[must_use]
+    pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle = Some(IdleTimer {
+            timeout,
+            sleep: Box::pin(tokio::time::sleep(timeout)),
+            seen: self.idle_activity.observed(&self.activity),
+        });
+        self
+    }
+
+    /// Yield at most `n` successful items, then end.
+    ///
+    /// Errors are passed through without counting towards `n`; the stream
+    /// ends right after the `n`-th `Ok` item, without waiting for more input.
+    /// Unlike [`StreamExt::take`], the result is still a
+    /// `ServerEventsStream`, so [`status`](Self::status),
+    /// [`headers`](Self::headers) and the other accessors stay available.
+    /// The upstream body is dropped together with the stream.
+    #[must_use]
+    pub fn take_events(self, n: usize) -> Self {
+        let limited = stream::unfold((self.inner, n), |(mut inner, left)| async move {
+            if left == 0 {
+                return None;
+            }
+            let item = inner.next().await?;
+            let left = if item.is_ok() { left - 1 } else { left };
+            Some((item, (inner, left)))
+        });
+        Self {
+            inner: Box::pin(limited),
+            ..self
+        }
+    }
+
+    /// End the stream cleanly at the first event whose `data` equals
+    /// `sentinel`, e.g. OpenAI's `[DONE]`; the sentinel itself is not
+    /// yielded.
+    ///
+    /// The check runs on the raw event before conversion to `T`, so a
+    /// sentinel that is not valid for `T` (such as `[DONE]` for a
+    /// [`Json`] stream) never surfaces as a parse error. On a
+    /// [`merge`](Self::merge)d stream it applies only to the `head_from`
+    /// source; call it on each source before merging instead.
+    #[must_use]
+    pub fn stop_on(self, sentinel: impl Into<String>) -> Self {
+        *self.observed.stop_on.lock() = Some(sentinel.into());
+        self
+    }
+
+    /// Transform each successful item with `f`, passing errors through
+    /// untouched.
+    ///
+    /// The result is still a `ServerEventsStream`, so
+    /// [`status`](Self::status), [`headers`](Self::headers) and the other
+    /// accessors stay available; `U` need not implement [`FromServerEvent`].
+    #[must_use]
+    pub fn map_ok<U, F>(self, mut f: F) -> ServerEventsStream<U>
+    where
+        U: Send + 'static,
+        F: FnMut(T) -> U + Send + 'static,
+    {
+        self.map_inner(|inner| Box::pin(inner.map(move |r| r.map(&mut f))))
+    }
+
+    /// Like [`map_ok`](Self::map_ok), but `f` may fail; its error is yielded
+    /// in place of the item.
+    #[must_use]
+    pub fn and_then_ok<U, F>(self, mut f: F) -> ServerEventsStream<U>
+    where
+        U: Send + 'static,
+        F: FnMut(T) -> Result<U, StreamingError> + Send + 'static,
+    {
+        self.map_inner(|inner| Box::pin(inner.map(move |r| r.and_then(&mut f))))
+    }
+
+    /// Replace the item stream, keeping the response head and tracking state.
+    #[allow(clippy::type_complexity)]
+    fn map_inner<U>(
+        self,
+        f: impl FnOnce(
+            Pin<Box<dyn Stream<Item = Result<T, StreamingError>> + Send>>,
+        ) -> Pin<Box<dyn Stream<Item = Result<U, StreamingError>> + Send>>,
+    ) -> ServerEventsStream<U> {
+        ServerEventsStream {
+            inner: f(self.inner),
+            status: self.status,
+            headers: self.headers,
+            activity: self.activity,
+            observed: self.observed,
+            last_event_id: self.last_event_id,
+            idle_activity: self.idle_activity,
+            idle: self.idle,
+            timed_out: self.timed_out,
+        }
+    }
+
+    /// Choose what resets the idle timer — e.g. whether keep-alive comment
+    /// blocks count as liveness. See [`IdleActivity`].
+    ///
+    /// Has no effect unless [`with_idle_timeout`](Self::with_idle_timeout)
+    /// is also set.
+    #[must_use]
+    pub fn with_idle_activity(mut self, activity: IdleActivity) -> Self {
+        self.idle_activity = activity;
+        if let Some(idle) = &mut self.idle {
+            idle.seen = activity.observed(&self.activity);
+        }
+        self
+    }
 }
 
-#[cfg(feature = "axum")]
 impl ServerEventsStream<ServerEvent> {
+    /// Fill in `event` for events that arrived without an `event:` line.
+    ///
+    /// Events that already carry a name are left untouched. Useful when
+    /// re-emitting upstream events so that every frame sent downstream has an
+    /// explicit `event:` line. Without this transform, absent names stay
+    /// absent and serialization is a byte-exact pass-through.
+    #[must_use]
+    pub fn with_default_event(self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        let mapped = self.inner.map(move |r| {
+            r.map(|mut event| {
+                if event.event.is_none() {
+                    event.event = Some(name.clone());
+                }
+                event
+            })
+        });
+        Self {
+            inner: Box::pin(mapped),
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "axum")]
+impl<T: ToServerEvent + Send + 'static> ServerEventsStream<T> {
     /// Convert this stream into an HTTP response suitable for sending to clients.
     ///
+    /// Each item is serialized through [`ToServerEvent`]; a conversion error
+    /// ends the body like any other stream error.
+    ///
     /// Sets appropriate SSE headers:
     /// - `Content-Type: text/event-stream`
     /// - `Cache-Control: no-cache`
     /// - `Connection: keep-alive`
     /// - `X-Accel-Buffering: no` (prevents reverse-proxy buffering)
     pub fn into_response(self) -> http::Response<axum::body::Body> {
-        crate::sse::server_events_response(self.inner)
+        crate::sse::server_events_response(Box::pin(self.map(into_server_event)))
+    }
+
+    /// Like [`into_response`](Self::into_response), but writes a
+    /// `: keepalive` comment whenever no event has been sent for `interval`,
+    /// so proxies do not drop a quiet connection. Heartbeats stop when this
+    /// stream ends.
+    pub fn into_response_with_keepalive(
+        self,
+        interval: Duration,
+    ) -> http::Response<axum::body::Body> {
+        crate::sse::server_events_response_with_keepalive(
+            Box::pin(self.map(into_server_event)),
+            interval,
+        )
+    }
+
+    /// Like [`into_response`](Self::into_response), but also sends the
+    /// headers in `extra`, e.g. `x-request-id` or `x-ratelimit-*` copied from
+    /// [`headers`](Self::headers) when proxying.
+    ///
+    /// The SSE headers set by `into_response` always win: any of them present
+    /// in `extra` is ignored, with all its values.
+    pub fn into_response_with_headers(self, extra: HeaderMap) -> http::Response<axum::body::Body> {
+        let mut response = self.into_response();
+        let headers = response.headers_mut();
+        // `HeaderMap::into_iter` yields the name only with the first value of
+        // each header, so the keep/skip decision carries over to the rest.
+        let mut keep = None;
+        for (name, value) in extra {
+            if let Some(name) = name {
+                keep = (!headers.contains_key(&name)).then_some(name);
+            }
+            if let Some(name) = &keep {
+                headers.append(name, value);
+            }
+        }
+        response
     }
+
+    /// Like [`into_response`](Self::into_response), but stops as soon as
+    /// `shutdown` completes: the body ends cleanly and this stream — and with
+    /// it the upstream connection — is dropped without being polled again.
+    ///
+    /// Wire `shutdown` to the downstream connection-closed signal so an
+    /// abandoned client does not keep the upstream streaming, e.g.
+    /// `token.cancelled_owned()` for a `tokio_util` `CancellationToken`.
+    pub fn into_response_until<F>(self, shutdown: F) -> http::Response<axum::body::Body>
+    where
+        F: Future + Send + 'static,
+    {
+        let state = Some((self.map(into_server_event), Box::pin(shutdown)));
+        let events = stream::unfold(state, |state| async move {
+            let (mut events, mut shutdown) = state?;
+            match future::select(shutdown.as_mut(), events.next()).await {
+                future::Either::Left(_) => None,
+                future::Either::Right((item, _)) => {
+                    item.map(|item| (item, Some((events, shutdown))))
+                }
+            }
+        });
+        crate::sse::server_events_response(Box::pin(events))
+    }
+}
+
+#[cfg(feature = "axum")]
+fn into_server_event<T: ToServerEvent>(
+    item: Result<T, StreamingError>,
+) -> Result<ServerEvent, StreamingError> {
+    item.and_then(ToServerEvent::into_server_event)
 }
 
-impl<T: FromServerEvent> Stream for ServerEventsStream<T> {
+impl<T> Stream for ServerEventsStream<T> {
     type Item = Result<T, StreamingError>;
 
-    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.inner.as_mut().poll_next(cx)
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.timed_out {
+            return Poll::Ready(None);
+        }
+
+        if let Poll::Ready(item) = this.inner.as_mut().poll_next(cx) {
+            if item.is_some() {
+                this.observed.sync_last_event_id(&mut this.last_event_id);
+                if let Some(idle) = &mut this.idle {
+                    idle.reset();
+                }
+            }
+            return Poll::Ready(item);
+        }
+
+        let Some(idle) = &mut this.idle else {
+            return Poll::Pending;
+        };
+        // The parser may have consumed input without completing an event.
+        let observed = this.idle_activity.observed(&this.activity);
+        if observed != idle.seen {
+            idle.seen = observed;
+            idle.reset();
+        }
+        if idle.sleep.as_mut().poll(cx).is_ready() {
+            this.timed_out = true;
+            return Poll::Ready(Some(Err(StreamingError::IdleTimeout {
+                timeout: idle.timeout,
+            })));
+        }
+        Poll::Pending
     }
 }
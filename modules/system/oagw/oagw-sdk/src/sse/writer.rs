@@ -0,0 +1,73 @@
+use tokio::sync::mpsc;
+
+use crate::error::StreamingError;
+use crate::sse::{ServerEvent, server_events_response};
+
+/// Events buffered between the writer and the response body before
+/// [`SseWriter::send`] waits for the client to catch up.
+const WRITER_BUFFER_EVENTS: usize = 16;
+
+/// Push side of an SSE response created by [`sse_channel`].
+///
+/// Each [`send`](Self::send) emits one event to the client. The response
+/// ends once the writer is [closed](Self::close) or dropped.
+#[derive(Debug, Clone)]
+pub struct SseWriter {
+    tx: mpsc::Sender<Result<ServerEvent, StreamingError>>,
+}
+
+impl SseWriter {
+    /// Emit one event, waiting while the client-side buffer is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::ServerEventsSerialize`] without sending if
+    /// the event's `id` or `event` contains a line break, and
+    /// [`StreamingError::Stream`] if the response body was dropped, typically
+    /// because the client disconnected.
+    pub async fn send(&self, event: ServerEvent) -> Result<(), StreamingError> {
+        event.validate()?;
+        self.tx
+            .send(Ok(event))
+            .await
+            .map_err(|_| StreamingError::Stream("SSE client disconnected".into()))
+    }
+
+    /// Whether the response body has been dropped, so further sends fail.
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.tx.is_closed()
+    }
+
+    /// End the stream. Equivalent to dropping the writer; the response only
+    /// completes once every clone has been closed or dropped.
+    pub fn close(self) {}
+}
+
+/// Create an SSE response fed imperatively through the returned [`SseWriter`].
+///
+/// The response carries the same headers as
+/// [`ServerEventsStream::into_response`](crate::sse::ServerEventsStream::into_response).
+/// Useful for handlers that emit events in reaction to other work rather
+/// than from an existing stream:
+///
+/// ```ignore
+/// let (writer, response) = sse_channel();
+/// tokio::spawn(async move {
+///     while let Some(update) = updates.recv().await {
+///         if writer.send(update.into()).await.is_err() {
+///             break; // client went away
+///         }
+///     }
+///     writer.close();
+/// });
+/// response
+/// ```
+#[must_use]
+pub fn sse_channel() -> (SseWriter, http::Response<axum::body::Body>) {
+    let (tx, rx) = mpsc::channel(WRITER_BUFFER_EVENTS);
+    let events = futures_util::stream::unfold(rx, |mut rx| async move {
+        rx.recv().await.map(|item| (item, rx))
+    });
+    (SseWriter { tx }, server_events_response(Box::pin(events)))
+}
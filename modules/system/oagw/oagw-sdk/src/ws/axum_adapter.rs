@@ -2,6 +2,9 @@
 //!
 //! Provides conversion between `axum::extract::ws::Message` and `WebSocketMessage`,
 //! and a `split` function returning abstract `(WebSocketSink, WebSocketReceiver)`.
+//!
+//! axum reassembles fragmented messages (RFC 6455 §5.4) before yielding them,
+//! so the receiver only ever produces complete Text and Binary messages.
 
 use axum::extract::ws::{self, WebSocket};
 use futures_util::{SinkExt, StreamExt};
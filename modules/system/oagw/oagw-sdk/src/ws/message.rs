@@ -22,6 +22,30 @@ pub enum WebSocketMessage {
     Close(Option<WebSocketCloseFrame>),
 }
 
+impl WebSocketMessage {
+    /// Payload length in bytes: the UTF-8 length of Text, the length of
+    /// Binary/Ping/Pong data, and the reason length of a Close frame (0 when
+    /// it has none). Framing and the 2-byte close code are not counted.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        match self {
+            Self::Text(text) => text.len(),
+            Self::Binary(data) | Self::Ping(data) | Self::Pong(data) => data.len(),
+            Self::Close(frame) => frame.as_ref().map_or(0, |f| f.reason.len()),
+        }
+    }
+}
+
+/// Whether a data chunk came from a Text or a Binary message; see
+/// [`WebSocketStreamReceiver::into_body_stream_tagged`](crate::ws::WebSocketStreamReceiver::into_body_stream_tagged).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrameKind {
+    /// UTF-8 text message.
+    Text,
+    /// Binary message.
+    Binary,
+}
+
 /// WebSocket close frame with status code and reason.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WebSocketCloseFrame {
@@ -31,9 +55,110 @@ pub struct WebSocketCloseFrame {
     pub reason: String,
 }
 
+impl WebSocketCloseFrame {
+    /// The close status as a [`CloseCode`].
+    #[must_use]
+    pub fn close_code(&self) -> CloseCode {
+        CloseCode::from_u16(self.code)
+    }
+}
+
+/// WebSocket close status codes (RFC 6455 section 7.4.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CloseCode {
+    /// 1000: the purpose of the connection has been fulfilled.
+    Normal,
+    /// 1001: the endpoint is going away (server shutdown, page navigation).
+    GoingAway,
+    /// 1002: the peer violated the WebSocket protocol.
+    ProtocolError,
+    /// 1008: a message violated the endpoint's policy.
+    PolicyViolation,
+    /// 1009: a message was too big to process.
+    MessageTooBig,
+    /// 1011: the server hit an unexpected condition.
+    InternalError,
+    /// Any other code, as sent on the wire.
+    Other(u16),
+}
+
+impl CloseCode {
+    /// Map a wire code to its variant; unlisted codes become [`Other`](Self::Other).
+    #[must_use]
+    pub fn from_u16(code: u16) -> Self {
+        match code {
+            1000 => Self::Normal,
+            1001 => Self::GoingAway,
+            1002 => Self::ProtocolError,
+            1008 => Self::PolicyViolation,
+            1009 => Self::MessageTooBig,
+            1011 => Self::InternalError,
+            other => Self::Other(other),
+        }
+    }
+
+    /// The wire code.
+    #[must_use]
+    pub fn as_u16(self) -> u16 {
+        match self {
+            Self::Normal => 1000,
+            Self::GoingAway => 1001,
+            Self::ProtocolError => 1002,
+            Self::PolicyViolation => 1008,
+            Self::MessageTooBig => 1009,
+            Self::InternalError => 1011,
+            Self::Other(code) => code,
+        }
+    }
+}
+
 /// A sink for sending WebSocket messages.
 pub type WebSocketSink = Pin<Box<dyn Sink<WebSocketMessage, Error = StreamingError> + Send>>;
 
 /// A stream for receiving WebSocket messages.
 pub type WebSocketReceiver =
     Pin<Box<dyn Stream<Item = Result<WebSocketMessage, StreamingError>> + Send>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size_counts_payload_bytes() {
+        assert_eq!(WebSocketMessage::Text("héllo".into()).size(), 6);
+        assert_eq!(WebSocketMessage::Binary(vec![0; 4]).size(), 4);
+        assert_eq!(WebSocketMessage::Ping(vec![1, 2]).size(), 2);
+        assert_eq!(WebSocketMessage::Pong(Vec::new()).size(), 0);
+        assert_eq!(WebSocketMessage::Close(None).size(), 0);
+        let close = WebSocketMessage::Close(Some(WebSocketCloseFrame {
+            code: 1000,
+            reason: "bye".into(),
+        }));
+        assert_eq!(close.size(), 3);
+    }
+
+    #[test]
+    fn known_close_codes_round_trip() {
+        for (code, expected) in [
+            (1000, CloseCode::Normal),
+            (1001, CloseCode::GoingAway),
+            (1002, CloseCode::ProtocolError),
+            (1008, CloseCode::PolicyViolation),
+            (1009, CloseCode::MessageTooBig),
+            (1011, CloseCode::InternalError),
+        ] {
+            assert_eq!(CloseCode::from_u16(code), expected);
+            assert_eq!(expected.as_u16(), code);
+        }
+    }
+
+    #[test]
+    fn unknown_close_code_is_other() {
+        let frame = WebSocketCloseFrame {
+            code: 4001,
+            reason: "app-specific".into(),
+        };
+        assert_eq!(frame.close_code(), CloseCode::Other(4001));
+        assert_eq!(frame.close_code().as_u16(), 4001);
+    }
+}
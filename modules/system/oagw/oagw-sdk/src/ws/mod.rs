@@ -1,7 +1,17 @@
 #[cfg(feature = "axum")]
 pub mod axum_adapter;
 mod message;
+mod relay;
+mod stats;
 mod stream;
+#[cfg(feature = "tungstenite")]
+pub mod tungstenite_adapter;
 
-pub use message::{WebSocketCloseFrame, WebSocketMessage, WebSocketReceiver, WebSocketSink};
-pub use stream::{FromWebSocketMessage, WebSocketSender, WebSocketStream, WebSocketStreamReceiver};
+pub use message::{
+    CloseCode, FrameKind, WebSocketCloseFrame, WebSocketMessage, WebSocketReceiver, WebSocketSink,
+};
+pub use relay::{RelayEnd, relay};
+pub use stats::WsStats;
+pub use stream::{
+    FromWebSocketMessage, WebSocketSender, WebSocketStream, WebSocketStreamReceiver, WsEvent,
+};
@@ -0,0 +1,113 @@
+//! Bidirectional relay between two WebSocket connections.
+
+use std::pin::pin;
+
+use futures_util::future::{self, Either};
+
+use crate::error::StreamingError;
+use crate::ws::message::{WebSocketCloseFrame, WebSocketMessage};
+use crate::ws::stream::{WebSocketSender, WebSocketStream, WebSocketStreamReceiver};
+
+/// Which side ended a [`relay`], with the Close frame it sent (if any).
+///
+/// `None` means the side's stream ended without a Close frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RelayEnd {
+    /// The upstream closed first; the close was forwarded downstream.
+    UpstreamClosed(Option<WebSocketCloseFrame>),
+    /// The downstream closed first; the close was forwarded upstream.
+    DownstreamClosed(Option<WebSocketCloseFrame>),
+}
+
+/// Relay messages between `upstream` and `downstream` until either side
+/// closes.
+///
+/// Text and Binary messages are forwarded in both directions. Ping/Pong are
+/// not forwarded; each connection answers its own keep-alives. When one side
+/// sends Close (or its stream ends), the same Close frame, with its code and
+/// reason, is sent to the other side and the relay returns.
+///
+/// The two directions are pumped independently, so a slow peer on one side
+/// never blocks traffic flowing the other way.
+///
+/// # Errors
+///
+/// Returns the first receive or send error from either direction. The side
+/// that did not fail is sent a Close frame (best effort) before returning:
+/// on a receive error the peer being forwarded to, on a send error the peer
+/// whose message could not be delivered.
+pub async fn relay(
+    upstream: WebSocketStream,
+    downstream: WebSocketStream,
+) -> Result<RelayEnd, StreamingError> {
+    let (mut up_tx, mut up_rx) = upstream.split();
+    let (mut down_tx, mut down_rx) = downstream.split();
+
+    let (upstream_first, end) = {
+        let up = pin!(pump(&mut up_rx, &mut down_tx));
+        let down = pin!(pump(&mut down_rx, &mut up_tx));
+        match future::select(up, down).await {
+            Either::Left((end, _)) => (true, end),
+            Either::Right((end, _)) => (false, end),
+        }
+    };
+    match end {
+        Ok(frame) if upstream_first => Ok(RelayEnd::UpstreamClosed(frame)),
+        Ok(frame) => Ok(RelayEnd::DownstreamClosed(frame)),
+        Err(PumpError::Recv(e)) => Err(e),
+        Err(PumpError::Send(e)) => {
+            // The receiving side is broken; close the side we were reading.
+            let source = if upstream_first {
+                &mut up_tx
+            } else {
+                &mut down_tx
+            };
+            forward_close(source, None).await;
+            Err(e)
+        }
+    }
+}
+
+/// Which end of a [`pump`] failed.
+enum PumpError {
+    /// Reading from `from` failed; `to` has already been closed.
+    Recv(StreamingError),
+    /// Writing to `to` failed; `from`'s peer is still open.
+    Send(StreamingError),
+}
+
+/// Forward data frames from `from` to `to` until `from` closes.
+///
+/// Returns the Close frame received from `from`, after forwarding it.
+async fn pump(
+    from: &mut WebSocketStreamReceiver,
+    to: &mut WebSocketSender,
+) -> Result<Option<WebSocketCloseFrame>, PumpError> {
+    loop {
+        match from.recv_raw().await {
+            Some(Ok(msg @ (WebSocketMessage::Text(_) | WebSocketMessage::Binary(_)))) => {
+                to.send_raw(msg).await.map_err(PumpError::Send)?;
+            }
+            Some(Ok(WebSocketMessage::Ping(_) | WebSocketMessage::Pong(_))) => {}
+            Some(Ok(WebSocketMessage::Close(frame))) => {
+                forward_close(to, frame.clone()).await;
+                return Ok(frame);
+            }
+            None => {
+                forward_close(to, None).await;
+                return Ok(None);
+            }
+            Some(Err(e)) => {
+                forward_close(to, None).await;
+                return Err(PumpError::Recv(e));
+            }
+        }
+    }
+}
+
+/// Send a Close frame, ignoring failures: the peer may already be gone.
+async fn forward_close(to: &mut WebSocketSender, frame: Option<WebSocketCloseFrame>) {
+    if let Err(e) = to.send_raw(WebSocketMessage::Close(frame)).await {
+        tracing::debug!(error = %e, "failed to forward WebSocket close during relay");
+    }
+}
@@ -0,0 +1,75 @@
+//! Per-connection WebSocket frame counters.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::ws::message::WebSocketMessage;
+
+/// Snapshot of the frames received on a WebSocket connection, by type.
+///
+/// Obtained via [`WebSocketStream::stats`](crate::ws::WebSocketStream::stats)
+/// or either split half. Counts cover every frame pulled from the transport,
+/// including control frames that are otherwise handled transparently.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WsStats {
+    /// Text messages received.
+    pub text: u64,
+    /// Binary messages received.
+    pub binary: u64,
+    /// Ping frames received.
+    pub ping: u64,
+    /// Pong frames received.
+    pub pong: u64,
+    /// Close frames received.
+    pub close: u64,
+}
+
+impl WsStats {
+    /// Total number of frames received.
+    #[must_use]
+    pub fn total(&self) -> u64 {
+        self.text + self.binary + self.ping + self.pong + self.close
+    }
+}
+
+/// Shared counters behind [`WsStats`].
+///
+/// Held in an `Arc` so both split halves report the same connection totals.
+#[derive(Debug, Default)]
+pub(crate) struct WsCounters {
+    text: AtomicU64,
+    binary: AtomicU64,
+    ping: AtomicU64,
+    pong: AtomicU64,
+    close: AtomicU64,
+}
+
+impl WsCounters {
+    pub(crate) fn shared() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Count `msg` and hand it back, so callers can record inline while
+    /// matching on the frame.
+    pub(crate) fn record(&self, msg: WebSocketMessage) -> WebSocketMessage {
+        let counter = match &msg {
+            WebSocketMessage::Text(_) => &self.text,
+            WebSocketMessage::Binary(_) => &self.binary,
+            WebSocketMessage::Ping(_) => &self.ping,
+            WebSocketMessage::Pong(_) => &self.pong,
+            WebSocketMessage::Close(_) => &self.close,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        msg
+    }
+
+    pub(crate) fn snapshot(&self) -> WsStats {
+        WsStats {
+            text: self.text.load(Ordering::Relaxed),
+            binary: self.binary.load(Ordering::Relaxed),
+            ping: self.ping.load(Ordering::Relaxed),
+            pong: self.pong.load(Ordering::Relaxed),
+            close: self.close.load(Ordering::Relaxed),
+        }
+    }
+}
@@ -2,7 +2,9 @@
 
 use std::marker::PhantomData;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::task::{Context, Poll};
+use std::time::Duration;
 
 use bytes::Bytes;
 use futures_core::Stream;
@@ -12,8 +14,14 @@ use crate::body::{BodyStream, BoxError};
 use crate::codec::Json;
 use crate::error::StreamingError;
 use crate::ws::message::{
-    WebSocketMessage, WebSocketReceiver as RawReceiver, WebSocketSink as RawSink,
+    FrameKind, WebSocketCloseFrame, WebSocketMessage, WebSocketReceiver as RawReceiver,
+    WebSocketSink as RawSink,
 };
+use crate::ws::stats::{WsCounters, WsStats};
+
+/// Sink shared by both halves of a connection; the receive side uses it to
+/// answer Pings.
+type SharedSink = Arc<tokio::sync::Mutex<RawSink>>;
 
 // ---------------------------------------------------------------------------
 // FromWebSocketMessage trait
@@ -22,7 +30,8 @@ use crate::ws::message::{
 /// Trait for types that can be converted to/from [`WebSocketMessage`].
 ///
 /// Only Text and Binary messages reach this trait — control frames (Ping, Pong,
-/// Close) are handled transparently by [`WebSocketStream`].
+/// Close) are handled transparently by [`WebSocketStream`], which answers
+/// each Ping with a Pong.
 pub trait FromWebSocketMessage: Sized + Send + 'static {
     fn from_ws_message(msg: WebSocketMessage) -> Result<Self, StreamingError>;
     fn to_ws_message(&self) -> WebSocketMessage;
@@ -65,6 +74,79 @@ where
     }
 }
 
+/// Form-encoded serialization/deserialization for WebSocket text messages.
+///
+/// # Panics
+///
+/// `to_ws_message` panics if `T` cannot be form-encoded (e.g. it contains
+/// nested structs or maps).
+#[cfg(feature = "form")]
+impl<T> FromWebSocketMessage for crate::codec::Form<T>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + Send + 'static,
+{
+    fn from_ws_message(msg: WebSocketMessage) -> Result<Self, StreamingError> {
+        match msg {
+            WebSocketMessage::Text(text) => serde_urlencoded::from_str(&text)
+                .map(crate::codec::Form)
+                .map_err(|e| StreamingError::WebSocketBridge {
+                    detail: format!("invalid form-encoded payload: {e}"),
+                }),
+            _ => Err(StreamingError::WebSocketBridge {
+                detail: "expected Text message for form deserialization, got Binary".into(),
+            }),
+        }
+    }
+
+    fn to_ws_message(&self) -> WebSocketMessage {
+        let body = serde_urlencoded::to_string(&self.0)
+            .expect("type must be representable as form-encoded key/value pairs");
+        WebSocketMessage::Text(body)
+    }
+}
+
+/// MessagePack serialization/deserialization for WebSocket binary messages.
+///
+/// # Panics
+///
+/// `to_ws_message` panics if `T`'s `Serialize` impl fails (e.g. it emits a
+/// sequence of unknown length).
+#[cfg(feature = "msgpack")]
+impl<T> FromWebSocketMessage for crate::codec::MsgPack<T>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + Send + 'static,
+{
+    fn from_ws_message(msg: WebSocketMessage) -> Result<Self, StreamingError> {
+        match msg {
+            WebSocketMessage::Binary(data) => rmp_serde::from_slice(&data)
+                .map(crate::codec::MsgPack)
+                .map_err(|e| StreamingError::WebSocketBridge {
+                    detail: format!("invalid MessagePack payload: {e}"),
+                }),
+            _ => Err(StreamingError::WebSocketBridge {
+                detail: "expected Binary message for MessagePack deserialization, got Text".into(),
+            }),
+        }
+    }
+
+    fn to_ws_message(&self) -> WebSocketMessage {
+        let data =
+            rmp_serde::to_vec_named(&self.0).expect("type must be representable as MessagePack");
+        WebSocketMessage::Binary(data)
+    }
+}
+
+/// An item from [`WebSocketStream::recv_event`]: a data message, or the end
+/// of the connection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WsEvent<T> {
+    /// A Text or Binary message, converted to `T`.
+    Message(T),
+    /// The connection closed with the peer's Close frame, or `None` if the
+    /// stream ended without one.
+    Closed(Option<WebSocketCloseFrame>),
+}
+
 // ---------------------------------------------------------------------------
 // WebSocketStream
 // ---------------------------------------------------------------------------
@@ -76,8 +158,12 @@ where
 /// - `WebSocketStream<Json<MyType>>` — automatic JSON serialization.
 /// - `WebSocketStream<MyType>` — custom conversion via [`FromWebSocketMessage`].
 pub struct WebSocketStream<T: FromWebSocketMessage = WebSocketMessage> {
-    sink: RawSink,
+    sink: SharedSink,
     receiver: RawReceiver,
+    stats: Arc<WsCounters>,
+    closed: bool,
+    close_frame: Option<WebSocketCloseFrame>,
+    heartbeat: Option<AbortOnDrop>,
     _marker: PhantomData<fn() -> T>,
 }
 
@@ -85,14 +171,35 @@ pub struct WebSocketStream<T: FromWebSocketMessage = WebSocketMessage> {
 
 impl From<(RawSink, RawReceiver)> for WebSocketStream {
     fn from((sink, receiver): (RawSink, RawReceiver)) -> Self {
+        let sink = Arc::new(tokio::sync::Mutex::new(sink));
         Self {
+            receiver: auto_pong(receiver, Arc::clone(&sink)),
             sink,
-            receiver,
+            stats: WsCounters::shared(),
+            closed: false,
+            close_frame: None,
+            heartbeat: None,
             _marker: PhantomData,
         }
     }
 }
 
+impl WebSocketStream {
+    /// Build a stream from any message sink and stream, boxing them
+    /// internally.
+    ///
+    /// Equivalent to converting a `(WebSocketSink, WebSocketReceiver)`
+    /// tuple, without pinning and boxing each half by hand — handy for
+    /// in-memory pipes, custom transports and test doubles.
+    pub fn from_parts<Si, St>(sink: Si, stream: St) -> Self
+    where
+        Si: futures_util::Sink<WebSocketMessage, Error = StreamingError> + Send + 'static,
+        St: Stream<Item = Result<WebSocketMessage, StreamingError>> + Send + 'static,
+    {
+        (Box::pin(sink) as RawSink, Box::pin(stream) as RawReceiver).into()
+    }
+}
+
 #[cfg(feature = "axum")]
 impl From<axum::extract::ws::WebSocket> for WebSocketStream {
     fn from(socket: axum::extract::ws::WebSocket) -> Self {
@@ -107,6 +214,8 @@ impl<T: FromWebSocketMessage> WebSocketStream<T> {
     pub async fn send(&mut self, msg: &T) -> Result<(), StreamingError> {
         let raw = msg.to_ws_message();
         self.sink
+            .lock()
+            .await
             .send(raw)
             .await
             .map_err(|e| StreamingError::WebSocketBridge {
@@ -114,16 +223,49 @@ impl<T: FromWebSocketMessage> WebSocketStream<T> {
             })
     }
 
+    /// Queue a typed message without flushing.
+    ///
+    /// Use with [`flush`](Self::flush) to batch several messages and pay
+    /// the flush cost once. Messages may sit in the sink's buffer until
+    /// flushed.
+    pub async fn feed(&mut self, msg: &T) -> Result<(), StreamingError> {
+        let raw = msg.to_ws_message();
+        self.sink
+            .lock()
+            .await
+            .feed(raw)
+            .await
+            .map_err(|e| StreamingError::WebSocketBridge {
+                detail: e.to_string(),
+            })
+    }
+
+    /// Flush messages queued with [`feed`](Self::feed).
+    pub async fn flush(&mut self) -> Result<(), StreamingError> {
+        self.sink
+            .lock()
+            .await
+            .flush()
+            .await
+            .map_err(|e| StreamingError::WebSocketBridge {
+                detail: e.to_string(),
+            })
+    }
+
     /// Receive the next typed message.
     ///
-    /// Ping/Pong frames are silently skipped. Returns `None` when the
+    /// Ping frames are answered with a Pong carrying the same payload; Ping
+    /// and Pong frames are otherwise skipped. Returns `None` when the
     /// connection is closed (Close frame or stream end).
     pub async fn recv(&mut self) -> Option<Result<T, StreamingError>> {
         loop {
             match self.receiver.next().await? {
-                Ok(msg) => match msg {
+                Ok(msg) => match self.stats.record(msg) {
                     WebSocketMessage::Ping(_) | WebSocketMessage::Pong(_) => continue,
-                    WebSocketMessage::Close(_) => return None,
+                    WebSocketMessage::Close(frame) => {
+                        self.close_frame = frame;
+                        return None;
+                    }
                     data => return Some(T::from_ws_message(data)),
                 },
                 Err(e) => return Some(Err(e)),
@@ -131,9 +273,51 @@ impl<T: FromWebSocketMessage> WebSocketStream<T> {
         }
     }
 
+    /// Like [`recv`](Self::recv), but gives up after `timeout`.
+    ///
+    /// Returns `Some(Err(StreamingError::WebSocketRecvTimeout { .. }))` if no
+    /// data message or Close arrives in time; Ping/Pong frames do not count.
+    /// No message is lost on timeout, so the stream can be polled again.
+    /// `None` still means the connection closed.
+    pub async fn recv_timeout(&mut self, timeout: Duration) -> Option<Result<T, StreamingError>> {
+        tokio::time::timeout(timeout, self.recv())
+            .await
+            .unwrap_or(Some(Err(StreamingError::WebSocketRecvTimeout { timeout })))
+    }
+
+    /// Receive the next typed message or the close of the connection.
+    ///
+    /// Like [`recv`](Self::recv), but the end of the connection is reported
+    /// once as [`WsEvent::Closed`] carrying the peer's Close frame, so its
+    /// code and reason can be relayed; later calls return `None`.
+    pub async fn recv_event(&mut self) -> Option<Result<WsEvent<T>, StreamingError>> {
+        next_event(
+            &mut self.receiver,
+            &self.stats,
+            &mut self.closed,
+            &mut self.close_frame,
+        )
+        .await
+    }
+
+    /// The Close frame received from the peer, once one has been seen.
+    ///
+    /// Set when [`recv`](Self::recv), [`recv_event`](Self::recv_event) or the
+    /// `Stream` impl observes a Close carrying a code and reason, so callers
+    /// can tell why the connection ended after `recv` returned `None`.
+    /// `None` while open, or when the peer closed without a frame or the
+    /// stream just ended.
+    #[must_use]
+    pub fn close_frame(&self) -> Option<&WebSocketCloseFrame> {
+        self.close_frame.as_ref()
+    }
+
     /// Close the connection gracefully.
-    pub async fn close(mut self) -> Result<(), StreamingError> {
+    pub async fn close(self) -> Result<(), StreamingError> {
+        drop(self.heartbeat);
         self.sink
+            .lock()
+            .await
             .send(WebSocketMessage::Close(None))
             .await
             .map_err(|e| StreamingError::WebSocketBridge {
@@ -141,19 +325,267 @@ impl<T: FromWebSocketMessage> WebSocketStream<T> {
             })
     }
 
+    /// Close the connection and wait for the peer to acknowledge.
+    ///
+    /// Sends a Close frame with the given `code` and `reason`, then drains
+    /// incoming messages until the peer's Close frame arrives. `timeout`
+    /// bounds the whole handshake, including waiting for the sink and
+    /// sending the Close frame. Returns `Ok(true)` if the close handshake
+    /// completed and `Ok(false)` if it did not finish in time (or the stream
+    /// ended without a Close frame).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::WebSocketBridge`] if sending the Close frame
+    /// fails.
+    pub async fn close_with_timeout(
+        mut self,
+        code: u16,
+        reason: impl Into<String>,
+        timeout: Duration,
+    ) -> Result<bool, StreamingError> {
+        let frame = WebSocketCloseFrame {
+            code,
+            reason: reason.into(),
+        };
+        self.heartbeat = None;
+        let (sink, receiver, stats) = (&self.sink, &mut self.receiver, &self.stats);
+        let handshake = async move {
+            sink.lock()
+                .await
+                .send(WebSocketMessage::Close(Some(frame)))
+                .await
+                .map_err(|e| StreamingError::WebSocketBridge {
+                    detail: e.to_string(),
+                })?;
+            Ok(drain_until_close(receiver, stats).await)
+        };
+        tokio::time::timeout(timeout, handshake)
+            .await
+            .unwrap_or(Ok(false))
+    }
+
+    /// Snapshot of the frames received so far, by type.
+    #[must_use]
+    pub fn stats(&self) -> WsStats {
+        self.stats.snapshot()
+    }
+
+    /// Send an empty Ping every `interval` to keep the connection alive.
+    ///
+    /// Pings go through the same sink as user messages, one frame at a time,
+    /// so they never interleave with a send. The heartbeat stops when the
+    /// stream is closed or dropped, or once a Ping fails to send; after
+    /// [`split`](Self::split) it belongs to the [`WebSocketSender`]. Calling
+    /// this again replaces the previous heartbeat.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a Tokio runtime or if `interval` is zero.
+    #[must_use]
+    pub fn with_heartbeat(mut self, interval: Duration) -> Self {
+        let sink = Arc::clone(&self.sink);
+        self.heartbeat = Some(AbortOnDrop(tokio::spawn(async move {
+            let mut ticks =
+                tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+            ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticks.tick().await;
+                if let Err(e) = sink
+                    .lock()
+                    .await
+                    .send(WebSocketMessage::Ping(Vec::new()))
+                    .await
+                {
+                    tracing::debug!(error = %e, "WebSocket heartbeat Ping failed");
+                    return;
+                }
+            }
+        })));
+        self
+    }
+
+    /// Reinterpret the connection with message type `U`, e.g. to turn a raw
+    /// stream from an adapter into a `WebSocketStream<Json<MyType>>`.
+    ///
+    /// Counters, close state and any heartbeat carry over.
+    #[must_use]
+    pub fn into_typed<U: FromWebSocketMessage>(self) -> WebSocketStream<U> {
+        WebSocketStream {
+            sink: self.sink,
+            receiver: self.receiver,
+            stats: self.stats,
+            closed: self.closed,
+            close_frame: self.close_frame,
+            heartbeat: self.heartbeat,
+            _marker: PhantomData,
+        }
+    }
+
     /// Split into separate send/receive halves for concurrent use.
+    ///
+    /// Both halves share this connection's frame counters.
     pub fn split(self) -> (WebSocketSender<T>, WebSocketStreamReceiver<T>) {
         (
             WebSocketSender {
                 sink: self.sink,
+                stats: Arc::clone(&self.stats),
+                heartbeat: self.heartbeat,
                 _marker: PhantomData,
             },
             WebSocketStreamReceiver {
                 receiver: self.receiver,
+                stats: self.stats,
+                closed: self.closed,
+                close_frame: self.close_frame,
                 _marker: PhantomData,
             },
         )
     }
+
+    /// Recombine the halves returned by [`split`](Self::split).
+    ///
+    /// The halves are not checked to come from the same connection; passing
+    /// halves of different connections yields a stream that sends on one
+    /// and receives from the other.
+    #[must_use]
+    pub fn reunite(sender: WebSocketSender<T>, receiver: WebSocketStreamReceiver<T>) -> Self {
+        Self {
+            sink: sender.sink,
+            receiver: receiver.receiver,
+            stats: receiver.stats,
+            closed: receiver.closed,
+            close_frame: receiver.close_frame,
+            heartbeat: sender.heartbeat,
+            _marker: PhantomData,
+        }
+    }
+}
+
+// --- Typed helpers ---
+
+impl<T> WebSocketStream<Json<T>>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + Send + 'static,
+{
+    /// Send `value` as a JSON `Text` message, without wrapping it in
+    /// [`Json`] first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::WebSocketBridge`] if `value` cannot be
+    /// serialized or the message cannot be sent.
+    pub async fn send_json(&mut self, value: &T) -> Result<(), StreamingError> {
+        send_json(&self.sink, value).await
+    }
+}
+
+// --- Raw message helpers ---
+
+impl WebSocketStream {
+    /// Send a `Text` message.
+    pub async fn send_text(&mut self, text: impl Into<String>) -> Result<(), StreamingError> {
+        self.send_untyped(WebSocketMessage::Text(text.into())).await
+    }
+
+    /// Send a `Binary` message.
+    pub async fn send_binary(&mut self, data: impl Into<Vec<u8>>) -> Result<(), StreamingError> {
+        self.send_untyped(WebSocketMessage::Binary(data.into()))
+            .await
+    }
+
+    async fn send_untyped(&mut self, msg: WebSocketMessage) -> Result<(), StreamingError> {
+        self.sink
+            .lock()
+            .await
+            .send(msg)
+            .await
+            .map_err(|e| StreamingError::WebSocketBridge {
+                detail: e.to_string(),
+            })
+    }
+
+    /// Bridge this connection to an HTTP streaming body pair.
+    ///
+    /// A spawned task forwards `inbound` chunks as messages, as
+    /// [`WebSocketSender::forward_body_stream`] does, and sends a Close frame
+    /// once `inbound` ends or fails. The returned body yields received
+    /// messages, as [`WebSocketStreamReceiver::into_body_stream`] does, and
+    /// ends on Close. Dropping or finishing the returned body stops the
+    /// forwarding task.
+    ///
+    /// Must be called within a Tokio runtime.
+    pub fn bridge_body(self, inbound: BodyStream) -> BodyStream {
+        let (mut sender, receiver) = self.split();
+        let forward = AbortOnDrop(tokio::spawn(async move {
+            if let Err(e) = sender.forward_body_stream(inbound).await {
+                tracing::debug!(error = %e, "WebSocket bridge: forwarding request body failed");
+            }
+            if let Err(e) = sender.send_raw(WebSocketMessage::Close(None)).await {
+                tracing::debug!(error = %e, "WebSocket bridge: failed to send Close");
+            }
+        }));
+        Box::pin(futures_util::stream::unfold(
+            (receiver.into_body_stream(), forward),
+            |(mut body, forward)| async move {
+                let item = body.next().await?;
+                Some((item, (body, forward)))
+            },
+        ))
+    }
+}
+
+/// Answer each incoming Ping with a Pong carrying the same payload (RFC 6455
+/// §5.5.2) before passing the Ping on.
+///
+/// Installed when the stream is built, so every receive path — including the
+/// split receive half, which no longer owns the sink — keeps the connection
+/// alive. A failed Pong is logged and does not affect the receive side.
+fn auto_pong(receiver: RawReceiver, sink: SharedSink) -> RawReceiver {
+    Box::pin(receiver.then(move |item| {
+        let pong = match &item {
+            Ok(WebSocketMessage::Ping(payload)) => Some((Arc::clone(&sink), payload.clone())),
+            _ => None,
+        };
+        async move {
+            if let Some((sink, payload)) = pong
+                && let Err(e) = sink
+                    .lock()
+                    .await
+                    .send(WebSocketMessage::Pong(payload))
+                    .await
+            {
+                tracing::debug!(error = %e, "failed to answer WebSocket Ping");
+            }
+            item
+        }
+    }))
+}
+
+/// Serialize `value` to JSON and send it as a `Text` message.
+async fn send_json<T: serde::Serialize>(
+    sink: &SharedSink,
+    value: &T,
+) -> Result<(), StreamingError> {
+    let text = serde_json::to_string(value).map_err(|e| StreamingError::WebSocketBridge {
+        detail: format!("JSON serialization failed: {e}"),
+    })?;
+    sink.lock()
+        .await
+        .send(WebSocketMessage::Text(text))
+        .await
+        .map_err(|e| StreamingError::WebSocketBridge {
+            detail: e.to_string(),
+        })
+}
+
+/// Aborts a spawned task when dropped.
+struct AbortOnDrop(tokio::task::JoinHandle<()>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
 }
 
 impl<T: FromWebSocketMessage> Stream for WebSocketStream<T> {
@@ -166,9 +598,12 @@ impl<T: FromWebSocketMessage> Stream for WebSocketStream<T> {
                 Poll::Pending => return Poll::Pending,
                 Poll::Ready(None) => return Poll::Ready(None),
                 Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
-                Poll::Ready(Some(Ok(msg))) => match msg {
+                Poll::Ready(Some(Ok(msg))) => match this.stats.record(msg) {
                     WebSocketMessage::Ping(_) | WebSocketMessage::Pong(_) => continue,
-                    WebSocketMessage::Close(_) => return Poll::Ready(None),
+                    WebSocketMessage::Close(frame) => {
+                        this.close_frame = frame;
+                        return Poll::Ready(None);
+                    }
                     data => return Poll::Ready(Some(T::from_ws_message(data))),
                 },
             }
@@ -176,30 +611,192 @@ impl<T: FromWebSocketMessage> Stream for WebSocketStream<T> {
     }
 }
 
+/// Pull the next data message or close from `receiver`, skipping Ping/Pong.
+///
+/// The close is reported once; `closed` then makes further calls return `None`.
+/// A received Close frame is also kept in `close_frame`.
+async fn next_event<T: FromWebSocketMessage>(
+    receiver: &mut RawReceiver,
+    stats: &WsCounters,
+    closed: &mut bool,
+    close_frame: &mut Option<WebSocketCloseFrame>,
+) -> Option<Result<WsEvent<T>, StreamingError>> {
+    if *closed {
+        return None;
+    }
+    loop {
+        let Some(item) = receiver.next().await else {
+            *closed = true;
+            return Some(Ok(WsEvent::Closed(None)));
+        };
+        match item {
+            Ok(msg) => match stats.record(msg) {
+                WebSocketMessage::Ping(_) | WebSocketMessage::Pong(_) => continue,
+                WebSocketMessage::Close(frame) => {
+                    *closed = true;
+                    close_frame.clone_from(&frame);
+                    return Some(Ok(WsEvent::Closed(frame)));
+                }
+                data => return Some(T::from_ws_message(data).map(WsEvent::Message)),
+            },
+            Err(e) => return Some(Err(e)),
+        }
+    }
+}
+
+/// Discard incoming messages until a Close frame is received.
+///
+/// Returns `true` if a Close frame arrived, `false` if the stream ended
+/// (or failed) first.
+async fn drain_until_close(receiver: &mut RawReceiver, stats: &WsCounters) -> bool {
+    while let Some(item) = receiver.next().await {
+        match item {
+            Ok(msg) => {
+                if matches!(stats.record(msg), WebSocketMessage::Close(_)) {
+                    return true;
+                }
+            }
+            Err(e) => {
+                tracing::debug!(error = %e, "WebSocket receive failed while awaiting close");
+                return false;
+            }
+        }
+    }
+    false
+}
+
 // ---------------------------------------------------------------------------
 // WebSocketSender / WebSocketStreamReceiver (split halves)
 // ---------------------------------------------------------------------------
 
 /// The send half of a split [`WebSocketStream`].
 pub struct WebSocketSender<T: FromWebSocketMessage = WebSocketMessage> {
-    sink: RawSink,
+    sink: SharedSink,
+    stats: Arc<WsCounters>,
+    heartbeat: Option<AbortOnDrop>,
     _marker: PhantomData<fn() -> T>,
 }
 
 impl<T: FromWebSocketMessage> WebSocketSender<T> {
+    /// Snapshot of the frames received so far on this connection, by type.
+    ///
+    /// Counts are updated by the receive half.
+    #[must_use]
+    pub fn stats(&self) -> WsStats {
+        self.stats.snapshot()
+    }
+
     /// Send a typed message.
     pub async fn send(&mut self, msg: &T) -> Result<(), StreamingError> {
         let raw = msg.to_ws_message();
         self.sink
+            .lock()
+            .await
             .send(raw)
             .await
             .map_err(|e| StreamingError::WebSocketBridge {
                 detail: e.to_string(),
             })
     }
+
+    /// Queue a typed message without flushing.
+    ///
+    /// Use with [`flush`](Self::flush) to batch several messages and pay
+    /// the flush cost once. Messages may sit in the sink's buffer until
+    /// flushed.
+    pub async fn feed(&mut self, msg: &T) -> Result<(), StreamingError> {
+        let raw = msg.to_ws_message();
+        self.sink
+            .lock()
+            .await
+            .feed(raw)
+            .await
+            .map_err(|e| StreamingError::WebSocketBridge {
+                detail: e.to_string(),
+            })
+    }
+
+    /// Flush messages queued with [`feed`](Self::feed).
+    pub async fn flush(&mut self) -> Result<(), StreamingError> {
+        self.sink
+            .lock()
+            .await
+            .flush()
+            .await
+            .map_err(|e| StreamingError::WebSocketBridge {
+                detail: e.to_string(),
+            })
+    }
+
+    /// Send a batch of messages, flushing once at the end.
+    ///
+    /// The sink is held for the whole batch, so Pongs and heartbeat Pings
+    /// are not interleaved with it. On error, messages fed before the
+    /// failure may or may not have been sent.
+    pub async fn send_all(
+        &mut self,
+        msgs: impl IntoIterator<Item = T>,
+    ) -> Result<(), StreamingError> {
+        let mut sink = self.sink.lock().await;
+        for msg in msgs {
+            sink.feed(msg.to_ws_message())
+                .await
+                .map_err(|e| StreamingError::WebSocketBridge {
+                    detail: e.to_string(),
+                })?;
+        }
+        sink.flush()
+            .await
+            .map_err(|e| StreamingError::WebSocketBridge {
+                detail: e.to_string(),
+            })
+    }
+}
+
+impl<T> WebSocketSender<Json<T>>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + Send + 'static,
+{
+    /// Send `value` as a JSON `Text` message; see
+    /// [`WebSocketStream::send_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamingError::WebSocketBridge`] if `value` cannot be
+    /// serialized or the message cannot be sent.
+    pub async fn send_json(&mut self, value: &T) -> Result<(), StreamingError> {
+        send_json(&self.sink, value).await
+    }
 }
 
 impl WebSocketSender {
+    /// Send a raw message, including control frames.
+    pub(crate) async fn send_raw(&mut self, msg: WebSocketMessage) -> Result<(), StreamingError> {
+        self.sink.lock().await.send(msg).await
+    }
+
+    /// Send a `Text` message.
+    pub async fn send_text(&mut self, text: impl Into<String>) -> Result<(), StreamingError> {
+        self.send_untyped(WebSocketMessage::Text(text.into())).await
+    }
+
+    /// Send a `Binary` message.
+    pub async fn send_binary(&mut self, data: impl Into<Vec<u8>>) -> Result<(), StreamingError> {
+        self.send_untyped(WebSocketMessage::Binary(data.into()))
+            .await
+    }
+
+    async fn send_untyped(&mut self, msg: WebSocketMessage) -> Result<(), StreamingError> {
+        self.sink
+            .lock()
+            .await
+            .send(msg)
+            .await
+            .map_err(|e| StreamingError::WebSocketBridge {
+                detail: e.to_string(),
+            })
+    }
+
     /// Forward a [`BodyStream`] as WebSocket text messages.
     ///
     /// Each `Bytes` chunk from the stream is sent as a `Text` message.
@@ -215,7 +812,7 @@ impl WebSocketSender {
                         Ok(text) => WebSocketMessage::Text(text),
                         Err(e) => WebSocketMessage::Binary(e.into_bytes()),
                     };
-                    self.sink.send(msg).await?;
+                    self.sink.lock().await.send(msg).await?;
                 }
                 Err(e) => return Err(StreamingError::Stream(e)),
             }
@@ -227,53 +824,112 @@ impl WebSocketSender {
 /// The receive half of a split [`WebSocketStream`].
 pub struct WebSocketStreamReceiver<T: FromWebSocketMessage = WebSocketMessage> {
     receiver: RawReceiver,
+    stats: Arc<WsCounters>,
+    closed: bool,
+    close_frame: Option<WebSocketCloseFrame>,
     _marker: PhantomData<fn() -> T>,
 }
 
 impl<T: FromWebSocketMessage> WebSocketStreamReceiver<T> {
+    /// Snapshot of the frames received so far on this connection, by type.
+    #[must_use]
+    pub fn stats(&self) -> WsStats {
+        self.stats.snapshot()
+    }
+
     /// Receive the next typed message.
     ///
-    /// Ping/Pong frames are silently skipped. Returns `None` on close.
+    /// Ping frames are answered through the shared sink, as on
+    /// [`WebSocketStream::recv`]; Ping/Pong frames are otherwise skipped.
+    /// Returns `None` on close.
     pub async fn recv(&mut self) -> Option<Result<T, StreamingError>> {
         loop {
             match self.receiver.next().await? {
-                Ok(msg) => match msg {
+                Ok(msg) => match self.stats.record(msg) {
                     WebSocketMessage::Ping(_) | WebSocketMessage::Pong(_) => continue,
-                    WebSocketMessage::Close(_) => return None,
+                    WebSocketMessage::Close(frame) => {
+                        self.close_frame = frame;
+                        return None;
+                    }
                     data => return Some(T::from_ws_message(data)),
                 },
                 Err(e) => return Some(Err(e)),
             }
         }
     }
+
+    /// Receive the next typed message or the close of the connection.
+    ///
+    /// See [`WebSocketStream::recv_event`].
+    pub async fn recv_event(&mut self) -> Option<Result<WsEvent<T>, StreamingError>> {
+        next_event(
+            &mut self.receiver,
+            &self.stats,
+            &mut self.closed,
+            &mut self.close_frame,
+        )
+        .await
+    }
+
+    /// The Close frame received from the peer, once one has been seen.
+    ///
+    /// See [`WebSocketStream::close_frame`].
+    #[must_use]
+    pub fn close_frame(&self) -> Option<&WebSocketCloseFrame> {
+        self.close_frame.as_ref()
+    }
 }
 
 impl WebSocketStreamReceiver {
+    /// Receive the next raw frame, including Ping/Pong and Close.
+    pub(crate) async fn recv_raw(&mut self) -> Option<Result<WebSocketMessage, StreamingError>> {
+        let item = self
+            .receiver
+            .next()
+            .await?
+            .map(|msg| self.stats.record(msg));
+        if let Ok(WebSocketMessage::Close(frame)) = &item {
+            self.close_frame.clone_from(frame);
+        }
+        Some(item)
+    }
+
     /// Convert this receiver into a [`BodyStream`] for use as a proxy request body.
     ///
     /// Text and Binary messages become `Bytes` chunks. Control frames (Ping, Pong)
     /// are filtered. The stream terminates on Close or end-of-stream.
+    ///
+    /// Use [`into_body_stream_tagged`](Self::into_body_stream_tagged) when
+    /// the consumer needs to know which chunks were Text.
     pub fn into_body_stream(self) -> BodyStream {
-        Box::pin(futures_util::stream::unfold(
-            self.receiver,
-            |mut rx| async {
-                loop {
-                    match rx.next().await? {
-                        Ok(WebSocketMessage::Text(text)) => {
-                            return Some((Ok(Bytes::from(text)), rx));
-                        }
-                        Ok(WebSocketMessage::Binary(data)) => {
-                            return Some((Ok(Bytes::from(data)), rx));
-                        }
-                        Ok(WebSocketMessage::Close(_)) => return None,
-                        Ok(_) => continue,
-                        Err(e) => {
-                            return Some((Err(Box::new(e) as BoxError), rx));
-                        }
+        Box::pin(
+            self.into_body_stream_tagged()
+                .map(|item| item.map(|(_, bytes)| bytes)),
+        )
+    }
+
+    /// Like [`into_body_stream`](Self::into_body_stream), but each chunk is
+    /// tagged with the [`FrameKind`] of the message it came from.
+    pub fn into_body_stream_tagged(
+        self,
+    ) -> impl Stream<Item = Result<(FrameKind, Bytes), BoxError>> + Send + 'static {
+        futures_util::stream::unfold(self.receiver, |mut rx| async {
+            loop {
+                match rx.next().await? {
+                    Ok(WebSocketMessage::Text(text)) => {
+                        return Some((Ok((FrameKind::Text, Bytes::from(text))), rx));
+                    }
+                    Ok(WebSocketMessage::Binary(data)) => {
+                        return Some((Ok((FrameKind::Binary, Bytes::from(data))), rx));
+                    }
+                    Ok(WebSocketMessage::Close(_)) => return None,
+                    Ok(_) => continue,
+                    Err(e) => {
+                        return Some((Err(Box::new(e) as BoxError), rx));
                     }
                 }
-            },
-        ))
+            }
+        })
     }
 }
 
@@ -287,9 +943,12 @@ impl<T: FromWebSocketMessage> Stream for WebSocketStreamReceiver<T> {
                 Poll::Pending => return Poll::Pending,
                 Poll::Ready(None) => return Poll::Ready(None),
                 Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
-                Poll::Ready(Some(Ok(msg))) => match msg {
+                Poll::Ready(Some(Ok(msg))) => match this.stats.record(msg) {
                     WebSocketMessage::Ping(_) | WebSocketMessage::Pong(_) => continue,
-                    WebSocketMessage::Close(_) => return Poll::Ready(None),
+                    WebSocketMessage::Close(frame) => {
+                        this.close_frame = frame;
+                        return Poll::Ready(None);
+                    }
                     data => return Poll::Ready(Some(T::from_ws_message(data))),
                 },
             }
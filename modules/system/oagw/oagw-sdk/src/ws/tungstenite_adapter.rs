@@ -0,0 +1,360 @@
+//! tokio-tungstenite adapter for the WebSocket abstraction.
+//!
+//! The client-side counterpart of [`axum_adapter`](super::axum_adapter):
+//! opens outbound connections to upstreams and converts between
+//! `tungstenite::Message` and `WebSocketMessage`.
+//!
+//! Only `ws://` URLs are supported unless a TLS feature of `tokio-tungstenite`
+//! is enabled elsewhere in the build.
+//!
+//! tungstenite reassembles fragmented messages (RFC 6455 §5.4) before
+//! yielding them. Streams that surface raw `Message::Frame` items instead are
+//! reassembled by [`split`]: fragments are buffered until the final frame and
+//! yielded as one Text or Binary message, so
+//! [`FromWebSocketMessage`](crate::ws::FromWebSocketMessage) never sees a
+//! partial payload. Raw frames bypass tungstenite's `max_message_size`, so
+//! a reassembled message larger than 64 MiB (tungstenite's default) fails
+//! the stream instead.
+
+use futures_core::Stream;
+use futures_util::{SinkExt, StreamExt};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_tungstenite::WebSocketStream as TungsteniteStream;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::protocol::frame::Frame;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::{CloseCode, Control, Data, OpCode};
+use tokio_tungstenite::tungstenite::{self, Message};
+
+use crate::error::StreamingError;
+use crate::ws::message::{WebSocketCloseFrame, WebSocketMessage, WebSocketReceiver, WebSocketSink};
+use crate::ws::stream::WebSocketStream;
+
+/// Largest message reassembled from raw fragments; tungstenite's default
+/// `max_message_size`.
+const MAX_MESSAGE_SIZE: usize = 64 << 20;
+
+/// Convert a `tungstenite::Message` to `WebSocketMessage`.
+///
+/// Raw `Frame` messages are never produced when reading; should one appear,
+/// its payload is passed on as Binary. Use [`split`] to reassemble fragmented
+/// frames instead.
+pub fn from_tungstenite(msg: Message) -> WebSocketMessage {
+    match msg {
+        Message::Text(text) => WebSocketMessage::Text(text.to_string()),
+        Message::Binary(data) => WebSocketMessage::Binary(data.to_vec()),
+        Message::Ping(data) => WebSocketMessage::Ping(data.to_vec()),
+        Message::Pong(data) => WebSocketMessage::Pong(data.to_vec()),
+        Message::Close(frame) => WebSocketMessage::Close(frame.map(|f| WebSocketCloseFrame {
+            code: f.code.into(),
+            reason: f.reason.to_string(),
+        })),
+        Message::Frame(frame) => WebSocketMessage::Binary(frame.into_payload().to_vec()),
+    }
+}
+
+/// Convert a `WebSocketMessage` to `tungstenite::Message`.
+pub fn to_tungstenite(msg: WebSocketMessage) -> Message {
+    match msg {
+        WebSocketMessage::Text(text) => Message::Text(text.into()),
+        WebSocketMessage::Binary(data) => Message::Binary(data.into()),
+        WebSocketMessage::Ping(data) => Message::Ping(data.into()),
+        WebSocketMessage::Pong(data) => Message::Pong(data.into()),
+        WebSocketMessage::Close(frame) => Message::Close(frame.map(|f| CloseFrame {
+            code: CloseCode::from(f.code),
+            reason: f.reason.into(),
+        })),
+    }
+}
+
+/// Split a tokio-tungstenite connection into abstract
+/// `(WebSocketSink, WebSocketReceiver)`.
+///
+/// Works for client connections from [`connect`] as well as server-side
+/// connections accepted with `tokio_tungstenite::accept_async`.
+pub fn split<S>(socket: TungsteniteStream<S>) -> (WebSocketSink, WebSocketReceiver)
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let (tx, rx) = socket.split();
+
+    // Wrap the sink: map errors and convert WebSocketMessage → tungstenite::Message
+    let sink: WebSocketSink = Box::pin(
+        tx.sink_map_err(|e| StreamingError::WebSocketBridge {
+            detail: e.to_string(),
+        })
+        .with(|msg: WebSocketMessage| async move { Ok(to_tungstenite(msg)) }),
+    );
+
+    (sink, receiver(rx))
+}
+
+/// Convert tungstenite messages to `WebSocketMessage`, reassembling
+/// fragmented frames.
+fn receiver<S>(rx: S) -> WebSocketReceiver
+where
+    S: Stream<Item = Result<Message, tungstenite::Error>> + Send + 'static,
+{
+    let mut fragments = Reassembler::new(MAX_MESSAGE_SIZE);
+    Box::pin(rx.filter_map(move |result| {
+        let item = result
+            .map_err(|e| StreamingError::WebSocketBridge {
+                detail: e.to_string(),
+            })
+            .and_then(|msg| fragments.push(msg))
+            .transpose();
+        async move { item }
+    }))
+}
+
+/// Buffers the frames of a fragmented Text or Binary message.
+struct Reassembler {
+    /// Opcode of the first fragment and the payload received so far.
+    pending: Option<(Data, Vec<u8>)>,
+    /// Largest reassembled payload accepted, in bytes.
+    max_size: usize,
+}
+
+impl Reassembler {
+    fn new(max_size: usize) -> Self {
+        Self {
+            pending: None,
+            max_size,
+        }
+    }
+
+    /// Feed one received message; returns the message to yield, or `None`
+    /// while a fragmented message is incomplete.
+    ///
+    /// Control frames may arrive between fragments and are yielded as-is.
+    fn push(&mut self, msg: Message) -> Result<Option<WebSocketMessage>, StreamingError> {
+        let frame = match msg {
+            Message::Frame(frame) => frame,
+            Message::Text(_) | Message::Binary(_) if self.pending.is_some() => {
+                return Err(fragment_error(
+                    "new message before the fragmented message finished",
+                ));
+            }
+            msg => return Ok(Some(from_tungstenite(msg))),
+        };
+        let is_final = frame.header().is_final;
+        match frame.header().opcode {
+            OpCode::Data(Data::Continue) => {
+                let (_, payload) = self
+                    .pending
+                    .as_mut()
+                    .ok_or_else(|| fragment_error("continuation frame without a first fragment"))?;
+                if payload.len() + frame.payload().len() > self.max_size {
+                    self.pending = None;
+                    return Err(self.too_large());
+                }
+                payload.extend_from_slice(frame.payload());
+            }
+            OpCode::Data(kind @ (Data::Text | Data::Binary)) => {
+                if self.pending.is_some() {
+                    return Err(fragment_error(
+                        "new message before the fragmented message finished",
+                    ));
+                }
+                if frame.payload().len() > self.max_size {
+                    return Err(self.too_large());
+                }
+                self.pending = Some((kind, frame.into_payload().to_vec()));
+            }
+            OpCode::Control(control) => return control_message(control, frame).map(Some),
+            OpCode::Data(Data::Reserved(op)) => {
+                return Err(fragment_error(&format!("reserved opcode {op:#x}")));
+            }
+        }
+        if !is_final {
+            return Ok(None);
+        }
+        let Some((kind, payload)) = self.pending.take() else {
+            return Ok(None);
+        };
+        match kind {
+            Data::Text => String::from_utf8(payload)
+                .map(|text| Some(WebSocketMessage::Text(text)))
+                .map_err(|_| fragment_error("fragmented Text message is not valid UTF-8")),
+            _ => Ok(Some(WebSocketMessage::Binary(payload))),
+        }
+    }
+
+    fn too_large(&self) -> StreamingError {
+        fragment_error(&format!(
+            "fragmented message exceeds {} bytes",
+            self.max_size
+        ))
+    }
+}
+
+/// Convert a raw control frame; the Close payload is a big-endian code
+/// followed by the UTF-8 reason (RFC 6455 §5.5.1).
+fn control_message(control: Control, frame: Frame) -> Result<WebSocketMessage, StreamingError> {
+    let payload = frame.into_payload();
+    match control {
+        Control::Ping => Ok(WebSocketMessage::Ping(payload.to_vec())),
+        Control::Pong => Ok(WebSocketMessage::Pong(payload.to_vec())),
+        Control::Close => match payload.as_ref() {
+            [] => Ok(WebSocketMessage::Close(None)),
+            [hi, lo, reason @ ..] => {
+                let reason = std::str::from_utf8(reason)
+                    .map_err(|_| fragment_error("Close reason is not valid UTF-8"))?;
+                Ok(WebSocketMessage::Close(Some(WebSocketCloseFrame {
+                    code: u16::from_be_bytes([*hi, *lo]),
+                    reason: reason.to_owned(),
+                })))
+            }
+            [_] => Err(fragment_error("truncated Close frame")),
+        },
+        Control::Reserved(op) => Err(fragment_error(&format!("reserved opcode {op:#x}"))),
+    }
+}
+
+fn fragment_error(detail: &str) -> StreamingError {
+    StreamingError::WebSocketBridge {
+        detail: format!("invalid WebSocket frame: {detail}"),
+    }
+}
+
+/// Open a WebSocket connection to `url` and wrap it in a [`WebSocketStream`].
+///
+/// # Errors
+///
+/// Returns [`StreamingError::WebSocketConnect`] when the URL is invalid, the
+/// TCP connection fails, or the upstream rejects the handshake.
+pub async fn connect(url: &str) -> Result<WebSocketStream, StreamingError> {
+    let (socket, _response) = tokio_tungstenite::connect_async(url)
+        .await
+        .map_err(connect_error)?;
+    Ok(split(socket).into())
+}
+
+fn connect_error(e: tungstenite::Error) -> StreamingError {
+    StreamingError::WebSocketConnect {
+        detail: e.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(data: &[u8], opcode: Data, is_final: bool) -> Result<Message, tungstenite::Error> {
+        Ok(Message::Frame(Frame::message(
+            data.to_vec(),
+            OpCode::Data(opcode),
+            is_final,
+        )))
+    }
+
+    async fn receive(items: Vec<Result<Message, tungstenite::Error>>) -> Vec<WebSocketMessage> {
+        receiver(futures_util::stream::iter(items))
+            .map(Result::unwrap)
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn fragments_are_reassembled_around_control_frames() {
+        let items = vec![
+            frame(b"hel", Data::Text, false),
+            Ok(Message::Ping(vec![9].into())),
+            frame(b"lo ", Data::Continue, false),
+            frame("wörld".as_bytes(), Data::Continue, true),
+            frame(&[1, 2], Data::Binary, false),
+            frame(&[3], Data::Continue, true),
+            Ok(Message::Text("whole".into())),
+        ];
+        assert_eq!(
+            receive(items).await,
+            [
+                WebSocketMessage::Ping(vec![9]),
+                WebSocketMessage::Text("hello wörld".into()),
+                WebSocketMessage::Binary(vec![1, 2, 3]),
+                WebSocketMessage::Text("whole".into()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn utf8_split_across_fragments_is_accepted() {
+        let bytes = "é".as_bytes();
+        let items = vec![
+            frame(&bytes[..1], Data::Text, false),
+            frame(&bytes[1..], Data::Continue, true),
+        ];
+        assert_eq!(receive(items).await, [WebSocketMessage::Text("é".into())]);
+    }
+
+    #[tokio::test]
+    async fn raw_close_frame_is_decoded() {
+        let items = vec![Ok(Message::Frame(Frame::close(Some(CloseFrame {
+            code: CloseCode::Away,
+            reason: "going away".into(),
+        }))))];
+        assert_eq!(
+            receive(items).await,
+            [WebSocketMessage::Close(Some(WebSocketCloseFrame {
+                code: 1001,
+                reason: "going away".into(),
+            }))]
+        );
+    }
+
+    #[test]
+    fn oversized_fragmented_message_fails() {
+        let mut fragments = Reassembler::new(8);
+        let frame = |data: &[u8], opcode, is_final| frame(data, opcode, is_final).unwrap();
+
+        assert_eq!(
+            fragments.push(frame(b"1234", Data::Binary, false)).unwrap(),
+            None
+        );
+        assert_eq!(
+            fragments
+                .push(frame(b"5678", Data::Continue, false))
+                .unwrap(),
+            None
+        );
+        let err = fragments
+            .push(frame(b"9", Data::Continue, false))
+            .unwrap_err();
+        assert!(
+            matches!(&err, StreamingError::WebSocketBridge { detail } if detail.contains("exceeds 8 bytes")),
+            "got {err:?}"
+        );
+        // The partial message is dropped, so a new one may start.
+        assert_eq!(
+            fragments.push(frame(b"ok", Data::Text, true)).unwrap(),
+            Some(WebSocketMessage::Text("ok".into()))
+        );
+
+        assert!(
+            fragments
+                .push(frame(b"123456789", Data::Text, false))
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn out_of_order_fragments_fail() {
+        for items in [
+            vec![frame(b"x", Data::Continue, true)],
+            vec![
+                frame(b"a", Data::Text, false),
+                frame(b"b", Data::Binary, true),
+            ],
+            vec![
+                frame(b"a", Data::Text, false),
+                Ok(Message::Text("b".into())),
+            ],
+        ] {
+            let results: Vec<_> = receiver(futures_util::stream::iter(items)).collect().await;
+            let err = results.into_iter().last().unwrap().unwrap_err();
+            assert!(
+                matches!(&err, StreamingError::WebSocketBridge { detail } if detail.starts_with("invalid WebSocket frame")),
+                "got {err:?}"
+            );
+        }
+    }
+}
@@ -10,20 +10,25 @@
 //! | WebSocket  | n/a (upgrade)         | n/a (bidirectional)    | `WebSocketStream` (via axum)        |
 //! | Multipart  | `Body::Bytes`/`Stream`| `Body::Bytes`          | `MultipartBody::into_request`       |
 
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
 use bytes::Bytes;
-use futures_util::{SinkExt, StreamExt};
+use futures_util::{SinkExt, StreamExt, TryStreamExt};
 use modkit_security::SecurityContext;
 use oagw_sdk::api::ServiceGatewayClientV1;
 use oagw_sdk::body::{Body, BodyStream, BoxError};
 use oagw_sdk::codec::Json;
 use oagw_sdk::error::ServiceGatewayError;
 use oagw_sdk::error::StreamingError;
-use oagw_sdk::sse::{FromServerEvent, ServerEvent, ServerEventsResponse, ServerEventsStream};
+use oagw_sdk::sse::{
+    FromServerEvent, IdleActivity, MergePolicy, ReconnectPolicy, ReconnectingServerEvents,
+    ResponseKind, ServerEvent, ServerEventRouter, ServerEventsResponse, ServerEventsStream,
+    SseDecoder,
+};
 use oagw_sdk::ws::{
-    FromWebSocketMessage, WebSocketMessage, WebSocketReceiver, WebSocketSink, WebSocketStream,
+    FromWebSocketMessage, RelayEnd, WebSocketCloseFrame, WebSocketMessage, WebSocketReceiver,
+    WebSocketSink, WebSocketStream, WsStats,
 };
 
 type TestResult = Result<(), Box<dyn std::error::Error + Send + Sync>>;
@@ -32,6 +37,17 @@ type TestResult = Result<(), Box<dyn std::error::Error + Send + Sync>>;
 // Helpers
 // ===========================================================================
 
+/// OpenAI chat completion chunks spelling "Hello from the stream", ending
+/// with the `[DONE]` sentinel.
+const OPENAI_CHAT_CHUNKS: &[&str] = &[
+    "data: {\"choices\":[{\"delta\":{\"role\":\"assistant\",\"content\":\"Hello\"}}]}\n\n",
+    "data: {\"choices\":[{\"delta\":{\"content\":\" from\"}}]}\n\n",
+    "data: {\"choices\":[{\"delta\":{\"content\":\" the\"}}]}\n\n",
+    "data: {\"choices\":[{\"delta\":{\"content\":\" stream\"}}]}\n\n",
+    "data: {\"choices\":[{\"delta\":{},\"finish_reason\":\"stop\"}]}\n\n",
+    "data: [DONE]\n\n",
+];
+
 /// Build an SSE response with a streaming body from the provided chunks.
 ///
 /// Each string becomes one frame in the body stream, simulating chunked transfer.
@@ -48,6 +64,59 @@ fn server_events_response(chunks: Vec<&str>) -> http::Response<Body> {
         .unwrap()
 }
 
+/// Build an SSE response whose body is fed through a channel, so tests control
+/// when (and whether) each chunk arrives.
+fn channel_server_events_response() -> (tokio::sync::mpsc::Sender<Bytes>, http::Response<Body>) {
+    let (tx, rx) = tokio::sync::mpsc::channel::<Bytes>(16);
+    let stream: BodyStream = Box::pin(futures_util::stream::unfold(rx, |mut rx| async {
+        rx.recv().await.map(|chunk| (Ok(chunk), rx))
+    }));
+    let resp = http::Response::builder()
+        .status(200)
+        .header("content-type", "text/event-stream")
+        .body(Body::Stream(stream))
+        .unwrap();
+    (tx, resp)
+}
+
+/// Send `chunk` `times` times, `every` apart, then `last`.
+fn spawn_trickle(
+    tx: tokio::sync::mpsc::Sender<Bytes>,
+    chunk: &'static str,
+    times: usize,
+    every: std::time::Duration,
+    last: &'static str,
+) {
+    tokio::spawn(async move {
+        for _ in 0..times {
+            tokio::time::sleep(every).await;
+            if tx.send(Bytes::from(chunk)).await.is_err() {
+                return;
+            }
+        }
+        let _ = tx.send(Bytes::from(last)).await;
+        // Keep the upstream open so only the idle timer can end the stream.
+        std::future::pending::<()>().await;
+    });
+}
+
+/// Build a channel-backed WebSocket sink so tests can observe sent messages.
+fn channel_sink() -> (WebSocketSink, tokio::sync::mpsc::Receiver<WebSocketMessage>) {
+    let (tx, rx) = tokio::sync::mpsc::channel::<WebSocketMessage>(16);
+    let sink: WebSocketSink = Box::pin(futures_util::sink::unfold(
+        tx,
+        |tx, msg: WebSocketMessage| async move {
+            tx.send(msg)
+                .await
+                .map_err(|e| StreamingError::WebSocketBridge {
+                    detail: e.to_string(),
+                })?;
+            Ok(tx)
+        },
+    ));
+    (sink, rx)
+}
+
 /// Mock gateway client that returns a pre-configured response.
 ///
 /// Shows how streaming tools integrate with `ServiceGatewayClientV1::proxy_request`.
@@ -216,6 +285,33 @@ async fn http_proxy_bytes_in_bytes_out() -> TestResult {
     Ok(())
 }
 
+/// Pass a shared client where the trait is expected.
+///
+/// Preconditions: the gateway is held as `Arc<dyn ServiceGatewayClientV1>`,
+///   as returned by `ClientHub`.
+/// Expected: the `Arc` itself implements the trait and forwards calls, so
+///   generic decorators accept it without a wrapper type.
+#[tokio::test]
+async fn arc_client_forwards_to_inner() -> TestResult {
+    async fn fetch_status(gw: &impl ServiceGatewayClientV1) -> Result<u16, ServiceGatewayError> {
+        let req = http::Request::get("/api/oagw/v1/proxy/openai/models")
+            .body(Body::Empty)
+            .unwrap();
+        let resp = gw.proxy_request(SecurityContext::anonymous(), req).await?;
+        Ok(resp.status().as_u16())
+    }
+
+    // -- precondition -------------------------------------------------------------
+    let gateway: Arc<dyn ServiceGatewayClientV1> = Arc::new(MockGateway::responding_with(
+        http::Response::builder().status(204).body(Body::Empty)?,
+    ));
+
+    // -- action / verify ------------------------------------------------------------
+    assert_eq!(fetch_status(&gateway).await?, 204);
+
+    Ok(())
+}
+
 /// HTTP proxy: SSE response arrives as `Body::Stream` before any parsing.
 ///
 /// Preconditions: upstream returns `text/event-stream` with `Body::Stream`.
@@ -339,14 +435,7 @@ async fn sse_stream_typed_events_with_all_fields() -> TestResult {
 #[tokio::test]
 async fn sse_stream_openai_chat_format() -> TestResult {
     // -- precondition: upstream returns OpenAI chat completion chunks ------------
-    let resp = server_events_response(vec![
-        "data: {\"choices\":[{\"delta\":{\"role\":\"assistant\",\"content\":\"Hello\"}}]}\n\n",
-        "data: {\"choices\":[{\"delta\":{\"content\":\" from\"}}]}\n\n",
-        "data: {\"choices\":[{\"delta\":{\"content\":\" the\"}}]}\n\n",
-        "data: {\"choices\":[{\"delta\":{\"content\":\" stream\"}}]}\n\n",
-        "data: {\"choices\":[{\"delta\":{},\"finish_reason\":\"stop\"}]}\n\n",
-        "data: [DONE]\n\n",
-    ]);
+    let resp = server_events_response(OPENAI_CHAT_CHUNKS.to_vec());
 
     let ServerEventsResponse::Events(mut events) =
         ServerEventsStream::from_response::<ServerEvent>(resp)
@@ -376,6 +465,156 @@ async fn sse_stream_openai_chat_format() -> TestResult {
     Ok(())
 }
 
+/// End an OpenAI-style stream at the `[DONE]` sentinel.
+///
+/// Preconditions: upstream returns the OpenAI chat chunks ending with
+///   `data: [DONE]`.
+/// Expected: with `stop_on("[DONE]")` the stream yields the five chunks and
+///   ends; the sentinel is never yielded.
+#[tokio::test]
+async fn sse_stop_on_sentinel_ends_stream() -> TestResult {
+    // -- precondition -----------------------------------------------------------
+    let resp = server_events_response(OPENAI_CHAT_CHUNKS.to_vec());
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        return Err("expected an SSE response".into());
+    };
+
+    // -- action -----------------------------------------------------------------
+    let collected: Vec<ServerEvent> = events.stop_on("[DONE]").try_collect().await?;
+
+    // -- verify -----------------------------------------------------------------
+    assert_eq!(collected.len(), 5);
+    assert!(collected.iter().all(|ev| ev.data != "[DONE]"));
+
+    Ok(())
+}
+
+/// `stop_on` checks the raw event before typed conversion.
+///
+/// Preconditions: the OpenAI chat chunks parsed as `Json<serde_json::Value>`;
+///   `[DONE]` is not valid JSON.
+/// Expected: the stream yields five parsed chunks and ends without a parse
+///   error for the sentinel.
+#[tokio::test]
+async fn sse_stop_on_sentinel_before_typed_conversion() -> TestResult {
+    // -- precondition -----------------------------------------------------------
+    let resp = server_events_response(OPENAI_CHAT_CHUNKS.to_vec());
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response::<Json<serde_json::Value>>(resp)
+    else {
+        return Err("expected an SSE response".into());
+    };
+
+    // -- action -----------------------------------------------------------------
+    let chunks: Vec<Json<serde_json::Value>> = events.stop_on("[DONE]").try_collect().await?;
+
+    // -- verify -----------------------------------------------------------------
+    let text: String = chunks
+        .iter()
+        .filter_map(|Json(chunk)| chunk["choices"][0]["delta"]["content"].as_str())
+        .collect();
+    assert_eq!(chunks.len(), 5);
+    assert_eq!(text, "Hello from the stream");
+
+    Ok(())
+}
+
+/// Dispatch events to handlers by event name.
+///
+/// Preconditions: upstream sends `progress`, `done`, an unnamed event and an
+///   unknown `ping` event; the router has `progress` and `done` handlers and
+///   a default.
+/// Expected: each named event reaches its handler; the unnamed and unknown
+///   events reach the default handler.
+#[tokio::test]
+async fn sse_router_dispatches_by_event_name() -> TestResult {
+    // -- precondition -----------------------------------------------------------
+    let resp = server_events_response(vec![
+        "event: progress\ndata: 50\n\n",
+        "data: plain\n\n",
+        "event: ping\ndata: p\n\n",
+        "event: done\ndata: ok\n\n",
+    ]);
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        return Err("expected an SSE response".into());
+    };
+    let calls: Arc<Mutex<Vec<String>>> = Arc::default();
+    let (progress, done, fallback) = (Arc::clone(&calls), Arc::clone(&calls), Arc::clone(&calls));
+
+    // -- action -----------------------------------------------------------------
+    ServerEventRouter::new()
+        .on("progress", move |ev| {
+            progress
+                .lock()
+                .unwrap()
+                .push(format!("progress:{}", ev.data));
+            Ok(())
+        })
+        .on("done", move |ev| {
+            done.lock().unwrap().push(format!("done:{}", ev.data));
+            Ok(())
+        })
+        .on_default(move |ev| {
+            fallback
+                .lock()
+                .unwrap()
+                .push(format!("default:{}", ev.data));
+            Ok(())
+        })
+        .run(events)
+        .await?;
+
+    // -- verify -----------------------------------------------------------------
+    assert_eq!(
+        *calls.lock().unwrap(),
+        ["progress:50", "default:plain", "default:p", "done:ok"]
+    );
+
+    Ok(())
+}
+
+/// The router stops at the first handler error.
+///
+/// Preconditions: the `fail` handler errors; more events follow it.
+/// Expected: `run` returns that error and no later event is dispatched.
+#[tokio::test]
+async fn sse_router_surfaces_first_handler_error() -> TestResult {
+    // -- precondition -----------------------------------------------------------
+    let resp = server_events_response(vec!["event: fail\ndata: boom\n\n", "data: after\n\n"]);
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        return Err("expected an SSE response".into());
+    };
+    let seen = Arc::new(Mutex::new(0));
+    let counter = Arc::clone(&seen);
+
+    // -- action -----------------------------------------------------------------
+    let result = ServerEventRouter::new()
+        .on("fail", |ev| {
+            Err(StreamingError::ServerEventsParse { detail: ev.data })
+        })
+        .on("message", move |_| {
+            *counter.lock().unwrap() += 1;
+            Ok(())
+        })
+        .run(events)
+        .await;
+
+    // -- verify -----------------------------------------------------------------
+    assert!(
+        matches!(&result, Err(StreamingError::ServerEventsParse { detail }) if detail == "boom"),
+        "got: {result:?}"
+    );
+    assert_eq!(*seen.lock().unwrap(), 0);
+
+    Ok(())
+}
+
 /// Non-SSE response: `from_response` gives back the original response.
 ///
 /// Preconditions: upstream returns `application/json`, not `text/event-stream`.
@@ -486,14 +725,7 @@ async fn sse_stream_typed_manual_impl() -> TestResult {
     }
 
     // -- precondition: OpenAI chat completion chunks ----------------------------
-    let resp = server_events_response(vec![
-        "data: {\"choices\":[{\"delta\":{\"role\":\"assistant\",\"content\":\"Hello\"}}]}\n\n",
-        "data: {\"choices\":[{\"delta\":{\"content\":\" from\"}}]}\n\n",
-        "data: {\"choices\":[{\"delta\":{\"content\":\" the\"}}]}\n\n",
-        "data: {\"choices\":[{\"delta\":{\"content\":\" stream\"}}]}\n\n",
-        "data: {\"choices\":[{\"delta\":{},\"finish_reason\":\"stop\"}]}\n\n",
-        "data: [DONE]\n\n",
-    ]);
+    let resp = server_events_response(OPENAI_CHAT_CHUNKS.to_vec());
 
     let ServerEventsResponse::Events(mut events) =
         ServerEventsStream::from_response::<ChatChunk>(resp)
@@ -560,6 +792,42 @@ async fn sse_stream_dispatch_pattern() -> TestResult {
     Ok(())
 }
 
+/// Consume SSE fetched with `reqwest` instead of through the gateway.
+///
+/// Preconditions: a `reqwest::Response` carrying `text/event-stream`.
+/// Expected: `Body::from_reqwest` keeps status and headers, so
+///   `from_response` detects SSE and parses the streamed events.
+///
+/// Requires the `reqwest` feature.
+#[cfg(feature = "reqwest")]
+#[tokio::test]
+async fn sse_from_reqwest_response() -> TestResult {
+    // -- precondition: a reqwest response as an HTTP client would return it ------
+    let upstream = http::Response::builder()
+        .status(200)
+        .header("content-type", "text/event-stream")
+        .header("x-request-id", "abc")
+        .body(reqwest::Body::from("data: first\n\ndata: second\n\n"))?;
+    let resp = reqwest::Response::from(upstream);
+
+    // -- action -----------------------------------------------------------------
+    let resp = Body::from_reqwest(resp);
+    assert!(resp.body().is_streaming());
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        return Err("expected an SSE response".into());
+    };
+
+    // -- verify -----------------------------------------------------------------
+    assert_eq!(events.status(), http::StatusCode::OK);
+    assert_eq!(events.headers()["x-request-id"], "abc");
+    let data: Vec<String> = events.map_ok(|e| e.data).try_collect().await?;
+    assert_eq!(data, ["first", "second"]);
+
+    Ok(())
+}
+
 /// Convert SSE stream back into an HTTP response for forwarding to clients.
 ///
 /// Preconditions: ServerEventsStream parsed from upstream SSE.
@@ -604,142 +872,1509 @@ async fn sse_stream_into_response() -> TestResult {
     Ok(())
 }
 
-/// Custom response headers are accessible via `events.headers()`.
+/// Force an explicit `event:` name on re-emitted events.
 ///
-/// Preconditions: upstream returns SSE with a custom `x-request-id` header.
-/// Expected: header is preserved and accessible on the stream wrapper.
+/// Preconditions: upstream sends one unnamed and one named event.
+/// Expected: `with_default_event` names only the unnamed event; the named
+///   one keeps its original name and both are serialized with `event:` lines.
+///
+/// Requires the `axum` feature.
+#[cfg(feature = "axum")]
 #[tokio::test]
-async fn sse_stream_preserves_headers() -> TestResult {
-    // -- precondition: SSE response with custom header --------------------------
-    let stream: BodyStream = Box::pin(futures_util::stream::iter(vec![Ok(Bytes::from(
-        "data: test\n\n",
-    ))]));
-    let resp = http::Response::builder()
-        .status(200)
-        .header("content-type", "text/event-stream")
-        .header("x-request-id", "req-42")
-        .body(Body::Stream(stream))?;
-
-    // -- action -----------------------------------------------------------------
+async fn sse_stream_with_default_event() -> TestResult {
+    // -- precondition -----------------------------------------------------------
+    let resp = server_events_response(vec!["data: plain\n\n", "event: delta\ndata: named\n\n"]);
     let ServerEventsResponse::Events(events) =
         ServerEventsStream::from_response::<ServerEvent>(resp)
     else {
         return Ok(());
     };
 
+    // -- action -----------------------------------------------------------------
+    let response = events.with_default_event("message").into_response();
+
     // -- verify -----------------------------------------------------------------
-    assert_eq!(events.status(), 200);
-    assert_eq!(events.headers().get("x-request-id").unwrap(), "req-42");
+    let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+    assert_eq!(
+        std::str::from_utf8(&body_bytes)?,
+        "event: message\ndata: plain\n\nevent: delta\ndata: named\n\n"
+    );
 
     Ok(())
 }
 
-/// Full integration pattern: gateway client → ServerEventsStream.
+/// Transform parsed events without losing the response head.
 ///
-/// Preconditions: `ServiceGatewayClientV1` returns an SSE response from `proxy_request`.
-/// Expected: wrap the response into a typed event stream.
+/// Preconditions: upstream replies `202 Accepted` with two SSE events.
+/// Expected: `map_ok` wraps each event's data in a newtype, and the mapped
+///   stream still reports the original status and headers.
 #[tokio::test]
-async fn sse_via_gateway_client() -> TestResult {
-    // -- setup: mock gateway returns an SSE response ----------------------------
-    let gateway = MockGateway::responding_with(server_events_response(vec![
-        "data: {\"status\":\"processing\"}\n\n",
-        "data: {\"status\":\"complete\"}\n\n",
-    ]));
-
-    // -- action: call proxy_request and wrap into ServerEventsStream -------------
-    let req = http::Request::get("/api/oagw/v1/proxy/openai/chat/completions").body(Body::Empty)?;
-    let resp = gateway
-        .proxy_request(SecurityContext::anonymous(), req)
-        .await?;
+async fn sse_map_ok_preserves_status_and_headers() -> TestResult {
+    #[derive(Debug, PartialEq)]
+    struct Delta(String);
 
-    let ServerEventsResponse::Events(mut events) =
+    // -- precondition -----------------------------------------------------------
+    let mut resp = server_events_response(vec!["data: a\n\n", "data: b\n\n"]);
+    *resp.status_mut() = http::StatusCode::ACCEPTED;
+    let ServerEventsResponse::Events(events) =
         ServerEventsStream::from_response::<ServerEvent>(resp)
     else {
-        return Ok(());
+        return Err("expected an SSE response".into());
     };
 
-    // -- verify -----------------------------------------------------------------
-    let first = events.next().await.expect("stream ended")?;
-    assert_eq!(first.data, r#"{"status":"processing"}"#);
+    // -- action -----------------------------------------------------------------
+    let deltas = events.map_ok(|event| Delta(event.data));
 
-    let second = events.next().await.expect("stream ended")?;
-    assert_eq!(second.data, r#"{"status":"complete"}"#);
+    // -- verify -----------------------------------------------------------------
+    assert_eq!(deltas.status(), http::StatusCode::ACCEPTED);
+    assert_eq!(deltas.content_type(), Some("text/event-stream"));
+    let items: Vec<Delta> = deltas.try_collect().await?;
+    assert_eq!(items, [Delta("a".into()), Delta("b".into())]);
 
     Ok(())
 }
 
-// ===========================================================================
-// WebSocket: WebSocketStream in-memory tests
-// ===========================================================================
-
-/// Ping/Pong frames are filtered transparently — recv skips them.
+/// Fallibly transform parsed events.
 ///
-/// Preconditions: stream contains Ping, Pong, Text, Close frames.
-/// Expected: recv() yields only the Text frame, then None after Close.
+/// Preconditions: upstream sends a numeric event, a non-numeric one, then
+///   another numeric one.
+/// Expected: `and_then_ok` yields the parsed numbers and the closure's error
+///   in place of the bad event; the stream continues after it.
 #[tokio::test]
-async fn websocket_stream_filters_ping_pong() -> TestResult {
-    // -- precondition: in-memory stream with control frames --------------------
-    let sink: WebSocketSink = Box::pin(
-        futures_util::sink::drain().sink_map_err(|e: std::convert::Infallible| match e {}),
-    );
-    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::iter(vec![
-        Ok(WebSocketMessage::Ping(vec![])),
-        Ok(WebSocketMessage::Pong(vec![])),
-        Ok(WebSocketMessage::Text("data".into())),
-        Ok(WebSocketMessage::Close(None)),
-    ]));
-
-    let mut ws: WebSocketStream = (sink, receiver).into();
+async fn sse_and_then_ok_yields_closure_errors() -> TestResult {
+    // -- precondition -----------------------------------------------------------
+    let resp = server_events_response(vec!["data: 1\n\n", "data: x\n\n", "data: 3\n\n"]);
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        return Err("expected an SSE response".into());
+    };
 
-    // -- verify: only Text frame is yielded ------------------------------------
-    let msg = ws.recv().await.expect("stream ended")?;
-    assert_eq!(msg, WebSocketMessage::Text("data".into()));
+    // -- action -----------------------------------------------------------------
+    let numbers = events.and_then_ok(|event| {
+        event
+            .data
+            .parse::<u32>()
+            .map_err(|e| StreamingError::ServerEventsParse {
+                detail: e.to_string(),
+            })
+    });
+    let items: Vec<_> = numbers.collect().await;
 
-    // -- verify: Close terminates the stream -----------------------------------
-    assert!(ws.recv().await.is_none());
+    // -- verify -----------------------------------------------------------------
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0].as_ref().ok(), Some(&1));
+    assert!(matches!(
+        items[1],
+        Err(StreamingError::ServerEventsParse { .. })
+    ));
+    assert_eq!(items[2].as_ref().ok(), Some(&3));
 
     Ok(())
 }
 
-/// Close frame terminates recv — returns None.
+/// Pass selected upstream headers through when re-emitting SSE.
 ///
-/// Preconditions: stream contains only a Close frame.
-/// Expected: first recv() returns None immediately.
+/// Preconditions: upstream SSE response carries `x-request-id` and two
+///   `x-ratelimit-remaining` values; `extra` also tries to override
+///   `content-type`.
+/// Expected: the custom headers survive with all their values, while the SSE
+///   `content-type` is kept.
+///
+/// Requires the `axum` feature.
+#[cfg(feature = "axum")]
 #[tokio::test]
-async fn websocket_stream_close_terminates() {
-    let sink: WebSocketSink = Box::pin(
-        futures_util::sink::drain().sink_map_err(|e: std::convert::Infallible| match e {}),
+async fn sse_into_response_with_headers_keeps_custom_headers() -> TestResult {
+    // -- precondition -----------------------------------------------------------
+    let mut resp = server_events_response(vec!["data: hi\n\n"]);
+    resp.headers_mut()
+        .insert("x-request-id", http::HeaderValue::from_static("req-1"));
+    resp.headers_mut()
+        .append("x-ratelimit-remaining", http::HeaderValue::from_static("9"));
+    resp.headers_mut().append(
+        "x-ratelimit-remaining",
+        http::HeaderValue::from_static("99"),
+    );
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        return Err("expected an SSE response".into());
+    };
+    let mut extra = http::HeaderMap::new();
+    for name in ["x-request-id", "x-ratelimit-remaining"] {
+        for value in events.headers().get_all(name) {
+            extra.append(name, value.clone());
+        }
+    }
+    extra.insert(
+        http::header::CONTENT_TYPE,
+        http::HeaderValue::from_static("application/json"),
     );
-    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::iter(vec![Ok(
-        WebSocketMessage::Close(None),
-    )]));
 
-    let mut ws: WebSocketStream = (sink, receiver).into();
+    // -- action -----------------------------------------------------------------
+    let response = events.into_response_with_headers(extra);
 
-    assert!(ws.recv().await.is_none());
+    // -- verify -----------------------------------------------------------------
+    let headers = response.headers();
+    assert_eq!(headers.get("x-request-id").unwrap(), "req-1");
+    let remaining: Vec<_> = headers.get_all("x-ratelimit-remaining").iter().collect();
+    assert_eq!(remaining, ["9", "99"]);
+    let content_types: Vec<_> = headers.get_all("content-type").iter().collect();
+    assert_eq!(content_types, ["text/event-stream"]);
+    let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+    assert_eq!(&body_bytes[..], b"data: hi\n\n");
+
+    Ok(())
 }
 
-/// JSON serialization round-trip via the `Json<T>` codec.
+/// Forward a typed JSON stream without dropping to raw events.
 ///
-/// Preconditions: `Json<T>` can serialize to a WebSocket message and deserialize back.
-/// Expected: `to_ws_message()` produces a Text frame; `from_ws_message()` recovers the value.
+/// Preconditions: upstream SSE parsed as `ServerEventsStream<Json<Usage>>`.
+/// Expected: `into_response()` re-serializes each `Usage` as the event's
+///   `data`, and parsing the response body yields the same values.
+///
+/// Requires the `axum` feature.
+#[cfg(feature = "axum")]
 #[tokio::test]
-async fn websocket_json_roundtrip() -> TestResult {
-    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
-    struct ChatMessage {
-        text: String,
+async fn sse_json_stream_into_response_round_trips() -> TestResult {
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Usage {
+        tokens: u32,
+        model: String,
     }
 
-    // -- action: serialize to WebSocket message --------------------------------
-    let outgoing = Json(ChatMessage {
-        text: "hello".into(),
-    });
-    let raw = outgoing.to_ws_message();
-    assert!(matches!(&raw, WebSocketMessage::Text(t) if t.contains("hello")));
+    // -- precondition -----------------------------------------------------------
+    let resp = server_events_response(vec![
+        "data: {\"tokens\":3,\"model\":\"a\"}\n\n",
+        "data: {\"tokens\":5,\"model\":\"b\"}\n\n",
+    ]);
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response::<Json<Usage>>(resp)
+    else {
+        return Err("expected an SSE response".into());
+    };
 
-    // -- action: deserialize back ----------------------------------------------
-    let parsed = <Json<ChatMessage>>::from_ws_message(raw)?;
-    assert_eq!(
+    // -- action -----------------------------------------------------------------
+    let response = events.into_response();
+    let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+
+    let wire = std::str::from_utf8(&body_bytes)?;
+
+    // -- verify: wire format ----------------------------------------------------
+    assert_eq!(
+        wire,
+        "data: {\"tokens\":3,\"model\":\"a\"}\n\ndata: {\"tokens\":5,\"model\":\"b\"}\n\n"
+    );
+
+    // -- verify: parses back to the same values ---------------------------------
+    let ServerEventsResponse::Events(reparsed) =
+        ServerEventsStream::from_response::<Json<Usage>>(server_events_response(vec![wire]))
+    else {
+        return Err("expected an SSE response".into());
+    };
+    let values: Vec<Usage> = reparsed.map_ok(|Json(u)| u).try_collect().await?;
+    assert_eq!(
+        values,
+        vec![
+            Usage {
+                tokens: 3,
+                model: "a".into(),
+            },
+            Usage {
+                tokens: 5,
+                model: "b".into(),
+            },
+        ]
+    );
+
+    Ok(())
+}
+
+/// Stop streaming to a client that went away.
+///
+/// Preconditions: upstream SSE stays open; the response was built with
+///   `into_response_until` and a shutdown signal.
+/// Expected: after the signal fires, the response body ends cleanly and the
+///   upstream stream is dropped, even though upstream never finished.
+///
+/// Requires the `axum` feature.
+#[cfg(feature = "axum")]
+#[tokio::test]
+async fn sse_response_stops_on_shutdown_signal() -> TestResult {
+    use http_body_util::BodyExt as _;
+
+    // -- precondition: an open upstream that has sent one event ------------------
+    let (tx, resp) = channel_server_events_response();
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        return Err("expected an SSE response".into());
+    };
+    tx.send(Bytes::from_static(b"data: first\n\n")).await?;
+    let (disconnect, disconnected) = tokio::sync::oneshot::channel::<()>();
+
+    // -- action -----------------------------------------------------------------
+    let mut body = events.into_response_until(disconnected).into_body();
+    let first = body.frame().await.ok_or("body ended early")??;
+    disconnect
+        .send(())
+        .map_err(|()| "response dropped the signal")?;
+
+    // -- verify -----------------------------------------------------------------
+    assert_eq!(first.into_data().unwrap(), "data: first\n\n");
+    assert!(body.frame().await.is_none(), "body must end after shutdown");
+    tokio::time::timeout(std::time::Duration::from_secs(1), tx.closed()).await?;
+
+    Ok(())
+}
+
+/// Emit SSE events imperatively through `sse_channel`.
+///
+/// Preconditions: a handler task owns the `SseWriter` half.
+/// Expected: events sent before `close()` appear in the response body in
+///   wire format, the body ends after close, and SSE headers are set.
+///
+/// Requires the `axum` feature.
+#[cfg(feature = "axum")]
+#[tokio::test]
+async fn sse_channel_streams_written_events() -> TestResult {
+    // -- action: write two events from a separate task, then close ---------------
+    let (writer, response) = oagw_sdk::sse::sse_channel();
+    let producer = tokio::spawn(async move {
+        for data in ["first", "second"] {
+            writer
+                .send(ServerEvent {
+                    data: data.into(),
+                    ..Default::default()
+                })
+                .await?;
+        }
+        writer.close();
+        Ok::<_, StreamingError>(())
+    });
+
+    // -- verify -----------------------------------------------------------------
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "text/event-stream"
+    );
+    let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+    assert_eq!(
+        std::str::from_utf8(&body_bytes)?,
+        "data: first\n\ndata: second\n\n"
+    );
+    producer.await??;
+
+    Ok(())
+}
+
+/// `SseWriter::send` reports a disconnected client.
+///
+/// Preconditions: the response returned by `sse_channel` is dropped.
+/// Expected: the writer observes the closure and `send` returns an error.
+///
+/// Requires the `axum` feature.
+#[cfg(feature = "axum")]
+#[tokio::test]
+async fn sse_channel_send_fails_after_client_disconnect() -> TestResult {
+    let (writer, response) = oagw_sdk::sse::sse_channel();
+
+    // -- action -----------------------------------------------------------------
+    drop(response);
+
+    // -- verify -----------------------------------------------------------------
+    assert!(writer.is_closed());
+    let err = writer.send(ServerEvent::default()).await.unwrap_err();
+    assert!(matches!(err, StreamingError::Stream(_)), "got: {err:?}");
+
+    Ok(())
+}
+
+/// Classify a response before deciding how to consume it.
+///
+/// Preconditions: one SSE and one JSON response.
+/// Expected: `inspect_response` reports `Sse`/`Other` by borrowing the
+///   response, which is then still available for `from_response`.
+#[tokio::test]
+async fn sse_inspect_response_does_not_consume() -> TestResult {
+    let sse = server_events_response(vec!["data: hi\n\n"]);
+    let json = http::Response::builder()
+        .header("content-type", "application/json")
+        .body(Body::from("{}"))?;
+
+    // -- action -----------------------------------------------------------------
+    let sse_kind = ServerEventsStream::inspect_response(&sse);
+    let json_kind = ServerEventsStream::inspect_response(&json);
+
+    // -- verify -----------------------------------------------------------------
+    assert_eq!(sse_kind, ResponseKind::Sse);
+    assert!(sse_kind.is_sse());
+    assert_eq!(json_kind, ResponseKind::Other);
+    assert!(json_kind.is_other());
+    assert!(matches!(
+        ServerEventsStream::from_response::<ServerEvent>(sse),
+        ServerEventsResponse::Events(_)
+    ));
+
+    Ok(())
+}
+
+/// Deployment-configured content types decide which responses are SSE.
+///
+/// Preconditions: rules that add `application/x-sse` to the SSE list; one
+///   upstream labels its stream that way, another sends NDJSON.
+/// Expected: the `application/x-sse` response parses as events; the NDJSON
+///   response is classified as such and returned unchanged.
+#[tokio::test]
+async fn sse_custom_content_types_route_responses() -> TestResult {
+    use oagw_sdk::content_type::{ContentTypeMatcher, StreamFormat, StreamingContentTypes};
+
+    let content_types = StreamingContentTypes::default()
+        .with_server_events(ContentTypeMatcher::exact("application/x-sse"));
+    let stream: BodyStream = Box::pin(futures_util::stream::iter(vec![Ok(Bytes::from(
+        "data: hi\n\n",
+    ))]));
+    let sse = http::Response::builder()
+        .header("content-type", "application/x-sse")
+        .body(Body::Stream(stream))?;
+    let ndjson = http::Response::builder()
+        .header("content-type", "application/x-ndjson")
+        .body(Body::from("{}\n"))?;
+
+    // -- verify ---------------------------------------------------------------
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response_with_content_types::<ServerEvent>(sse, &content_types)
+    else {
+        panic!("expected SSE stream");
+    };
+    let events: Vec<ServerEvent> = events.try_collect().await?;
+    assert_eq!(events[0].data, "hi");
+
+    assert_eq!(
+        content_types.format_of(ndjson.headers()),
+        Some(StreamFormat::NdJson)
+    );
+    assert!(matches!(
+        ServerEventsStream::from_response_with_content_types::<ServerEvent>(ndjson, &content_types),
+        ServerEventsResponse::Response(_)
+    ));
+
+    Ok(())
+}
+
+/// Custom response headers are accessible via `events.headers()`.
+///
+/// Preconditions: upstream returns SSE with a custom `x-request-id` header.
+/// Expected: header is preserved and accessible on the stream wrapper.
+#[tokio::test]
+async fn sse_stream_preserves_headers() -> TestResult {
+    // -- precondition: SSE response with custom header --------------------------
+    let stream: BodyStream = Box::pin(futures_util::stream::iter(vec![Ok(Bytes::from(
+        "data: test\n\n",
+    ))]));
+    let resp = http::Response::builder()
+        .status(200)
+        .header("content-type", "text/event-stream")
+        .header("x-request-id", "req-42")
+        .body(Body::Stream(stream))?;
+
+    // -- action -----------------------------------------------------------------
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        return Ok(());
+    };
+
+    // -- verify -----------------------------------------------------------------
+    assert_eq!(events.status(), 200);
+    assert_eq!(events.headers().get("x-request-id").unwrap(), "req-42");
+    assert_eq!(events.content_type(), Some("text/event-stream"));
+
+    Ok(())
+}
+
+/// Content-Type parameters survive SSE detection.
+///
+/// Preconditions: upstream sends `text/event-stream; charset=utf-8`.
+/// Expected: `content_type()` returns the full header value, parameters included.
+#[tokio::test]
+async fn sse_stream_content_type_with_parameters() -> TestResult {
+    let stream: BodyStream = Box::pin(futures_util::stream::iter(vec![Ok(Bytes::from(
+        "data: test\n\n",
+    ))]));
+    let resp = http::Response::builder()
+        .status(200)
+        .header("content-type", "text/event-stream; charset=utf-8")
+        .body(Body::Stream(stream))?;
+
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        panic!("expected SSE stream");
+    };
+
+    assert_eq!(
+        events.content_type(),
+        Some("text/event-stream; charset=utf-8")
+    );
+
+    Ok(())
+}
+
+/// Opt-in sniffing recognises SSE from an upstream that omits `Content-Type`.
+///
+/// Preconditions: no `Content-Type` header; body starts with a `data:` line split across chunks.
+/// Expected: `from_response_sniffing` yields events, including the peeked bytes.
+#[tokio::test]
+async fn sse_stream_sniffs_missing_content_type() -> TestResult {
+    let chunks: Vec<Result<Bytes, BoxError>> = vec![
+        Ok(Bytes::from("da")),
+        Ok(Bytes::from("ta: first\n\n")),
+        Ok(Bytes::from("data: second\n\n")),
+    ];
+    let resp = http::Response::builder()
+        .status(200)
+        .body(Body::Stream(Box::pin(futures_util::stream::iter(chunks))))?;
+
+    // -- action ----------------------------------------------------------------
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response_sniffing::<ServerEvent>(resp).await
+    else {
+        panic!("expected sniffed SSE stream");
+    };
+
+    // -- verify ----------------------------------------------------------------
+    let data: Vec<String> = events.map(|e| e.map(|e| e.data)).try_collect().await?;
+    assert_eq!(data, vec!["first", "second"]);
+
+    Ok(())
+}
+
+/// Sniffing falls back to the original response without losing bytes.
+///
+/// Preconditions: `application/octet-stream` body that is not SSE.
+/// Expected: `Response` variant whose body is byte-for-byte intact.
+#[tokio::test]
+async fn sse_stream_sniffing_negative_keeps_body() -> TestResult {
+    let chunks: Vec<Result<Bytes, BoxError>> = vec![
+        Ok(Bytes::from("\x00\x01binary")),
+        Ok(Bytes::from(" payload")),
+    ];
+    let resp = http::Response::builder()
+        .status(200)
+        .header("content-type", "application/octet-stream")
+        .body(Body::Stream(Box::pin(futures_util::stream::iter(chunks))))?;
+
+    let ServerEventsResponse::Response(resp) =
+        ServerEventsStream::from_response_sniffing::<ServerEvent>(resp).await
+    else {
+        panic!("expected non-SSE fallback");
+    };
+
+    let bytes = resp.into_body().into_bytes().await?;
+    assert_eq!(bytes, Bytes::from("\x00\x01binary payload"));
+
+    Ok(())
+}
+
+/// Sniffing never overrides a specific non-SSE content type.
+///
+/// Preconditions: `text/plain` body that starts with `data:`.
+/// Expected: `Response` variant, exactly like `from_response`.
+#[tokio::test]
+async fn sse_stream_sniffing_respects_explicit_content_type() -> TestResult {
+    let resp = http::Response::builder()
+        .status(200)
+        .header("content-type", "text/plain")
+        .body(Body::from("data: not really sse\n\n"))?;
+
+    let result = ServerEventsStream::from_response_sniffing::<ServerEvent>(resp).await;
+    assert!(matches!(result, ServerEventsResponse::Response(_)));
+
+    Ok(())
+}
+
+/// Vendor fields are captured when the decoder opts in.
+///
+/// Preconditions: upstream event mixing standard fields with `x-usage`.
+/// Expected: standard fields parse as usual; `x-usage` lands in `extra`.
+#[tokio::test]
+async fn sse_stream_captures_extra_fields_with_configured_decoder() -> TestResult {
+    let resp = server_events_response(vec!["event: usage\nx-usage: 42\ndata: {}\n\n"]);
+
+    let ServerEventsResponse::Events(mut events) =
+        ServerEventsStream::from_response_with_decoder::<ServerEvent>(
+            resp,
+            SseDecoder::new().with_extra_fields(true),
+        )
+    else {
+        panic!("expected SSE stream");
+    };
+
+    // -- verify ----------------------------------------------------------------
+    let event = events.next().await.expect("stream ended")?;
+    assert_eq!(event.event.as_deref(), Some("usage"));
+    assert_eq!(event.data, "{}");
+    assert_eq!(event.extra.get("x-usage").map(String::as_str), Some("42"));
+
+    Ok(())
+}
+
+/// Keep-alive comments reach a comment observer set on the decoder.
+///
+/// Preconditions: upstream sends a `: ping` keep-alive before an event.
+/// Expected: the observer receives `ping`; the stream yields only the event.
+#[tokio::test]
+async fn sse_stream_reports_keepalive_comments() -> TestResult {
+    let resp = server_events_response(vec![": ping\n\n", "data: a\n\n"]);
+    let comments = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&comments);
+
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response_with_decoder::<ServerEvent>(
+            resp,
+            SseDecoder::new()
+                .with_comment_observer(move |text| sink.lock().unwrap().push(text.to_owned())),
+        )
+    else {
+        panic!("expected SSE stream");
+    };
+
+    // -- verify ----------------------------------------------------------------
+    let data: Vec<String> = events.map_ok(|e| e.data).try_collect().await?;
+    assert_eq!(data, ["a"]);
+    assert_eq!(*comments.lock().unwrap(), ["ping"]);
+
+    Ok(())
+}
+
+/// `stats` counts parsed events, raw bytes and errors as the stream is read.
+///
+/// Preconditions: upstream sends two events over three chunks, one with
+///   CRLF line endings, then invalid UTF-8.
+/// Expected: counters start at zero and grow while polling; the final stats
+///   report 2 events, every raw byte, and 1 error.
+#[tokio::test]
+async fn sse_stats_count_events_bytes_and_errors() -> TestResult {
+    let chunks: Vec<Result<Bytes, BoxError>> = vec![
+        Ok(Bytes::from_static(b"data: a\r\n\r\n")),
+        Ok(Bytes::from_static(b"data: b")),
+        Ok(Bytes::from_static(b"\n\n\xFF")),
+    ];
+    let resp = http::Response::builder()
+        .header("content-type", "text/event-stream")
+        .body(Body::Stream(Box::pin(futures_util::stream::iter(chunks))))?;
+    let ServerEventsResponse::Events(mut events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        panic!("expected SSE stream");
+    };
+
+    // -- verify ----------------------------------------------------------------
+    assert_eq!(events.stats(), oagw_sdk::sse::SseStats::default());
+
+    events.next().await.expect("stream ended")?;
+    assert_eq!(events.stats().events, 1);
+    assert_eq!(events.stats().bytes, 11);
+
+    while events.next().await.is_some() {}
+    assert_eq!(
+        events.stats(),
+        oagw_sdk::sse::SseStats {
+            events: 2,
+            bytes: 11 + 7 + 3,
+            errors: 1,
+        }
+    );
+
+    Ok(())
+}
+
+/// `take_events` bounds consumption while keeping the stream wrapper.
+///
+/// Preconditions: upstream sends three events with a custom header.
+/// Expected: `take_events(2)` yields the first two events, then ends;
+///   status and headers remain readable.
+#[tokio::test]
+async fn sse_take_events_limits_and_keeps_metadata() -> TestResult {
+    let mut resp = server_events_response(vec!["data: 1\n\n", "data: 2\n\n", "data: 3\n\n"]);
+    resp.headers_mut().insert("x-request-id", "abc".parse()?);
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        panic!("expected SSE stream");
+    };
+
+    // -- action -----------------------------------------------------------------
+    let mut events = events.take_events(2);
+
+    // -- verify -----------------------------------------------------------------
+    assert_eq!(events.status(), http::StatusCode::OK);
+    assert_eq!(events.headers()["x-request-id"], "abc");
+    let mut data = Vec::new();
+    while let Some(event) = events.next().await {
+        data.push(event?.data);
+    }
+    assert_eq!(data, ["1", "2"]);
+
+    Ok(())
+}
+
+/// `take_events` counts only successful items.
+///
+/// Preconditions: upstream sends five events; the second fails conversion.
+/// Expected: `take_events(2)` yields the first value, the error, and the
+///   third value, then ends — the error does not use up the budget.
+#[tokio::test]
+async fn sse_take_events_counts_only_ok_items() -> TestResult {
+    // -- precondition -----------------------------------------------------------
+    let resp = server_events_response(vec![
+        "data: 1\n\n",
+        "data: x\n\n",
+        "data: 2\n\n",
+        "data: 3\n\n",
+        "data: 4\n\n",
+    ]);
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        return Err("expected an SSE response".into());
+    };
+    let numbers = events.and_then_ok(|event| {
+        event
+            .data
+            .parse::<u32>()
+            .map_err(|e| StreamingError::ServerEventsParse {
+                detail: e.to_string(),
+            })
+    });
+
+    // -- action -----------------------------------------------------------------
+    let items: Vec<_> = numbers.take_events(2).collect().await;
+
+    // -- verify -----------------------------------------------------------------
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0].as_ref().ok(), Some(&1));
+    assert!(items[1].is_err());
+    assert_eq!(items[2].as_ref().ok(), Some(&2));
+
+    Ok(())
+}
+
+/// `current_retry` tracks the latest server `retry:` and feeds the reconnect
+/// policy.
+///
+/// Preconditions: upstream sends an event without `retry:`, then `retry: 500`,
+/// then an event without `retry:`, then `retry: 2000`.
+/// Expected: `None` until the first `retry:`, the value persists across later
+/// events, and a newer `retry:` replaces it; the policy uses it as its base.
+#[tokio::test]
+async fn sse_current_retry_tracks_server_value() -> TestResult {
+    let resp = server_events_response(vec![
+        "data: a\n\n",
+        "retry: 500\ndata: b\n\n",
+        "data: c\n\n",
+        "retry: 2000\ndata: d\n\n",
+    ]);
+    let ServerEventsResponse::Events(mut events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        panic!("expected SSE stream");
+    };
+    let policy = ReconnectPolicy::new(
+        std::time::Duration::from_millis(100),
+        std::time::Duration::from_secs(10),
+    );
+
+    // -- verify ----------------------------------------------------------------
+    assert_eq!(events.current_retry(), None);
+    assert_eq!(
+        policy.delay(0, events.current_retry()),
+        std::time::Duration::from_millis(100)
+    );
+
+    events.next().await.expect("stream ended")?;
+    assert_eq!(events.current_retry(), None);
+
+    events.next().await.expect("stream ended")?;
+    let retry = Some(std::time::Duration::from_millis(500));
+    assert_eq!(events.current_retry(), retry);
+    assert_eq!(
+        policy.delay(1, events.current_retry()),
+        std::time::Duration::from_millis(1000)
+    );
+
+    events.next().await.expect("stream ended")?;
+    assert_eq!(events.current_retry(), retry);
+
+    events.next().await.expect("stream ended")?;
+    assert_eq!(
+        events.current_retry(),
+        Some(std::time::Duration::from_secs(2))
+    );
+
+    Ok(())
+}
+
+/// Reconnect transparently after a failed connect and a dropped stream.
+///
+/// Preconditions: the connect closure fails on its first call, then returns
+///   one-event SSE streams that end after the event.
+/// Expected: `next()` yields the events of both streams in order; the
+///   closure is called with no id until one is seen, then with the last
+///   `id:` as the `Last-Event-ID` to send.
+#[tokio::test]
+async fn sse_reconnecting_retries_failed_connect_and_resumes() -> TestResult {
+    // -- precondition -----------------------------------------------------------
+    let calls: Arc<Mutex<Vec<Option<String>>>> = Arc::default();
+    let seen = Arc::clone(&calls);
+    let mut events = ReconnectingServerEvents::new(move |last_event_id| {
+        let attempt = {
+            let mut calls = seen.lock().unwrap();
+            calls.push(last_event_id);
+            calls.len()
+        };
+        async move {
+            match attempt {
+                1 => Err(ServiceGatewayError::ConnectionTimeout {
+                    detail: "upstream unreachable".into(),
+                    instance: String::new(),
+                }),
+                2 => Ok(server_events_response(vec!["id: 1\ndata: a\n\n"])),
+                _ => Ok(server_events_response(vec!["id: 2\ndata: b\n\n"])),
+            }
+        }
+    })
+    .with_policy(ReconnectPolicy::new(
+        std::time::Duration::from_millis(1),
+        std::time::Duration::from_millis(10),
+    ));
+
+    // -- action -----------------------------------------------------------------
+    let first = events.next().await.ok_or("ended early")??;
+    let second = events.next().await.ok_or("ended early")??;
+
+    // -- verify -----------------------------------------------------------------
+    assert_eq!(first.data, "a");
+    assert_eq!(second.data, "b");
+    assert_eq!(events.last_event_id(), Some("2"));
+    assert_eq!(*calls.lock().unwrap(), [None, None, Some("1".to_owned())]);
+
+    Ok(())
+}
+
+/// Give up reconnecting after repeated connect failures.
+///
+/// Preconditions: the connect closure always fails; `with_max_attempts(2)`.
+/// Expected: after two calls, `next()` yields the gateway error once and then
+///   ends.
+#[tokio::test]
+async fn sse_reconnecting_gives_up_after_max_attempts() -> TestResult {
+    // -- precondition -----------------------------------------------------------
+    let calls = Arc::new(Mutex::new(0));
+    let seen = Arc::clone(&calls);
+    let mut events = ReconnectingServerEvents::new(move |_| {
+        *seen.lock().unwrap() += 1;
+        async {
+            Err(ServiceGatewayError::ConnectionTimeout {
+                detail: "upstream unreachable".into(),
+                instance: String::new(),
+            })
+        }
+    })
+    .with_policy(ReconnectPolicy::new(
+        std::time::Duration::from_millis(1),
+        std::time::Duration::from_millis(10),
+    ))
+    .with_max_attempts(2);
+
+    // -- action -----------------------------------------------------------------
+    let err = events.next().await.ok_or("ended without an error")?;
+
+    // -- verify -----------------------------------------------------------------
+    let Err(StreamingError::Stream(source)) = err else {
+        return Err(format!("expected a wrapped gateway error, got {err:?}").into());
+    };
+    assert!(source.is::<ServiceGatewayError>());
+    assert!(events.next().await.is_none());
+    assert_eq!(*calls.lock().unwrap(), 2);
+
+    Ok(())
+}
+
+/// `suggested_retry` updates on each valid `retry:` and ignores values the
+/// parser rejects.
+///
+/// Preconditions: upstream sends `retry: 500`, then non-numeric and empty
+///   `retry:` fields, then `retry: 2000`.
+/// Expected: the invalid fields leave the last valid value in place, as a
+///   browser keeps its reconnection time; the new valid value replaces it.
+#[tokio::test]
+async fn sse_suggested_retry_ignores_invalid_values() -> TestResult {
+    let resp = server_events_response(vec![
+        "retry: 500\ndata: a\n\n",
+        "retry: 1000x\ndata: b\n\n",
+        "retry:\ndata: c\n\n",
+        "retry: 2000\ndata: d\n\n",
+    ]);
+    let ServerEventsResponse::Events(mut events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        panic!("expected SSE stream");
+    };
+
+    // -- verify ----------------------------------------------------------------
+    let mut seen = Vec::new();
+    while let Some(event) = events.next().await {
+        event?;
+        assert_eq!(events.suggested_retry(), events.current_retry());
+        seen.push(events.suggested_retry());
+    }
+    let retry = Some(std::time::Duration::from_millis(500));
+    let updated = Some(std::time::Duration::from_millis(2000));
+    assert_eq!(seen, [retry, retry, retry, updated]);
+
+    Ok(())
+}
+
+/// `last_event_id` carries the latest `id:` across events that omit it.
+///
+/// Preconditions: upstream sends an event without `id:`, then `id: 1`, an
+///   event without `id:`, then `id: 2`.
+/// Expected: `None` until the first `id:`, the value persists across later
+///   events, and a newer `id:` replaces it.
+#[tokio::test]
+async fn sse_last_event_id_carries_over() -> TestResult {
+    let resp = server_events_response(vec![
+        "data: a\n\n",
+        "id: 1\ndata: b\n\n",
+        "data: c\n\n",
+        "id: 2\ndata: d\n\n",
+    ]);
+    let ServerEventsResponse::Events(mut events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        panic!("expected SSE stream");
+    };
+
+    // -- verify ----------------------------------------------------------------
+    let mut seen = vec![events.last_event_id().map(str::to_owned)];
+    while let Some(event) = events.next().await {
+        event?;
+        seen.push(events.last_event_id().map(str::to_owned));
+    }
+    let expected = [None, None, Some("1"), Some("1"), Some("2")];
+    assert_eq!(seen, expected.map(|id| id.map(str::to_owned)));
+
+    Ok(())
+}
+
+/// An explicit empty `id:` resets `last_event_id` to `Some("")`.
+///
+/// Preconditions: upstream sends `id: 7`, then a bare `id:` line.
+/// Expected: the id is `Some("7")`, then `Some("")`; it does not revert to
+///   `None` or keep the stale value.
+#[tokio::test]
+async fn sse_last_event_id_empty_resets() -> TestResult {
+    let resp = server_events_response(vec!["id: 7\ndata: a\n\n", "id\ndata: b\n\n"]);
+    let ServerEventsResponse::Events(mut events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        panic!("expected SSE stream");
+    };
+
+    // -- verify ----------------------------------------------------------------
+    events.next().await.expect("stream ended")?;
+    assert_eq!(events.last_event_id(), Some("7"));
+
+    events.next().await.expect("stream ended")?;
+    assert_eq!(events.last_event_id(), Some(""));
+
+    Ok(())
+}
+
+/// Fan-in: `merge` interleaves events from several upstreams as they arrive.
+///
+/// Preconditions: two channel-backed upstreams; the second has status 202
+///   and is chosen as the head. Events are pushed alternately and the first
+///   upstream ends early.
+/// Expected: events come out in arrival order, the merged stream continues
+///   after the first upstream ends, and reports the head's status/headers.
+#[tokio::test]
+async fn sse_merge_interleaves_until_all_sources_end() -> TestResult {
+    let (tx_a, resp_a) = channel_server_events_response();
+    let (tx_b, resp_b) = channel_server_events_response();
+    let (mut parts, body) = resp_b.into_parts();
+    parts.status = http::StatusCode::ACCEPTED;
+    parts.headers.insert("x-upstream", "b".parse()?);
+    let resp_b = http::Response::from_parts(parts, body);
+    let (ServerEventsResponse::Events(a), ServerEventsResponse::Events(b)) = (
+        ServerEventsStream::from_response::<ServerEvent>(resp_a),
+        ServerEventsStream::from_response::<ServerEvent>(resp_b),
+    ) else {
+        panic!("expected SSE streams");
+    };
+
+    // -- action ----------------------------------------------------------------
+    let mut merged = ServerEventsStream::merge_with(vec![a, b], MergePolicy::UntilAllEnd, 1);
+    assert_eq!(merged.status(), http::StatusCode::ACCEPTED);
+    assert_eq!(
+        merged.headers().get("x-upstream").map(|v| v.as_bytes()),
+        Some(&b"b"[..])
+    );
+
+    tx_a.send(Bytes::from("data: a1\n\n")).await?;
+    let first = merged.next().await.expect("stream ended")?;
+    tx_b.send(Bytes::from("data: b1\n\n")).await?;
+    let second = merged.next().await.expect("stream ended")?;
+    drop(tx_a);
+    tx_b.send(Bytes::from("data: b2\n\n")).await?;
+    let third = merged.next().await.expect("stream ended")?;
+    drop(tx_b);
+
+    // -- verify ----------------------------------------------------------------
+    assert_eq!(
+        [first.data, second.data, third.data],
+        ["a1".to_owned(), "b1".to_owned(), "b2".to_owned()]
+    );
+    assert!(merged.next().await.is_none());
+
+    Ok(())
+}
+
+/// `MergePolicy::UntilFirstEnd` stops with the first source, and any error
+/// ends the merged stream.
+///
+/// Preconditions: (1) two sources, one finite and one that never ends;
+///   (2) a source whose typed conversion fails, beside a healthy one.
+/// Expected: (1) the finite source's events, then the end of the merged
+///   stream; (2) the error is yielded once and the stream ends.
+#[tokio::test]
+async fn sse_merge_policy_and_error_propagation() -> TestResult {
+    let finite = server_events_response(vec!["data: x\n\n"]);
+    let (_tx_open, open) = channel_server_events_response();
+    let (ServerEventsResponse::Events(finite), ServerEventsResponse::Events(open)) = (
+        ServerEventsStream::from_response::<ServerEvent>(finite),
+        ServerEventsStream::from_response::<ServerEvent>(open),
+    ) else {
+        panic!("expected SSE streams");
+    };
+
+    // -- verify: first end stops the merge ---------------------------------------
+    let merged = ServerEventsStream::merge_with(vec![finite, open], MergePolicy::UntilFirstEnd, 0);
+    let events: Vec<ServerEvent> = merged.try_collect().await?;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].data, "x");
+
+    // -- verify: the first error ends the merge ----------------------------------
+    let bad = server_events_response(vec!["data: not json\n\n"]);
+    let (_tx_open, open) = channel_server_events_response();
+    let (ServerEventsResponse::Events(bad), ServerEventsResponse::Events(open)) = (
+        ServerEventsStream::from_response::<Json<serde_json::Value>>(bad),
+        ServerEventsStream::from_response::<Json<serde_json::Value>>(open),
+    ) else {
+        panic!("expected SSE streams");
+    };
+    let mut merged = ServerEventsStream::merge(vec![bad, open]);
+    let err = merged.next().await.expect("stream ended").unwrap_err();
+    assert!(
+        matches!(err, StreamingError::ServerEventsParse { .. }),
+        "got {err:?}"
+    );
+    assert!(merged.next().await.is_none());
+
+    Ok(())
+}
+
+/// Idle timeout ends a stalled stream with an error.
+///
+/// Preconditions: upstream sends one event, then stays open but silent.
+/// Expected: the event, then `IdleTimeout`, then end of stream.
+#[tokio::test]
+async fn sse_idle_timeout_fires_on_stalled_stream() -> TestResult {
+    let (tx, resp) = channel_server_events_response();
+    tx.send(Bytes::from("data: a\n\n")).await?;
+
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        panic!("expected SSE stream");
+    };
+    let mut events = events.with_idle_timeout(std::time::Duration::from_millis(50));
+
+    // -- verify ----------------------------------------------------------------
+    assert_eq!(events.next().await.expect("stream ended")?.data, "a");
+    let err = events.next().await.expect("stream ended").unwrap_err();
+    assert!(matches!(err, StreamingError::IdleTimeout { .. }));
+    assert!(events.next().await.is_none());
+
+    drop(tx);
+    Ok(())
+}
+
+/// By default, keep-alive comments count as liveness.
+///
+/// Preconditions: upstream sends `: ping` heartbeats for longer than the
+/// timeout before the first event.
+/// Expected: the event arrives; no timeout.
+#[tokio::test]
+async fn sse_idle_timeout_heartbeats_keep_stream_alive() -> TestResult {
+    let (tx, resp) = channel_server_events_response();
+    spawn_trickle(
+        tx,
+        ": ping\n\n",
+        15,
+        std::time::Duration::from_millis(10),
+        "data: b\n\n",
+    );
+
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        panic!("expected SSE stream");
+    };
+    let mut events = events.with_idle_timeout(std::time::Duration::from_millis(100));
+
+    assert_eq!(events.next().await.expect("stream ended")?.data, "b");
+
+    Ok(())
+}
+
+/// By default, any received chunk counts as liveness, even mid-event.
+///
+/// Preconditions: upstream trickles one event body a byte at a time for
+/// longer than the timeout.
+/// Expected: the event arrives; no timeout.
+#[tokio::test]
+async fn sse_idle_timeout_partial_chunks_keep_stream_alive() -> TestResult {
+    let (tx, resp) = channel_server_events_response();
+    tx.send(Bytes::from("data: ")).await?;
+    spawn_trickle(tx, "x", 15, std::time::Duration::from_millis(10), "\n\n");
+
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        panic!("expected SSE stream");
+    };
+    let mut events = events.with_idle_timeout(std::time::Duration::from_millis(100));
+
+    assert_eq!(
+        events.next().await.expect("stream ended")?.data,
+        "x".repeat(15)
+    );
+
+    Ok(())
+}
+
+/// `IdleActivity::EventsOnly` does not treat heartbeats as liveness.
+///
+/// Preconditions: same heartbeat-only upstream as above.
+/// Expected: `IdleTimeout` before the event is delivered.
+#[tokio::test]
+async fn sse_idle_timeout_events_only_ignores_heartbeats() -> TestResult {
+    let (tx, resp) = channel_server_events_response();
+    spawn_trickle(
+        tx,
+        ": ping\n\n",
+        15,
+        std::time::Duration::from_millis(10),
+        "data: b\n\n",
+    );
+
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        panic!("expected SSE stream");
+    };
+    let mut events = events
+        .with_idle_timeout(std::time::Duration::from_millis(100))
+        .with_idle_activity(IdleActivity::EventsOnly);
+
+    let err = events.next().await.expect("stream ended").unwrap_err();
+    assert!(matches!(err, StreamingError::IdleTimeout { .. }));
+
+    Ok(())
+}
+
+/// `IdleActivity::EventsAndComments` counts heartbeats but not partial data.
+///
+/// Preconditions: upstream trickles an event body without ever finishing it.
+/// Expected: `IdleTimeout`, even though bytes keep arriving.
+#[tokio::test]
+async fn sse_idle_timeout_partial_data_is_not_a_heartbeat() -> TestResult {
+    let (tx, resp) = channel_server_events_response();
+    tx.send(Bytes::from("data: ")).await?;
+    spawn_trickle(tx, "x", 15, std::time::Duration::from_millis(10), "\n\n");
+
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        panic!("expected SSE stream");
+    };
+    let mut events = events
+        .with_idle_timeout(std::time::Duration::from_millis(100))
+        .with_idle_activity(IdleActivity::EventsAndComments);
+
+    let err = events.next().await.expect("stream ended").unwrap_err();
+    assert!(matches!(err, StreamingError::IdleTimeout { .. }));
+
+    Ok(())
+}
+
+/// Full integration pattern: gateway client → ServerEventsStream.
+///
+/// Preconditions: `ServiceGatewayClientV1` returns an SSE response from `proxy_request`.
+/// Expected: wrap the response into a typed event stream.
+#[tokio::test]
+async fn sse_via_gateway_client() -> TestResult {
+    // -- setup: mock gateway returns an SSE response ----------------------------
+    let gateway = MockGateway::responding_with(server_events_response(vec![
+        "data: {\"status\":\"processing\"}\n\n",
+        "data: {\"status\":\"complete\"}\n\n",
+    ]));
+
+    // -- action: call proxy_request and wrap into ServerEventsStream -------------
+    let req = http::Request::get("/api/oagw/v1/proxy/openai/chat/completions").body(Body::Empty)?;
+    let resp = gateway
+        .proxy_request(SecurityContext::anonymous(), req)
+        .await?;
+
+    let ServerEventsResponse::Events(mut events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        return Ok(());
+    };
+
+    // -- verify -----------------------------------------------------------------
+    let first = events.next().await.expect("stream ended")?;
+    assert_eq!(first.data, r#"{"status":"processing"}"#);
+
+    let second = events.next().await.expect("stream ended")?;
+    assert_eq!(second.data, r#"{"status":"complete"}"#);
+
+    Ok(())
+}
+
+// ===========================================================================
+// WebSocket: WebSocketStream in-memory tests
+// ===========================================================================
+
+/// Ping/Pong frames are filtered transparently — recv skips them.
+///
+/// Preconditions: stream contains Ping, Pong, Text, Close frames.
+/// Expected: recv() yields only the Text frame, then None after Close.
+#[tokio::test]
+async fn websocket_stream_filters_ping_pong() -> TestResult {
+    // -- precondition: in-memory stream with control frames --------------------
+    let sink: WebSocketSink = Box::pin(
+        futures_util::sink::drain().sink_map_err(|e: std::convert::Infallible| match e {}),
+    );
+    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::iter(vec![
+        Ok(WebSocketMessage::Ping(vec![])),
+        Ok(WebSocketMessage::Pong(vec![])),
+        Ok(WebSocketMessage::Text("data".into())),
+        Ok(WebSocketMessage::Close(None)),
+    ]));
+
+    let mut ws: WebSocketStream = (sink, receiver).into();
+
+    // -- verify: only Text frame is yielded ------------------------------------
+    let msg = ws.recv().await.expect("stream ended")?;
+    assert_eq!(msg, WebSocketMessage::Text("data".into()));
+
+    // -- verify: Close terminates the stream -----------------------------------
+    assert!(ws.recv().await.is_none());
+
+    Ok(())
+}
+
+/// Incoming Pings are answered with a Pong carrying the same payload.
+///
+/// Preconditions: the peer sends a Ping with a payload, then a Text frame,
+///   on a stream with a channel-backed sink.
+/// Expected: `recv` yields only the Text frame, and the sink received a Pong
+///   echoing the Ping payload.
+#[tokio::test]
+async fn websocket_stream_answers_ping_with_pong() -> TestResult {
+    // -- precondition -----------------------------------------------------------
+    let (sink, mut sent) = channel_sink();
+    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::iter(vec![
+        Ok(WebSocketMessage::Ping(vec![7, 8])),
+        Ok(WebSocketMessage::Text("data".into())),
+    ]));
+    let mut ws: WebSocketStream = (sink, receiver).into();
+
+    // -- action -----------------------------------------------------------------
+    let msg = ws.recv().await.ok_or("stream ended")??;
+
+    // -- verify -----------------------------------------------------------------
+    assert_eq!(msg, WebSocketMessage::Text("data".into()));
+    assert_eq!(sent.try_recv()?, WebSocketMessage::Pong(vec![7, 8]));
+
+    Ok(())
+}
+
+/// The split receive half answers Pings through the shared sink.
+///
+/// Preconditions: the stream is split; the peer sends a Ping while only the
+///   receive half is polled.
+/// Expected: a Pong reaches the sink without the send half doing anything,
+///   and messages sent through the send half still go out after it.
+#[tokio::test]
+async fn websocket_split_receiver_answers_ping() -> TestResult {
+    // -- precondition -----------------------------------------------------------
+    let (sink, mut sent) = channel_sink();
+    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::iter(vec![
+        Ok(WebSocketMessage::Ping(b"keepalive".to_vec())),
+        Ok(WebSocketMessage::Close(None)),
+    ]));
+    let ws: WebSocketStream = (sink, receiver).into();
+    let (mut tx, mut rx) = ws.split();
+
+    // -- action -----------------------------------------------------------------
+    assert!(rx.recv().await.is_none());
+    tx.send_text("after").await?;
+
+    // -- verify -----------------------------------------------------------------
+    assert_eq!(
+        sent.recv().await,
+        Some(WebSocketMessage::Pong(b"keepalive".to_vec()))
+    );
+    assert_eq!(
+        sent.recv().await,
+        Some(WebSocketMessage::Text("after".into()))
+    );
+
+    Ok(())
+}
+
+/// A heartbeat pings an idle connection and stops when it is closed.
+///
+/// Preconditions: a connection whose peer sends nothing; heartbeat every
+///   20ms.
+/// Expected: at least one empty Ping reaches the sink alongside a user
+///   message; after `close` only the Close frame follows.
+#[tokio::test]
+async fn websocket_heartbeat_sends_pings_until_close() -> TestResult {
+    let (sink, mut sent) = channel_sink();
+    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::pending());
+    let ws: WebSocketStream = (sink, receiver).into();
+
+    // -- action -----------------------------------------------------------------
+    let mut ws = ws.with_heartbeat(std::time::Duration::from_millis(20));
+    ws.send_text("user").await?;
+
+    // -- verify: a Ping arrives within the window -------------------------------
+    let mut seen = Vec::new();
+    tokio::time::timeout(std::time::Duration::from_secs(1), async {
+        while let Some(msg) = sent.recv().await {
+            let ping = msg == WebSocketMessage::Ping(Vec::new());
+            seen.push(msg);
+            if ping {
+                break;
+            }
+        }
+    })
+    .await?;
+    assert!(seen.contains(&WebSocketMessage::Text("user".into())));
+
+    // -- verify: close stops the heartbeat --------------------------------------
+    ws.close().await?;
+    let mut tail = Vec::new();
+    while let Some(msg) = sent.recv().await {
+        tail.push(msg);
+    }
+    let close_at = tail
+        .iter()
+        .position(|m| matches!(m, WebSocketMessage::Close(_)))
+        .ok_or("Close not sent")?;
+    assert_eq!(close_at, tail.len() - 1, "frames after Close: {tail:?}");
+
+    Ok(())
+}
+
+/// `recv_timeout` reports a stalled peer without ending the stream.
+///
+/// Preconditions: a receiver that yields nothing until a message is pushed
+///   through a channel, then closes.
+/// Expected: the first call times out with `WebSocketRecvTimeout`; after the
+///   peer sends, the next call returns the message, and the one after returns
+///   `None` for the close.
+#[tokio::test]
+async fn websocket_recv_timeout_distinguishes_stall_from_close() -> TestResult {
+    let (peer, incoming) = tokio::sync::mpsc::unbounded_channel();
+    let receiver: WebSocketReceiver =
+        Box::pin(futures_util::stream::unfold(incoming, |mut rx| async {
+            rx.recv().await.map(|msg| (Ok(msg), rx))
+        }));
+    let (sink, _sent) = channel_sink();
+    let mut ws: WebSocketStream = (sink, receiver).into();
+    let timeout = std::time::Duration::from_millis(20);
+
+    // -- action: peer is silent ---------------------------------------------------
+    let err = ws
+        .recv_timeout(timeout)
+        .await
+        .ok_or("stream ended")?
+        .unwrap_err();
+    assert!(
+        matches!(err, StreamingError::WebSocketRecvTimeout { timeout: t } if t == timeout),
+        "got {err:?}"
+    );
+
+    // -- action: peer sends, then closes ------------------------------------------
+    peer.send(WebSocketMessage::Text("late".into()))?;
+    peer.send(WebSocketMessage::Close(None))?;
+
+    // -- verify -------------------------------------------------------------------
+    assert_eq!(
+        ws.recv_timeout(timeout).await.transpose()?,
+        Some(WebSocketMessage::Text("late".into()))
+    );
+    assert!(ws.recv_timeout(timeout).await.is_none());
+
+    Ok(())
+}
+
+/// Close frame terminates recv — returns None.
+///
+/// Preconditions: stream contains only a Close frame.
+/// Expected: first recv() returns None immediately.
+#[tokio::test]
+async fn websocket_stream_close_terminates() {
+    let sink: WebSocketSink = Box::pin(
+        futures_util::sink::drain().sink_map_err(|e: std::convert::Infallible| match e {}),
+    );
+    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::iter(vec![Ok(
+        WebSocketMessage::Close(None),
+    )]));
+
+    let mut ws: WebSocketStream = (sink, receiver).into();
+
+    assert!(ws.recv().await.is_none());
+}
+
+/// The peer's Close frame stays readable after `recv` returns `None`.
+///
+/// Preconditions: the peer sends a Text frame, then Close with code 1011 and
+///   a reason.
+/// Expected: `close_frame()` is `None` while open; after `recv` returns
+///   `None` it reports the code and reason. The split receive half behaves
+///   the same when drained through its `Stream` impl.
+#[tokio::test]
+async fn websocket_close_frame_is_retrievable() -> TestResult {
+    let internal_error = WebSocketCloseFrame {
+        code: 1011,
+        reason: "upstream crashed".into(),
+    };
+    let frames = || -> WebSocketReceiver {
+        Box::pin(futures_util::stream::iter(vec![
+            Ok(WebSocketMessage::Text("data".into())),
+            Ok(WebSocketMessage::Close(Some(WebSocketCloseFrame {
+                code: 1011,
+                reason: "upstream crashed".into(),
+            }))),
+        ]))
+    };
+
+    // -- action: unsplit stream ---------------------------------------------------
+    let (sink, _sent) = channel_sink();
+    let mut ws: WebSocketStream = (sink, frames()).into();
+    assert!(ws.recv().await.transpose()?.is_some());
+    assert_eq!(ws.close_frame(), None);
+    assert!(ws.recv().await.is_none());
+
+    // -- verify -------------------------------------------------------------------
+    let frame = ws.close_frame().ok_or("close frame not recorded")?;
+    assert_eq!(frame.code, 1011);
+    assert_eq!(frame.reason, "upstream crashed");
+
+    // -- action: split receive half -------------------------------------------------
+    let (sink, _sent) = channel_sink();
+    let ws: WebSocketStream = (sink, frames()).into();
+    let (_tx, mut rx) = ws.split();
+    while rx.next().await.transpose()?.is_some() {}
+
+    // -- verify -------------------------------------------------------------------
+    assert_eq!(rx.close_frame(), Some(&internal_error));
+
+    Ok(())
+}
+
+/// `recv_event` reports the Close frame in-band, then ends.
+///
+/// Preconditions: stream contains a Ping, a Text frame, a Close frame with
+///   code 1001, and a trailing Text frame.
+/// Expected: `Message`, then `Closed` with the code and reason, then `None`;
+///   a split receiver whose stream ends without Close reports `Closed(None)`.
+#[tokio::test]
+async fn websocket_recv_event_surfaces_close_frame() -> TestResult {
+    use oagw_sdk::ws::WsEvent;
+
+    let going_away = WebSocketCloseFrame {
+        code: 1001,
+        reason: "server restart".into(),
+    };
+    let sink: WebSocketSink = Box::pin(
+        futures_util::sink::drain().sink_map_err(|e: std::convert::Infallible| match e {}),
+    );
+    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::iter(vec![
+        Ok(WebSocketMessage::Ping(vec![])),
+        Ok(WebSocketMessage::Text("data".into())),
+        Ok(WebSocketMessage::Close(Some(going_away.clone()))),
+        Ok(WebSocketMessage::Text("after close".into())),
+    ]));
+    let mut ws: WebSocketStream = (sink, receiver).into();
+
+    // -- verify ---------------------------------------------------------------
+    assert_eq!(
+        ws.recv_event().await.transpose()?,
+        Some(WsEvent::Message(WebSocketMessage::Text("data".into())))
+    );
+    assert_eq!(
+        ws.recv_event().await.transpose()?,
+        Some(WsEvent::Closed(Some(going_away)))
+    );
+    assert!(ws.recv_event().await.is_none());
+
+    // -- action: stream ends without a Close frame -----------------------------
+    let sink: WebSocketSink = Box::pin(
+        futures_util::sink::drain().sink_map_err(|e: std::convert::Infallible| match e {}),
+    );
+    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::empty());
+    let (_tx, mut rx) = WebSocketStream::from((sink, receiver)).split();
+
+    // -- verify ---------------------------------------------------------------
+    assert_eq!(
+        rx.recv_event().await.transpose()?,
+        Some(WsEvent::Closed(None))
+    );
+    assert!(rx.recv_event().await.is_none());
+
+    Ok(())
+}
+
+/// JSON serialization round-trip via the `Json<T>` codec.
+///
+/// Preconditions: `Json<T>` can serialize to a WebSocket message and deserialize back.
+/// Expected: `to_ws_message()` produces a Text frame; `from_ws_message()` recovers the value.
+#[tokio::test]
+async fn websocket_json_roundtrip() -> TestResult {
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+    struct ChatMessage {
+        text: String,
+    }
+
+    // -- action: serialize to WebSocket message --------------------------------
+    let outgoing = Json(ChatMessage {
+        text: "hello".into(),
+    });
+    let raw = outgoing.to_ws_message();
+    assert!(matches!(&raw, WebSocketMessage::Text(t) if t.contains("hello")));
+
+    // -- action: deserialize back ----------------------------------------------
+    let parsed = <Json<ChatMessage>>::from_ws_message(raw)?;
+    assert_eq!(
         parsed.into_inner(),
         ChatMessage {
             text: "hello".into()
@@ -749,6 +2384,44 @@ async fn websocket_json_roundtrip() -> TestResult {
     Ok(())
 }
 
+/// Send bare values on a `Json<T>` stream without wrapping them.
+///
+/// Preconditions: a `WebSocketStream<Json<ChatMessage>>` over a channel sink.
+/// Expected: `send_json` on the stream and on its sender half emit Text
+///   frames containing the value's JSON.
+#[tokio::test]
+async fn websocket_send_json_bare_value() -> TestResult {
+    #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
+    struct ChatMessage {
+        text: String,
+    }
+
+    let (sink, mut sent) = channel_sink();
+    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::empty());
+    let raw: WebSocketStream = (sink, receiver).into();
+    let mut ws: WebSocketStream<Json<ChatMessage>> = raw.into_typed();
+
+    // -- action -----------------------------------------------------------------
+    ws.send_json(&ChatMessage { text: "hi".into() }).await?;
+    let (mut tx, _rx) = ws.split();
+    tx.send_json(&ChatMessage {
+        text: "again".into(),
+    })
+    .await?;
+
+    // -- verify -----------------------------------------------------------------
+    assert_eq!(
+        sent.recv().await,
+        Some(WebSocketMessage::Text(r#"{"text":"hi"}"#.into()))
+    );
+    assert_eq!(
+        sent.recv().await,
+        Some(WebSocketMessage::Text(r#"{"text":"again"}"#.into()))
+    );
+
+    Ok(())
+}
+
 /// `FromWebSocketMessage for Json<T>` rejects Binary messages.
 ///
 /// Preconditions: a Binary WebSocket message.
@@ -760,13 +2433,223 @@ async fn websocket_json_rejects_binary() {
         x: i32,
     }
 
-    let binary_msg = WebSocketMessage::Binary(vec![0, 1, 2]);
-    let err = <Json<Msg>>::from_ws_message(binary_msg).unwrap_err();
+    let binary_msg = WebSocketMessage::Binary(vec![0, 1, 2]);
+    let err = <Json<Msg>>::from_ws_message(binary_msg).unwrap_err();
+
+    assert!(
+        matches!(&err, StreamingError::WebSocketBridge { detail } if detail.contains("Text")),
+        "expected WebSocketBridge mentioning Text, got {err:?}"
+    );
+}
+
+/// Form-encoded round-trip via the `Form<T>` codec.
+///
+/// Preconditions: a flat struct with string, numeric, and boolean fields.
+/// Expected: `to_ws_message()` produces a form-encoded Text frame;
+///   `from_ws_message()` recovers the value.
+///
+/// Requires the `form` feature.
+#[cfg(feature = "form")]
+#[tokio::test]
+async fn websocket_form_roundtrip() -> TestResult {
+    use oagw_sdk::codec::Form;
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
+    struct Login {
+        user: String,
+        attempts: u32,
+        remember: bool,
+    }
+
+    // -- action: serialize to WebSocket message --------------------------------
+    let outgoing = Form(Login {
+        user: "alice smith".into(),
+        attempts: 2,
+        remember: true,
+    });
+    let raw = outgoing.to_ws_message();
+    assert_eq!(
+        raw,
+        WebSocketMessage::Text("user=alice+smith&attempts=2&remember=true".into())
+    );
+
+    // -- action: deserialize back ----------------------------------------------
+    let parsed = <Form<Login>>::from_ws_message(raw)?;
+    assert_eq!(parsed.into_inner(), outgoing.0);
+
+    Ok(())
+}
+
+/// `Form<T>` reports malformed payloads and non-Text frames.
+///
+/// Preconditions: a Text frame whose field has the wrong type, and a Binary frame.
+/// Expected: both produce `Err(WebSocketBridge)` with a descriptive detail.
+///
+/// Requires the `form` feature.
+#[cfg(feature = "form")]
+#[tokio::test]
+async fn websocket_form_rejects_malformed_input() {
+    use oagw_sdk::codec::Form;
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize)]
+    struct Counter {
+        count: u32,
+    }
+
+    let err =
+        <Form<Counter>>::from_ws_message(WebSocketMessage::Text("count=many".into())).unwrap_err();
+    assert!(
+        matches!(&err, StreamingError::WebSocketBridge { detail } if detail.starts_with("invalid form-encoded payload")),
+        "expected WebSocketBridge for malformed payload, got {err:?}"
+    );
+
+    let err = <Form<Counter>>::from_ws_message(WebSocketMessage::Binary(b"count=1".to_vec()))
+        .unwrap_err();
+    assert!(
+        matches!(&err, StreamingError::WebSocketBridge { detail } if detail.contains("Text")),
+        "expected WebSocketBridge mentioning Text, got {err:?}"
+    );
+}
+
+/// MessagePack serialization round-trip via the `MsgPack<T>` codec.
+///
+/// Preconditions: `MsgPack<T>` can serialize to a WebSocket message and
+///   deserialize back.
+/// Expected: `to_ws_message()` produces a Binary frame; `from_ws_message()`
+///   recovers the value and rejects Text frames.
+///
+/// Requires the `msgpack` feature.
+#[cfg(feature = "msgpack")]
+#[tokio::test]
+async fn websocket_msgpack_roundtrip() -> TestResult {
+    use oagw_sdk::codec::MsgPack;
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
+    struct Tick {
+        seq: u64,
+        symbol: String,
+    }
+
+    // -- action: serialize to WebSocket message --------------------------------
+    let outgoing = MsgPack(Tick {
+        seq: 7,
+        symbol: "ABC".into(),
+    });
+    let raw = outgoing.to_ws_message();
+    assert!(matches!(&raw, WebSocketMessage::Binary(_)), "got {raw:?}");
+
+    // -- action: deserialize back ----------------------------------------------
+    let parsed = <MsgPack<Tick>>::from_ws_message(raw)?;
+    assert_eq!(parsed.into_inner(), outgoing.0);
+
+    // -- verify: Text frames are rejected --------------------------------------
+    let err = <MsgPack<Tick>>::from_ws_message(WebSocketMessage::Text("{}".into())).unwrap_err();
+    assert!(
+        matches!(&err, StreamingError::WebSocketBridge { detail } if detail.contains("Binary")),
+        "expected WebSocketBridge mentioning Binary, got {err:?}"
+    );
+
+    Ok(())
+}
+
+/// MessagePack SSE events travel as base64 `data`.
+///
+/// Preconditions: an event built with `MsgPack::into_server_event`, served
+///   as an SSE stream.
+/// Expected: parsing with `SseDecoder::with_binary_data(true)` yields the
+///   original value as `MsgPack<T>`.
+///
+/// Requires the `msgpack` feature.
+#[cfg(feature = "msgpack")]
+#[tokio::test]
+async fn sse_msgpack_roundtrip() -> TestResult {
+    use oagw_sdk::codec::MsgPack;
+    use oagw_sdk::sse::ToServerEvent;
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+    struct Tick {
+        seq: u64,
+    }
+
+    // -- precondition ---------------------------------------------------------
+    let wire = MsgPack(Tick { seq: 42 }).into_server_event()?.to_wire()?;
+    let resp = server_events_response(vec![std::str::from_utf8(&wire)?]);
+
+    // -- action ---------------------------------------------------------------
+    let ServerEventsResponse::Events(events) = ServerEventsStream::from_response_with_decoder::<
+        MsgPack<Tick>,
+    >(resp, SseDecoder::new().with_binary_data(true)) else {
+        return Err("expected an SSE response".into());
+    };
+    let ticks: Vec<MsgPack<Tick>> = events.try_collect().await?;
+
+    // -- verify ---------------------------------------------------------------
+    assert_eq!(ticks, [MsgPack(Tick { seq: 42 })]);
+
+    Ok(())
+}
+
+/// Outbound connection to a local echo server.
+///
+/// Preconditions: a tokio-tungstenite server on a loopback port that echoes
+///   Text and Binary messages.
+/// Expected: `tungstenite_adapter::connect` returns a `WebSocketStream` that
+///   sends and receives through it; connecting to a closed port fails with
+///   `WebSocketConnect`.
+///
+/// Requires the `tungstenite` feature.
+#[cfg(feature = "tungstenite")]
+#[tokio::test]
+async fn websocket_tungstenite_connect_echo() -> TestResult {
+    use oagw_sdk::ws::tungstenite_adapter;
+    use tokio_tungstenite::tungstenite::Message;
+
+    // -- precondition: echo server ------------------------------------------------
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    tokio::spawn(async move {
+        let (tcp, _) = listener.accept().await?;
+        let mut server = tokio_tungstenite::accept_async(tcp).await?;
+        while let Some(msg) = server.next().await {
+            match msg? {
+                msg @ (Message::Text(_) | Message::Binary(_)) => server.send(msg).await?,
+                Message::Close(_) => break,
+                _ => {}
+            }
+        }
+        Ok::<_, BoxError>(())
+    });
+
+    // -- action -------------------------------------------------------------------
+    let mut ws = tungstenite_adapter::connect(&format!("ws://{addr}")).await?;
+    ws.send_text("hello").await?;
+    ws.send_binary(vec![1, 2, 3]).await?;
+
+    // -- verify -------------------------------------------------------------------
+    assert_eq!(
+        ws.recv().await.transpose()?,
+        Some(WebSocketMessage::Text("hello".into()))
+    );
+    assert_eq!(
+        ws.recv().await.transpose()?,
+        Some(WebSocketMessage::Binary(vec![1, 2, 3]))
+    );
+    ws.close().await?;
 
+    // -- verify: connect failure --------------------------------------------------
+    let closed = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let closed_addr = closed.local_addr()?;
+    drop(closed);
+    let err = tungstenite_adapter::connect(&format!("ws://{closed_addr}"))
+        .await
+        .err()
+        .ok_or("connect to a closed port succeeded")?;
     assert!(
-        matches!(&err, StreamingError::WebSocketBridge { detail } if detail.contains("Text")),
-        "expected WebSocketBridge mentioning Text, got {err:?}"
+        matches!(err, StreamingError::WebSocketConnect { .. }),
+        "got: {err:?}"
     );
+
+    Ok(())
 }
 
 /// WebSocketStream as `Stream` trait — polls correctly via `collect()`.
@@ -802,6 +2685,94 @@ async fn websocket_stream_as_futures_stream() -> TestResult {
     Ok(())
 }
 
+/// The tagged body stream keeps the Text/Binary distinction.
+///
+/// Preconditions: the peer sends a Ping, a Text and a Binary message, then
+///   Close.
+/// Expected: `into_body_stream_tagged` yields the two data chunks tagged
+///   `FrameKind::Text` and `FrameKind::Binary`, skipping the Ping.
+#[tokio::test]
+async fn websocket_into_body_stream_tagged() -> TestResult {
+    use oagw_sdk::ws::FrameKind;
+
+    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::iter(vec![
+        Ok(WebSocketMessage::Ping(vec![1])),
+        Ok(WebSocketMessage::Text("hello".into())),
+        Ok(WebSocketMessage::Binary(vec![0xff, 0x00])),
+        Ok(WebSocketMessage::Close(None)),
+    ]));
+    let (sink, _sent) = channel_sink();
+    let ws: WebSocketStream = (sink, receiver).into();
+    let (_tx, rx) = ws.split();
+
+    // -- action -------------------------------------------------------------------
+    let chunks: Vec<(FrameKind, Bytes)> = rx.into_body_stream_tagged().try_collect().await?;
+
+    // -- verify -------------------------------------------------------------------
+    assert_eq!(
+        chunks,
+        [
+            (FrameKind::Text, Bytes::from("hello")),
+            (FrameKind::Binary, Bytes::from_static(&[0xff, 0x00])),
+        ]
+    );
+
+    Ok(())
+}
+
+/// `bridge_body` runs an HTTP upload/download pair over one WebSocket.
+///
+/// Preconditions: an upstream connection whose incoming frames the test
+///   controls; a two-chunk request body.
+/// Expected: the chunks are sent upstream as Text messages followed by a
+///   Close; the returned body yields the upstream's messages and ends on its
+///   Close.
+#[tokio::test]
+async fn websocket_bridge_body_runs_both_directions() -> TestResult {
+    let (sink, mut wire_rx) = channel_sink();
+    let (down_tx, down_rx) =
+        tokio::sync::mpsc::channel::<Result<WebSocketMessage, StreamingError>>(16);
+    let receiver: WebSocketReceiver =
+        Box::pin(futures_util::stream::unfold(down_rx, |mut rx| async {
+            rx.recv().await.map(|msg| (msg, rx))
+        }));
+    let upload: BodyStream = Box::pin(futures_util::stream::iter(vec![
+        Ok(Bytes::from("up-1")),
+        Ok(Bytes::from("up-2")),
+    ]));
+
+    // -- action ----------------------------------------------------------------
+    let mut download = WebSocketStream::from((sink, receiver)).bridge_body(upload);
+
+    // -- verify: upload direction ------------------------------------------------
+    assert_eq!(
+        wire_rx.recv().await,
+        Some(WebSocketMessage::Text("up-1".into()))
+    );
+    assert_eq!(
+        wire_rx.recv().await,
+        Some(WebSocketMessage::Text("up-2".into()))
+    );
+    assert_eq!(wire_rx.recv().await, Some(WebSocketMessage::Close(None)));
+
+    // -- verify: download direction ----------------------------------------------
+    down_tx
+        .send(Ok(WebSocketMessage::Text("down-1".into())))
+        .await?;
+    down_tx.send(Ok(WebSocketMessage::Ping(vec![]))).await?;
+    down_tx
+        .send(Ok(WebSocketMessage::Binary(vec![1, 2])))
+        .await?;
+    down_tx.send(Ok(WebSocketMessage::Close(None))).await?;
+    let mut chunks = Vec::new();
+    while let Some(chunk) = download.next().await {
+        chunks.push(chunk?);
+    }
+    assert_eq!(chunks, [Bytes::from("down-1"), Bytes::from_static(&[1, 2])]);
+
+    Ok(())
+}
+
 /// Split into sender/receiver halves for concurrent send and receive.
 ///
 /// Preconditions: in-memory WebSocket with a channel-based sink.
@@ -845,6 +2816,451 @@ async fn websocket_stream_split() -> TestResult {
     Ok(())
 }
 
+/// Build a stream straight from `futures` channels with `from_parts`.
+///
+/// Preconditions: an unbounded channel as the outgoing sink and another as
+///   the incoming stream; no manual boxing.
+/// Expected: messages sent on the stream reach the outgoing channel;
+///   messages pushed into the incoming channel are received.
+#[tokio::test]
+async fn websocket_from_parts_with_futures_channels() -> TestResult {
+    use futures::channel::mpsc;
+
+    let (out_tx, mut out_rx) = mpsc::unbounded::<WebSocketMessage>();
+    let (in_tx, in_rx) = mpsc::unbounded::<Result<WebSocketMessage, StreamingError>>();
+    let mut ws = WebSocketStream::from_parts(
+        out_tx.sink_map_err(|e| StreamingError::WebSocketBridge {
+            detail: e.to_string(),
+        }),
+        in_rx,
+    );
+
+    // -- action -------------------------------------------------------------------
+    ws.send_text("out").await?;
+    in_tx.unbounded_send(Ok(WebSocketMessage::Text("in".into())))?;
+
+    // -- verify -------------------------------------------------------------------
+    assert_eq!(
+        out_rx.next().await,
+        Some(WebSocketMessage::Text("out".into()))
+    );
+    assert_eq!(
+        ws.recv().await.transpose()?,
+        Some(WebSocketMessage::Text("in".into()))
+    );
+    drop(in_tx);
+    assert!(ws.recv().await.is_none());
+
+    Ok(())
+}
+
+/// Send a burst of messages with one flush.
+///
+/// Preconditions: the send half of a split stream over a channel sink.
+/// Expected: every message from one `send_all` call arrives, in order.
+#[tokio::test]
+async fn websocket_sender_send_all_batches() -> TestResult {
+    let (sink, mut sent) = channel_sink();
+    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::empty());
+    let ws: WebSocketStream = (sink, receiver).into();
+    let (mut sender, receiver) = ws.split();
+    let batch: Vec<WebSocketMessage> = (0..5)
+        .map(|i| WebSocketMessage::Text(format!("msg {i}")))
+        .collect();
+
+    // -- action -------------------------------------------------------------------
+    sender.send_all(batch.clone()).await?;
+    drop((sender, receiver));
+
+    // -- verify -------------------------------------------------------------------
+    let mut received = Vec::new();
+    while let Some(msg) = sent.recv().await {
+        received.push(msg);
+    }
+    assert_eq!(received, batch);
+
+    Ok(())
+}
+
+/// Recombine split halves after a scoped helper is done with them.
+///
+/// Preconditions: a stream split into halves; the peer sends one Text
+///   message, then Close.
+/// Expected: a message sent on the sender half reaches the sink; the
+///   reunited stream receives the incoming message and sends on the same
+///   sink.
+#[tokio::test]
+async fn websocket_stream_reunite() -> TestResult {
+    let (sink, mut sent) = channel_sink();
+    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::iter(vec![
+        Ok(WebSocketMessage::Text("received".into())),
+        Ok(WebSocketMessage::Close(None)),
+    ]));
+    let ws: WebSocketStream = (sink, receiver).into();
+    let (mut sender, stream_receiver) = ws.split();
+
+    // -- action -------------------------------------------------------------------
+    sender.send_text("from sender").await?;
+    let mut ws = WebSocketStream::reunite(sender, stream_receiver);
+
+    // -- verify -------------------------------------------------------------------
+    assert_eq!(
+        ws.recv().await.transpose()?,
+        Some(WebSocketMessage::Text("received".into()))
+    );
+    ws.send_text("from stream").await?;
+    assert_eq!(
+        sent.recv().await,
+        Some(WebSocketMessage::Text("from sender".into()))
+    );
+    assert_eq!(
+        sent.recv().await,
+        Some(WebSocketMessage::Text("from stream".into()))
+    );
+    assert!(ws.recv().await.is_none());
+
+    Ok(())
+}
+
+/// Send raw text and binary frames without building `WebSocketMessage`s.
+///
+/// Preconditions: default (raw) stream backed by a channel sink.
+/// Expected: `send_text`/`send_binary` on the stream and on its sender half
+///   emit `Text` and `Binary` frames respectively.
+#[tokio::test]
+async fn websocket_send_text_and_binary_helpers() -> TestResult {
+    let (sink, mut sent) = channel_sink();
+    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::pending());
+    let mut ws: WebSocketStream = (sink, receiver).into();
+
+    // -- action ----------------------------------------------------------------
+    ws.send_text("hello").await?;
+    ws.send_binary(vec![1, 2, 3]).await?;
+    let (mut sender, _rx) = ws.split();
+    sender.send_text(String::from("split")).await?;
+    sender.send_binary(b"raw".as_slice()).await?;
+
+    // -- verify ----------------------------------------------------------------
+    assert_eq!(
+        sent.recv().await,
+        Some(WebSocketMessage::Text("hello".into()))
+    );
+    assert_eq!(
+        sent.recv().await,
+        Some(WebSocketMessage::Binary(vec![1, 2, 3]))
+    );
+    assert_eq!(
+        sent.recv().await,
+        Some(WebSocketMessage::Text("split".into()))
+    );
+    assert_eq!(
+        sent.recv().await,
+        Some(WebSocketMessage::Binary(b"raw".to_vec()))
+    );
+
+    Ok(())
+}
+
+/// Close handshake completes when the peer answers with its own Close frame.
+///
+/// Preconditions: peer sends a Text frame and then acknowledges the close.
+/// Expected: our Close frame carries the code/reason; returns `true`.
+#[tokio::test]
+async fn websocket_close_with_timeout_completes_handshake() -> TestResult {
+    let (sink, mut sent) = channel_sink();
+    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::iter(vec![
+        Ok(WebSocketMessage::Text("late".into())),
+        Ok(WebSocketMessage::Close(None)),
+    ]));
+    let ws: WebSocketStream = (sink, receiver).into();
+
+    // -- action ----------------------------------------------------------------
+    let completed = ws
+        .close_with_timeout(1000, "bye", std::time::Duration::from_secs(5))
+        .await?;
+
+    // -- verify ----------------------------------------------------------------
+    assert!(completed);
+    assert_eq!(
+        sent.recv().await,
+        Some(WebSocketMessage::Close(Some(WebSocketCloseFrame {
+            code: 1000,
+            reason: "bye".into(),
+        })))
+    );
+
+    Ok(())
+}
+
+/// Close handshake is bounded when the peer never answers.
+///
+/// Preconditions: peer never sends anything after our Close frame.
+/// Expected: returns `false` once the timeout elapses.
+#[tokio::test]
+async fn websocket_close_with_timeout_gives_up_on_silent_peer() -> TestResult {
+    let (sink, _sent) = channel_sink();
+    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::pending());
+    let ws: WebSocketStream = (sink, receiver).into();
+
+    let completed = ws
+        .close_with_timeout(1001, "going away", std::time::Duration::from_millis(20))
+        .await?;
+
+    assert!(!completed);
+
+    Ok(())
+}
+
+/// Close handshake is bounded even when the Close frame cannot be sent.
+///
+/// Preconditions: the sink never accepts a frame (peer stopped reading).
+/// Expected: returns `false` once the timeout elapses instead of hanging.
+#[tokio::test]
+async fn websocket_close_with_timeout_bounds_stalled_send() -> TestResult {
+    let sink: WebSocketSink = Box::pin(futures_util::sink::unfold(
+        (),
+        |(), _msg: WebSocketMessage| futures_util::future::pending::<Result<(), StreamingError>>(),
+    ));
+    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::pending());
+    let ws: WebSocketStream = (sink, receiver).into();
+
+    // -- action ----------------------------------------------------------------
+    let completed = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        ws.close_with_timeout(1001, "going away", std::time::Duration::from_millis(20)),
+    )
+    .await
+    .expect("close_with_timeout must not hang on a stalled sink")?;
+
+    // -- verify ----------------------------------------------------------------
+    assert!(!completed);
+
+    Ok(())
+}
+
+/// Batch sends with `feed` and a single `flush`.
+///
+/// Preconditions: sender half of a split stream backed by a channel sink.
+/// Expected: all fed messages arrive, in order, after `flush`.
+#[tokio::test]
+async fn websocket_feed_then_flush_batches_messages() -> TestResult {
+    let (sink, mut sent) = channel_sink();
+    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::pending());
+    let ws: WebSocketStream = (sink, receiver).into();
+    let (mut sender, _rx) = ws.split();
+
+    // -- action ----------------------------------------------------------------
+    for text in ["a", "b", "c"] {
+        sender.feed(&WebSocketMessage::Text(text.into())).await?;
+    }
+    sender.flush().await?;
+
+    // -- verify ----------------------------------------------------------------
+    for text in ["a", "b", "c"] {
+        assert_eq!(sent.recv().await, Some(WebSocketMessage::Text(text.into())));
+    }
+
+    Ok(())
+}
+
+/// `relay` forwards data in both directions and propagates the close.
+///
+/// Preconditions: upstream sends text, a ping, binary and a Close frame with a
+/// code and reason; downstream sends one text message and stays open.
+/// Expected: each side receives the other's data frames (pings are not
+/// forwarded), downstream receives the upstream's Close frame unchanged, and
+/// the relay reports that the upstream closed.
+#[tokio::test]
+async fn websocket_relay_forwards_and_propagates_close() -> TestResult {
+    let close = WebSocketCloseFrame {
+        code: 4001,
+        reason: "upstream done".into(),
+    };
+    let (down_sink, mut to_downstream) = channel_sink();
+    let (up_sink, mut to_upstream) = channel_sink();
+    let (down_in, down_rx) = tokio::sync::mpsc::channel::<WebSocketMessage>(16);
+    let (up_in, up_rx) = tokio::sync::mpsc::channel::<WebSocketMessage>(16);
+    let receiver = |rx: tokio::sync::mpsc::Receiver<WebSocketMessage>| -> WebSocketReceiver {
+        Box::pin(futures_util::stream::unfold(rx, |mut rx| async {
+            rx.recv().await.map(|msg| (Ok(msg), rx))
+        }))
+    };
+    let upstream: WebSocketStream = (up_sink, receiver(up_rx)).into();
+    let downstream: WebSocketStream = (down_sink, receiver(down_rx)).into();
+    let relay = tokio::spawn(oagw_sdk::ws::relay(upstream, downstream));
+
+    // -- action ----------------------------------------------------------------
+    down_in.send(WebSocketMessage::Text("hello".into())).await?;
+    assert_eq!(
+        to_upstream.recv().await,
+        Some(WebSocketMessage::Text("hello".into()))
+    );
+    up_in.send(WebSocketMessage::Text("u1".into())).await?;
+    up_in.send(WebSocketMessage::Ping(vec![])).await?;
+    up_in.send(WebSocketMessage::Binary(vec![1, 2])).await?;
+    up_in
+        .send(WebSocketMessage::Close(Some(close.clone())))
+        .await?;
+
+    // -- verify ----------------------------------------------------------------
+    let end = relay.await??;
+    assert_eq!(end, RelayEnd::UpstreamClosed(Some(close.clone())));
+    assert_eq!(
+        to_downstream.recv().await,
+        Some(WebSocketMessage::Text("u1".into()))
+    );
+    assert_eq!(
+        to_downstream.recv().await,
+        Some(WebSocketMessage::Binary(vec![1, 2]))
+    );
+    assert_eq!(
+        to_downstream.recv().await,
+        Some(WebSocketMessage::Close(Some(close)))
+    );
+
+    drop(down_in);
+    Ok(())
+}
+
+/// A side whose stream ends without a Close frame still closes the other.
+///
+/// Preconditions: downstream's stream ends; upstream stays open.
+/// Expected: upstream receives `Close(None)`; relay reports the downstream end.
+#[tokio::test]
+async fn websocket_relay_stream_end_closes_peer() -> TestResult {
+    let (up_sink, mut to_upstream) = channel_sink();
+    let (down_sink, _to_downstream) = channel_sink();
+    let upstream: WebSocketStream = (
+        up_sink,
+        Box::pin(futures_util::stream::pending()) as WebSocketReceiver,
+    )
+        .into();
+    let downstream: WebSocketStream = (
+        down_sink,
+        Box::pin(futures_util::stream::empty()) as WebSocketReceiver,
+    )
+        .into();
+
+    // -- action ----------------------------------------------------------------
+    let end = oagw_sdk::ws::relay(upstream, downstream).await?;
+
+    // -- verify ----------------------------------------------------------------
+    assert_eq!(end, RelayEnd::DownstreamClosed(None));
+    assert_eq!(
+        to_upstream.recv().await,
+        Some(WebSocketMessage::Close(None))
+    );
+
+    Ok(())
+}
+
+/// A failed send closes the side whose message could not be delivered.
+///
+/// Preconditions: upstream sends a Text frame; downstream's sink fails.
+/// Expected: relay returns the send error and upstream receives `Close(None)`.
+#[tokio::test]
+async fn websocket_relay_send_error_closes_source() -> TestResult {
+    let (up_sink, mut to_upstream) = channel_sink();
+    let failing: WebSocketSink = Box::pin(futures_util::sink::unfold(
+        (),
+        |(), _msg: WebSocketMessage| async {
+            Err(StreamingError::WebSocketBridge {
+                detail: "downstream gone".into(),
+            })
+        },
+    ));
+    let upstream: WebSocketStream = (
+        up_sink,
+        Box::pin(
+            futures_util::stream::iter([Ok(WebSocketMessage::Text("hello".into()))])
+                .chain(futures_util::stream::pending()),
+        ) as WebSocketReceiver,
+    )
+        .into();
+    let downstream: WebSocketStream = (
+        failing,
+        Box::pin(futures_util::stream::pending()) as WebSocketReceiver,
+    )
+        .into();
+
+    // -- action ----------------------------------------------------------------
+    let err = oagw_sdk::ws::relay(upstream, downstream)
+        .await
+        .expect_err("send to downstream fails");
+
+    // -- verify ----------------------------------------------------------------
+    assert!(err.to_string().contains("downstream gone"), "got: {err}");
+    assert_eq!(
+        to_upstream.recv().await,
+        Some(WebSocketMessage::Close(None))
+    );
+
+    Ok(())
+}
+
+/// Frame counters break down everything received, including control frames.
+///
+/// Preconditions: peer sends text, binary, ping, pong and close frames.
+/// Expected: `stats()` counts each frame type, even those `recv` skips.
+#[tokio::test]
+async fn websocket_stats_count_frames_by_type() -> TestResult {
+    let (sink, _sent) = channel_sink();
+    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::iter(vec![
+        Ok(WebSocketMessage::Ping(vec![])),
+        Ok(WebSocketMessage::Text("a".into())),
+        Ok(WebSocketMessage::Ping(vec![])),
+        Ok(WebSocketMessage::Binary(vec![1])),
+        Ok(WebSocketMessage::Pong(vec![])),
+        Ok(WebSocketMessage::Close(None)),
+    ]));
+    let mut ws: WebSocketStream = (sink, receiver).into();
+    assert_eq!(ws.stats(), WsStats::default());
+
+    // -- action ----------------------------------------------------------------
+    while ws.recv().await.transpose()?.is_some() {}
+
+    // -- verify ----------------------------------------------------------------
+    assert_eq!(
+        ws.stats(),
+        WsStats {
+            text: 1,
+            binary: 1,
+            ping: 2,
+            pong: 1,
+            close: 1,
+        }
+    );
+    assert_eq!(ws.stats().total(), 6);
+
+    Ok(())
+}
+
+/// Split halves share the connection's frame counters.
+///
+/// Preconditions: stream is split; frames are consumed via the receive half's `Stream` impl.
+/// Expected: both halves report the same snapshot.
+#[tokio::test]
+async fn websocket_stats_shared_across_split_halves() -> TestResult {
+    let (sink, _sent) = channel_sink();
+    let receiver: WebSocketReceiver = Box::pin(futures_util::stream::iter(vec![
+        Ok(WebSocketMessage::Text("a".into())),
+        Ok(WebSocketMessage::Ping(vec![])),
+        Ok(WebSocketMessage::Text("b".into())),
+    ]));
+    let ws: WebSocketStream = (sink, receiver).into();
+    let (sender, mut rx) = ws.split();
+
+    while let Some(msg) = rx.next().await {
+        msg?;
+    }
+
+    assert_eq!(rx.stats().text, 2);
+    assert_eq!(rx.stats().ping, 1);
+    assert_eq!(sender.stats(), rx.stats());
+
+    Ok(())
+}
+
 // ===========================================================================
 // Multipart: file uploads via MultipartBody
 // ===========================================================================
@@ -1014,6 +3430,35 @@ async fn body_empty_to_bytes() -> TestResult {
     Ok(())
 }
 
+/// `clone_bytes()` reads buffered bodies without consuming them.
+///
+/// Preconditions: a `Body::Bytes`, a `Body::Empty` and a `Body::Stream`.
+/// Expected: buffered variants yield their bytes and stay usable; streams
+/// yield `None`.
+#[tokio::test]
+async fn body_clone_bytes_does_not_consume() -> TestResult {
+    let body = Body::from("hello");
+    assert_eq!(body.clone_bytes().as_deref(), Some(&b"hello"[..]));
+    body.assert_bytes_eq("hello").await;
+
+    assert_eq!(Body::Empty.clone_bytes(), Some(Bytes::new()));
+
+    let stream: BodyStream = Box::pin(futures_util::stream::iter(vec![Ok(Bytes::from("x"))]));
+    assert!(Body::Stream(stream).clone_bytes().is_none());
+    Ok(())
+}
+
+/// `assert_bytes_eq()` buffers a multi-chunk stream before comparing.
+#[tokio::test]
+async fn body_assert_bytes_eq_buffers_stream() -> TestResult {
+    let stream: BodyStream = Box::pin(futures_util::stream::iter(vec![
+        Ok(Bytes::from("chunk1")),
+        Ok(Bytes::from("chunk2")),
+    ]));
+    Body::Stream(stream).assert_bytes_eq(b"chunk1chunk2").await;
+    Ok(())
+}
+
 /// `Body::Bytes` converts to a single-item stream via `into_stream()`.
 #[tokio::test]
 async fn body_bytes_to_stream() -> TestResult {
@@ -4,85 +4,46 @@ use modkit::api::problem::Problem;
 
 use crate::domain::error::DomainError;
 use oagw_sdk::api::ErrorSource;
-
-// ---------------------------------------------------------------------------
-// GTS error type constants
-// ---------------------------------------------------------------------------
-
-pub(crate) const ERR_VALIDATION: &str = "gts.cf.core.errors.err.v1~cf.oagw.validation.error.v1";
-pub(crate) const ERR_CONFLICT: &str = "gts.cf.core.errors.err.v1~cf.oagw.resource.conflict.v1";
-pub(crate) const ERR_MISSING_TARGET_HOST: &str =
-    "gts.cf.core.errors.err.v1~cf.oagw.routing.missing_target_host.v1";
-pub(crate) const ERR_INVALID_TARGET_HOST: &str =
-    "gts.cf.core.errors.err.v1~cf.oagw.routing.invalid_target_host.v1";
-pub(crate) const ERR_UNKNOWN_TARGET_HOST: &str =
-    "gts.cf.core.errors.err.v1~cf.oagw.routing.unknown_target_host.v1";
-pub(crate) const ERR_AUTH_FAILED: &str = "gts.cf.core.errors.err.v1~cf.oagw.auth.failed.v1";
-pub(crate) const ERR_NOT_FOUND: &str = "gts.cf.core.errors.err.v1~cf.oagw.resource.not_found.v1";
-pub(crate) const ERR_ROUTE_NOT_FOUND: &str = "gts.cf.core.errors.err.v1~cf.oagw.route.not_found.v1";
-pub(crate) const ERR_PAYLOAD_TOO_LARGE: &str =
-    "gts.cf.core.errors.err.v1~cf.oagw.payload.too_large.v1";
-pub(crate) const ERR_RATE_LIMIT_EXCEEDED: &str =
-    "gts.cf.core.errors.err.v1~cf.oagw.rate_limit.exceeded.v1";
-pub(crate) const ERR_SECRET_NOT_FOUND: &str =
-    "gts.cf.core.errors.err.v1~cf.oagw.secret.not_found.v1";
-pub(crate) const ERR_DOWNSTREAM: &str = "gts.cf.core.errors.err.v1~cf.oagw.downstream.error.v1";
-pub(crate) const ERR_PROTOCOL: &str = "gts.cf.core.errors.err.v1~cf.oagw.protocol.error.v1";
-pub(crate) const ERR_UPSTREAM_DISABLED: &str =
-    "gts.cf.core.errors.err.v1~cf.oagw.routing.upstream_disabled.v1";
-pub(crate) const ERR_CONNECTION_TIMEOUT: &str =
-    "gts.cf.core.errors.err.v1~cf.oagw.timeout.connection.v1";
-pub(crate) const ERR_REQUEST_TIMEOUT: &str = "gts.cf.core.errors.err.v1~cf.oagw.timeout.request.v1";
-pub(crate) const ERR_GUARD_REJECTED: &str = "gts.cf.core.errors.err.v1~cf.oagw.guard.rejected.v1";
-pub(crate) const ERR_CORS_ORIGIN_NOT_ALLOWED: &str =
-    "gts.cf.core.errors.err.v1~cf.oagw.cors.origin_not_allowed.v1";
-pub(crate) const ERR_CORS_METHOD_NOT_ALLOWED: &str =
-    "gts.cf.core.errors.err.v1~cf.oagw.cors.method_not_allowed.v1";
-pub(crate) const ERR_STREAM_ABORTED: &str = "gts.cf.core.errors.err.v1~cf.oagw.stream.aborted.v1";
-pub(crate) const ERR_LINK_UNAVAILABLE: &str =
-    "gts.cf.core.errors.err.v1~cf.oagw.link.unavailable.v1";
-pub(crate) const ERR_CIRCUIT_BREAKER_OPEN: &str =
-    "gts.cf.core.errors.err.v1~cf.oagw.circuit_breaker.open.v1";
-pub(crate) const ERR_IDLE_TIMEOUT: &str = "gts.cf.core.errors.err.v1~cf.oagw.timeout.idle.v1";
-pub(crate) const ERR_PLUGIN_NOT_FOUND: &str =
-    "gts.cf.core.errors.err.v1~cf.oagw.plugin.not_found.v1";
-pub(crate) const ERR_PLUGIN_IN_USE: &str = "gts.cf.core.errors.err.v1~cf.oagw.plugin.in_use.v1";
-pub(crate) const ERR_FORBIDDEN: &str = "gts.cf.core.errors.err.v1~cf.oagw.authz.forbidden.v1";
+use oagw_sdk::error::ProblemKind;
 
 // ---------------------------------------------------------------------------
 // DomainError → Problem helpers
 // ---------------------------------------------------------------------------
 
-fn gts_type(err: &DomainError) -> &str {
+/// Problem type and title for `err`, from the table shared with
+/// [`ServiceGatewayError`](oagw_sdk::error::ServiceGatewayError).
+fn problem_kind(err: &DomainError) -> ProblemKind {
     match err {
-        DomainError::Validation { .. } => ERR_VALIDATION,
-        DomainError::Conflict { .. } => ERR_CONFLICT,
-        DomainError::MissingTargetHost { .. } => ERR_MISSING_TARGET_HOST,
-        DomainError::InvalidTargetHost { .. } => ERR_INVALID_TARGET_HOST,
-        DomainError::UnknownTargetHost { .. } => ERR_UNKNOWN_TARGET_HOST,
-        DomainError::AuthenticationFailed { .. } => ERR_AUTH_FAILED,
+        DomainError::Validation { .. } => ProblemKind::VALIDATION,
+        DomainError::Conflict { .. } => ProblemKind::CONFLICT,
+        DomainError::MissingTargetHost { .. } => ProblemKind::MISSING_TARGET_HOST,
+        DomainError::InvalidTargetHost { .. } => ProblemKind::INVALID_TARGET_HOST,
+        DomainError::UnknownTargetHost { .. } => ProblemKind::UNKNOWN_TARGET_HOST,
+        DomainError::AuthenticationFailed { .. } => ProblemKind::AUTH_FAILED,
         DomainError::NotFound {
             entity: "route", ..
-        } => ERR_ROUTE_NOT_FOUND,
-        DomainError::NotFound { .. } => ERR_NOT_FOUND,
-        DomainError::PayloadTooLarge { .. } => ERR_PAYLOAD_TOO_LARGE,
-        DomainError::RateLimitExceeded { .. } => ERR_RATE_LIMIT_EXCEEDED,
-        DomainError::SecretNotFound { .. } => ERR_SECRET_NOT_FOUND,
-        DomainError::DownstreamError { .. } | DomainError::Internal { .. } => ERR_DOWNSTREAM,
-        DomainError::ProtocolError { .. } => ERR_PROTOCOL,
-        DomainError::UpstreamDisabled { .. } => ERR_UPSTREAM_DISABLED,
-        DomainError::ConnectionTimeout { .. } => ERR_CONNECTION_TIMEOUT,
-        DomainError::RequestTimeout { .. } => ERR_REQUEST_TIMEOUT,
-        DomainError::GuardRejected { .. } => ERR_GUARD_REJECTED,
-        DomainError::CorsOriginNotAllowed { .. } => ERR_CORS_ORIGIN_NOT_ALLOWED,
-        DomainError::CorsMethodNotAllowed { .. } => ERR_CORS_METHOD_NOT_ALLOWED,
-        DomainError::StreamAborted { .. } => ERR_STREAM_ABORTED,
-        DomainError::LinkUnavailable { .. } => ERR_LINK_UNAVAILABLE,
-        DomainError::CircuitBreakerOpen { .. } => ERR_CIRCUIT_BREAKER_OPEN,
-        DomainError::IdleTimeout { .. } => ERR_IDLE_TIMEOUT,
-        DomainError::PluginNotFound { .. } => ERR_PLUGIN_NOT_FOUND,
-        DomainError::PluginInUse { .. } => ERR_PLUGIN_IN_USE,
-        DomainError::Forbidden { .. } => ERR_FORBIDDEN,
+        } => ProblemKind::ROUTE_NOT_FOUND,
+        DomainError::NotFound { .. } => ProblemKind::NOT_FOUND,
+        DomainError::PayloadTooLarge { .. } => ProblemKind::PAYLOAD_TOO_LARGE,
+        DomainError::RateLimitExceeded { .. } => ProblemKind::RATE_LIMIT_EXCEEDED,
+        DomainError::SecretNotFound { .. } => ProblemKind::SECRET_NOT_FOUND,
+        DomainError::DownstreamError { .. } | DomainError::Internal { .. } => {
+            ProblemKind::DOWNSTREAM
+        }
+        DomainError::ProtocolError { .. } => ProblemKind::PROTOCOL,
+        DomainError::UpstreamDisabled { .. } => ProblemKind::UPSTREAM_DISABLED,
+        DomainError::ConnectionTimeout { .. } => ProblemKind::CONNECTION_TIMEOUT,
+        DomainError::RequestTimeout { .. } => ProblemKind::REQUEST_TIMEOUT,
+        DomainError::GuardRejected { .. } => ProblemKind::GUARD_REJECTED,
+        DomainError::CorsOriginNotAllowed { .. } => ProblemKind::CORS_ORIGIN_NOT_ALLOWED,
+        DomainError::CorsMethodNotAllowed { .. } => ProblemKind::CORS_METHOD_NOT_ALLOWED,
+        DomainError::StreamAborted { .. } => ProblemKind::STREAM_ABORTED,
+        DomainError::LinkUnavailable { .. } => ProblemKind::LINK_UNAVAILABLE,
+        DomainError::CircuitBreakerOpen { .. } => ProblemKind::CIRCUIT_BREAKER_OPEN,
+        DomainError::IdleTimeout { .. } => ProblemKind::IDLE_TIMEOUT,
+        DomainError::PluginNotFound { .. } => ProblemKind::PLUGIN_NOT_FOUND,
+        DomainError::PluginInUse { .. } => ProblemKind::PLUGIN_IN_USE,
+        DomainError::Forbidden { .. } => ProblemKind::FORBIDDEN,
     }
 }
 
@@ -123,36 +84,6 @@ fn http_status_code(err: &DomainError) -> StatusCode {
     }
 }
 
-fn error_title(err: &DomainError) -> &str {
-    match err {
-        DomainError::Validation { .. } => "Validation Error",
-        DomainError::Conflict { .. } => "Conflict",
-        DomainError::MissingTargetHost { .. } => "Missing Target Host",
-        DomainError::InvalidTargetHost { .. } => "Invalid Target Host",
-        DomainError::UnknownTargetHost { .. } => "Unknown Target Host",
-        DomainError::AuthenticationFailed { .. } => "Authentication Failed",
-        DomainError::NotFound { .. } => "Not Found",
-        DomainError::PayloadTooLarge { .. } => "Payload Too Large",
-        DomainError::RateLimitExceeded { .. } => "Rate Limit Exceeded",
-        DomainError::SecretNotFound { .. } => "Secret Not Found",
-        DomainError::DownstreamError { .. } | DomainError::Internal { .. } => "Downstream Error",
-        DomainError::ProtocolError { .. } => "Protocol Error",
-        DomainError::UpstreamDisabled { .. } => "Upstream Disabled",
-        DomainError::ConnectionTimeout { .. } => "Connection Timeout",
-        DomainError::RequestTimeout { .. } => "Request Timeout",
-        DomainError::GuardRejected { .. } => "Guard Rejected",
-        DomainError::CorsOriginNotAllowed { .. } => "CORS Origin Not Allowed",
-        DomainError::CorsMethodNotAllowed { .. } => "CORS Method Not Allowed",
-        DomainError::StreamAborted { .. } => "Stream Aborted",
-        DomainError::LinkUnavailable { .. } => "Link Unavailable",
-        DomainError::CircuitBreakerOpen { .. } => "Circuit Breaker Open",
-        DomainError::IdleTimeout { .. } => "Idle Timeout",
-        DomainError::PluginNotFound { .. } => "Plugin Not Found",
-        DomainError::PluginInUse { .. } => "Plugin In Use",
-        DomainError::Forbidden { .. } => "Forbidden",
-    }
-}
-
 fn error_instance(err: &DomainError) -> &str {
     match err {
         DomainError::Validation { instance, .. }
@@ -190,14 +121,13 @@ fn error_instance(err: &DomainError) -> &str {
 
 impl From<DomainError> for Problem {
     fn from(err: DomainError) -> Self {
-        let gts = gts_type(&err).to_string();
+        let kind = problem_kind(&err);
         let inst = error_instance(&err).to_string();
         let status = http_status_code(&err);
-        let t = error_title(&err).to_string();
         let detail = err.to_string();
 
-        Problem::new(status, t, detail)
-            .with_type(gts)
+        Problem::new(status, kind.title, detail)
+            .with_type(kind.type_id)
             .with_instance(inst)
     }
 }
@@ -285,7 +215,7 @@ mod tests {
         };
         let p: Problem = err.into();
         assert_eq!(p.status, StatusCode::BAD_REQUEST);
-        assert_eq!(p.type_url, ERR_VALIDATION);
+        assert_eq!(p.type_url, ProblemKind::VALIDATION.type_id);
         assert_eq!(p.title, "Validation Error");
         assert!(p.detail.contains("missing required field"));
         assert_eq!(p.instance, "/oagw/v1/upstreams");
@@ -298,7 +228,7 @@ mod tests {
         };
         let p: Problem = err.into();
         assert_eq!(p.status, StatusCode::CONFLICT);
-        assert_eq!(p.type_url, ERR_CONFLICT);
+        assert_eq!(p.type_url, ProblemKind::CONFLICT.type_id);
         assert_eq!(p.title, "Conflict");
     }
 
@@ -314,7 +244,7 @@ mod tests {
         };
         let p: Problem = err.into();
         assert_eq!(p.status, StatusCode::TOO_MANY_REQUESTS);
-        assert_eq!(p.type_url, ERR_RATE_LIMIT_EXCEEDED);
+        assert_eq!(p.type_url, ProblemKind::RATE_LIMIT_EXCEEDED.type_id);
     }
 
     #[test]
@@ -325,7 +255,7 @@ mod tests {
         };
         let p: Problem = err.into();
         assert_eq!(p.status, StatusCode::NOT_FOUND);
-        assert_eq!(p.type_url, ERR_ROUTE_NOT_FOUND);
+        assert_eq!(p.type_url, ProblemKind::ROUTE_NOT_FOUND.type_id);
     }
 
     #[test]
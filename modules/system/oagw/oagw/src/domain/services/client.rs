@@ -6,6 +6,7 @@ use modkit_security::SecurityContext;
 use oagw_sdk::api::ServiceGatewayClientV1;
 use oagw_sdk::body::Body;
 use oagw_sdk::error::ServiceGatewayError;
+use oagw_sdk::rate_limit::RateLimitInfo;
 use uuid::Uuid;
 
 use super::{ControlPlaneService, DataPlaneService};
@@ -229,11 +230,19 @@ fn domain_err_to_sdk(err: DomainError) -> ServiceGatewayError {
             detail,
             instance,
             retry_after_secs,
-            ..
+            limit,
+            remaining,
+            reset_epoch,
         } => ServiceGatewayError::RateLimitExceeded {
             detail,
             instance,
             retry_after_secs,
+            rate_limit: (limit.is_some() || remaining.is_some() || reset_epoch.is_some())
+                .then_some(RateLimitInfo {
+                    limit,
+                    remaining,
+                    reset_epoch,
+                }),
         },
         DomainError::SecretNotFound { detail, instance } => {
             ServiceGatewayError::SecretNotFound { detail, instance }
@@ -877,6 +886,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn domain_err_rate_limit_carries_quota_to_sdk() {
+        let err = DomainError::RateLimitExceeded {
+            detail: "too fast".into(),
+            instance: "/api".into(),
+            retry_after_secs: Some(30),
+            limit: Some(100),
+            remaining: Some(0),
+            reset_epoch: None,
+        };
+        let sdk_err = domain_err_to_sdk(err);
+        match sdk_err {
+            ServiceGatewayError::RateLimitExceeded {
+                retry_after_secs,
+                rate_limit,
+                ..
+            } => {
+                assert_eq!(retry_after_secs, Some(30));
+                assert_eq!(
+                    rate_limit,
+                    Some(RateLimitInfo {
+                        limit: Some(100),
+                        remaining: Some(0),
+                        reset_epoch: None,
+                    })
+                );
+            }
+            _ => panic!("expected RateLimitExceeded"),
+        }
+    }
+
     #[test]
     fn domain_err_timeout_maps_to_sdk() {
         let err = DomainError::RequestTimeout {
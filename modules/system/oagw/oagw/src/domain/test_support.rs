@@ -184,6 +184,7 @@ impl CredStoreClientV1 for MockCredStoreClient {
             owner_tenant_id: CredstoreTenantId::nil(),
             sharing: SharingMode::default(),
             is_inherited: false,
+            version: None,
         }))
     }
 }
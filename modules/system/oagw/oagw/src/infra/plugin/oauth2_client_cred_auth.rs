@@ -477,6 +477,7 @@ mod tests {
                     owner_tenant_id: CredstoreTenantId::nil(),
                     sharing: SharingMode::default(),
                     is_inherited: false,
+                    version: None,
                 }))
             }
         }
@@ -412,7 +412,9 @@ impl DataPlaneService for DataPlaneServiceImpl {
                 }
                 (b, None)
             }
-            Body::Stream(s) => (Bytes::new(), Some(s)),
+            // Request trailers are not forwarded upstream.
+            Body::Stream(s) | Body::StreamWithTrailers(s, _) => (Bytes::new(), Some(s)),
+            file @ Body::File(_) => (Bytes::new(), Some(file.into_stream())),
         };
 
         // 1+2. Resolve upstream + route in one pass (single hierarchy walk).